@@ -0,0 +1,21 @@
+use futures_util::StreamExt;
+use twilio::{CallFilter, CallStatus, Client};
+
+#[tokio::main]
+async fn main() {
+    let app_id = "<app-id>";
+    let auth_token = "<auth-token>";
+    let client = Client::new(app_id, auth_token);
+    let filter = CallFilter {
+        status: Some(CallStatus::Completed),
+        start_time_after: Some("2023-01-01"),
+        ..Default::default()
+    };
+    let mut calls = client.list_calls(&filter);
+    while let Some(call) = calls.next().await {
+        match call {
+            Ok(c) => println!("{:?}", c),
+            Err(e) => eprintln!("{:?}", e),
+        }
+    }
+}
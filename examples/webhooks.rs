@@ -2,7 +2,7 @@ use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Request, Response};
 use std::convert::Infallible;
 use std::net::SocketAddr;
-use twilio::twiml::{Say, Twiml, Voice};
+use twilio::twiml::{MessagingResponse, Say, Twiml, Voice};
 
 async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
     let app_id = "<app-id>";
@@ -16,7 +16,7 @@ async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
         "/message" => {
             client
                 .respond_to_webhook(req, |msg: twilio::Message| {
-                    let mut t = Twiml::new();
+                    let mut t = MessagingResponse::new();
                     t.add(&twilio::twiml::Message {
                         txt: format!("You told me: '{}'", msg.body.unwrap()),
                     });
@@ -28,10 +28,11 @@ async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
             client
                 .respond_to_webhook(req, |_: twilio::Call| {
                     let mut t = Twiml::new();
-                    t.add(&Say {
+                    t.add_verb(&Say {
                         txt: "Thanks for using twilio-rs. Bye!".to_string(),
                         voice: Voice::Woman,
                         language: "en".to_string(),
+                        ssml: Vec::new(),
                     });
                     t
                 })
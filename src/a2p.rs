@@ -0,0 +1,292 @@
+//! [A2P 10DLC](https://www.twilio.com/docs/messaging/compliance/a2p-10dlc)
+//! registration, which US carriers require before a business texts from
+//! ordinary long code numbers.
+//!
+//! Register a brand from an approved customer profile and A2P trust product
+//! (see the `trusthub` module), then register a campaign describing the
+//! messages on the Messaging Service that will send them.
+
+use crate::endpoint::MESSAGING;
+use crate::{
+    bool_str, AccountSid, BrandRegistrationSid, BundleSid, Client, MessagingServiceSid,
+    PageIterator, Timestamp, TwilioError, UsAppToPersonSid, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+pub enum BrandType {
+    Standard,
+    /// For individuals and small businesses without a tax ID.
+    SoleProprietor,
+}
+
+impl BrandType {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            BrandType::Standard => "STANDARD",
+            BrandType::SoleProprietor => "SOLE_PROPRIETOR",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BrandStatus {
+    Pending,
+    InReview,
+    Approved,
+    Failed,
+    Suspended,
+    Deleted,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BrandRegistration {
+    pub sid: BrandRegistrationSid,
+    pub account_sid: AccountSid,
+    pub customer_profile_bundle_sid: BundleSid,
+    pub a2p_profile_bundle_sid: BundleSid,
+    pub brand_type: Option<String>,
+    pub status: BrandStatus,
+    /// The brand's ID with The Campaign Registry, once registered.
+    pub tcr_id: Option<String>,
+    pub failure_reason: Option<String>,
+    pub brand_score: Option<u32>,
+    pub identity_status: Option<String>,
+    pub russell_3000: Option<bool>,
+    pub tax_exempt_status: Option<String>,
+    pub skip_automatic_sec_vet: Option<bool>,
+    pub mock: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// A campaign, or `UsAppToPerson` resource, registered on a Messaging Service.
+#[derive(Debug, Deserialize)]
+pub struct Campaign {
+    pub sid: UsAppToPersonSid,
+    pub account_sid: AccountSid,
+    pub brand_registration_sid: BrandRegistrationSid,
+    pub messaging_service_sid: MessagingServiceSid,
+    pub description: String,
+    pub message_flow: Option<String>,
+    #[serde(default)]
+    pub message_samples: Vec<String>,
+    pub us_app_to_person_usecase: String,
+    pub has_embedded_links: Option<bool>,
+    pub has_embedded_phone: Option<bool>,
+    /// `PENDING`, `IN_PROGRESS`, `VERIFIED` or `FAILED`.
+    pub campaign_status: Option<String>,
+    /// The campaign's ID with The Campaign Registry, once registered.
+    pub campaign_id: Option<String>,
+    pub is_externally_registered: Option<bool>,
+    /// Per-carrier throughput limits, once verified.
+    pub rate_limits: Option<serde_json::Value>,
+    #[serde(default)]
+    pub errors: Vec<serde_json::Value>,
+    pub mock: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A campaign to register with `Client::create_campaign`. Carriers review
+/// the description, flow and samples, so they should match what is sent.
+pub struct NewCampaign<'a> {
+    pub brand_registration_sid: &'a BrandRegistrationSid,
+    /// A use case code from `Client::list_campaign_usecases`, e.g.
+    /// `"2FA"` or `"MARKETING"`.
+    pub usecase: &'a str,
+    pub description: &'a str,
+    /// How recipients opt in to the messages.
+    pub message_flow: &'a str,
+    /// Two to five example messages.
+    pub message_samples: Vec<&'a str>,
+    pub has_embedded_links: bool,
+    pub has_embedded_phone: bool,
+    pub opt_in_message: Option<&'a str>,
+    pub opt_out_message: Option<&'a str>,
+    pub help_message: Option<&'a str>,
+    pub opt_in_keywords: Vec<&'a str>,
+    pub opt_out_keywords: Vec<&'a str>,
+    pub help_keywords: Vec<&'a str>,
+}
+
+impl<'a> NewCampaign<'a> {
+    pub fn new(
+        brand_registration_sid: &'a BrandRegistrationSid,
+        usecase: &'a str,
+        description: &'a str,
+        message_flow: &'a str,
+        message_samples: Vec<&'a str>,
+    ) -> NewCampaign<'a> {
+        NewCampaign {
+            brand_registration_sid,
+            usecase,
+            description,
+            message_flow,
+            message_samples,
+            has_embedded_links: false,
+            has_embedded_phone: false,
+            opt_in_message: None,
+            opt_out_message: None,
+            help_message: None,
+            opt_in_keywords: Vec::new(),
+            opt_out_keywords: Vec::new(),
+            help_keywords: Vec::new(),
+        }
+    }
+}
+
+/// A campaign use case a brand is eligible for.
+#[derive(Debug, Deserialize)]
+pub struct Usecase {
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Whether campaigns with this use case need a further manual review.
+    pub post_approval_required: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct Usecases {
+    us_app_to_person_usecases: Vec<Usecase>,
+}
+
+impl Client {
+    pub async fn create_brand_registration(
+        &self,
+        customer_profile_sid: &BundleSid,
+        a2p_profile_sid: &BundleSid,
+        brand_type: BrandType,
+    ) -> Result<BrandRegistration, TwilioError> {
+        let opts = [
+            ("CustomerProfileBundleSid", customer_profile_sid.as_str()),
+            ("A2PProfileBundleSid", a2p_profile_sid.as_str()),
+            ("BrandType", brand_type.as_str()),
+        ];
+        self.send_product_request(POST, &MESSAGING, "a2p/BrandRegistrations", &opts)
+            .await
+    }
+
+    pub fn list_brand_registrations(&self) -> PageIterator<BrandRegistration> {
+        self.list_product(&MESSAGING, "a2p/BrandRegistrations", "data", &[])
+    }
+
+    pub async fn retrieve_brand_registration(
+        &self,
+        sid: &BrandRegistrationSid,
+    ) -> Result<BrandRegistration, TwilioError> {
+        self.send_product_request(
+            GET,
+            &MESSAGING,
+            &format!("a2p/BrandRegistrations/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn list_campaign_usecases(
+        &self,
+        service_sid: &MessagingServiceSid,
+        brand_registration_sid: &BrandRegistrationSid,
+    ) -> Result<Vec<Usecase>, TwilioError> {
+        let usecases: Usecases = self
+            .send_product_request(
+                GET,
+                &MESSAGING,
+                &format!("Services/{service_sid}/Compliance/Usa2p/Usecases"),
+                &[("BrandRegistrationSid", brand_registration_sid.as_str())],
+            )
+            .await?;
+        Ok(usecases.us_app_to_person_usecases)
+    }
+
+    pub async fn create_campaign(
+        &self,
+        service_sid: &MessagingServiceSid,
+        campaign: &NewCampaign<'_>,
+    ) -> Result<Campaign, TwilioError> {
+        let mut opts = vec![
+            (
+                "BrandRegistrationSid",
+                campaign.brand_registration_sid.as_str(),
+            ),
+            ("UsAppToPersonUsecase", campaign.usecase),
+            ("Description", campaign.description),
+            ("MessageFlow", campaign.message_flow),
+            ("HasEmbeddedLinks", bool_str(campaign.has_embedded_links)),
+            ("HasEmbeddedPhone", bool_str(campaign.has_embedded_phone)),
+        ];
+        for sample in &campaign.message_samples {
+            opts.push(("MessageSamples", sample));
+        }
+        if let Some(msg) = campaign.opt_in_message {
+            opts.push(("OptInMessage", msg));
+        }
+        if let Some(msg) = campaign.opt_out_message {
+            opts.push(("OptOutMessage", msg));
+        }
+        if let Some(msg) = campaign.help_message {
+            opts.push(("HelpMessage", msg));
+        }
+        for keyword in &campaign.opt_in_keywords {
+            opts.push(("OptInKeywords", keyword));
+        }
+        for keyword in &campaign.opt_out_keywords {
+            opts.push(("OptOutKeywords", keyword));
+        }
+        for keyword in &campaign.help_keywords {
+            opts.push(("HelpKeywords", keyword));
+        }
+        self.send_product_request(
+            POST,
+            &MESSAGING,
+            &format!("Services/{service_sid}/Compliance/Usa2p"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_campaigns(&self, service_sid: &MessagingServiceSid) -> PageIterator<Campaign> {
+        self.list_product(
+            &MESSAGING,
+            &format!("Services/{service_sid}/Compliance/Usa2p"),
+            "compliance",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_campaign(
+        &self,
+        service_sid: &MessagingServiceSid,
+        sid: &UsAppToPersonSid,
+    ) -> Result<Campaign, TwilioError> {
+        self.send_product_request(
+            GET,
+            &MESSAGING,
+            &format!("Services/{service_sid}/Compliance/Usa2p/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn delete_campaign(
+        &self,
+        service_sid: &MessagingServiceSid,
+        sid: &UsAppToPersonSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &MESSAGING,
+            &format!("Services/{service_sid}/Compliance/Usa2p/{sid}"),
+        )
+        .await
+    }
+}
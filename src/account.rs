@@ -0,0 +1,165 @@
+use crate::endpoint::API;
+use crate::{AccountSid, Client, PageIterator, Timestamp, TwilioError, GET, POST};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Closed accounts cannot be reopened, and are deleted by Twilio after 30
+/// days.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountStatus {
+    Active,
+    Suspended,
+    Closed,
+}
+
+impl AccountStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            AccountStatus::Active => "active",
+            AccountStatus::Suspended => "suspended",
+            AccountStatus::Closed => "closed",
+        }
+    }
+}
+
+/// Whether an account is on a free trial or has been upgraded.
+#[derive(Debug, Deserialize, PartialEq)]
+pub enum AccountType {
+    Trial,
+    Full,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Account {
+    pub sid: AccountSid,
+    /// The parent account's sid, or `sid` itself for a main account.
+    pub owner_account_sid: AccountSid,
+    pub friendly_name: String,
+    pub status: AccountStatus,
+    #[serde(rename = "type")]
+    pub account_type: Option<AccountType>,
+    pub auth_token: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub subresource_uris: BTreeMap<String, String>,
+}
+
+impl Account {
+    pub fn is_subaccount(&self) -> bool {
+        self.sid != self.owner_account_sid
+    }
+}
+
+/// The account's current balance. `balance` is a decimal string such as
+/// `"12.34"`, in `currency`.
+#[derive(Debug, Deserialize)]
+pub struct Balance {
+    pub account_sid: AccountSid,
+    pub balance: String,
+    pub currency: String,
+}
+
+#[derive(Default)]
+pub struct AccountUpdate<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub status: Option<AccountStatus>,
+}
+
+/// Filters accepted by `Client::list_accounts`.
+#[derive(Default)]
+pub struct AccountFilter<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub status: Option<AccountStatus>,
+    pub page_size: Option<u32>,
+}
+
+impl Client {
+    /// Fetches an account. Pass the client's own sid for the main account, or
+    /// the sid of one of its subaccounts.
+    pub async fn retrieve_account(&self, sid: &AccountSid) -> Result<Account, TwilioError> {
+        self.send_product_request(GET, &API, &format!("Accounts/{sid}.json"), &[])
+            .await
+    }
+
+    /// Fetches the balance of the account the client is scoped to.
+    pub async fn balance(&self) -> Result<Balance, TwilioError> {
+        self.send_request(GET, "Balance", &[]).await
+    }
+
+    pub async fn update_account(
+        &self,
+        sid: &AccountSid,
+        update: AccountUpdate<'_>,
+    ) -> Result<Account, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = update.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref status) = update.status {
+            opts.push(("Status", status.as_str()));
+        }
+        self.send_product_request(POST, &API, &format!("Accounts/{sid}.json"), &opts)
+            .await
+    }
+
+    /// Suspends a subaccount. Its resources stop working until it is
+    /// reactivated with `activate_account`.
+    pub async fn suspend_account(&self, sid: &AccountSid) -> Result<Account, TwilioError> {
+        self.set_account_status(sid, AccountStatus::Suspended).await
+    }
+
+    pub async fn activate_account(&self, sid: &AccountSid) -> Result<Account, TwilioError> {
+        self.set_account_status(sid, AccountStatus::Active).await
+    }
+
+    /// Permanently closes a subaccount, releasing its phone numbers.
+    pub async fn close_account(&self, sid: &AccountSid) -> Result<Account, TwilioError> {
+        self.set_account_status(sid, AccountStatus::Closed).await
+    }
+
+    async fn set_account_status(
+        &self,
+        sid: &AccountSid,
+        status: AccountStatus,
+    ) -> Result<Account, TwilioError> {
+        let update = AccountUpdate {
+            status: Some(status),
+            ..Default::default()
+        };
+        self.update_account(sid, update).await
+    }
+
+    pub async fn create_subaccount(
+        &self,
+        friendly_name: Option<&str>,
+    ) -> Result<Account, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        self.send_product_request(POST, &API, "Accounts.json", &opts)
+            .await
+    }
+
+    /// Lists the main account along with all of its subaccounts.
+    pub fn list_accounts(&self, filter: &AccountFilter<'_>) -> PageIterator<Account> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(name) = filter.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref status) = filter.status {
+            opts.push(("Status", status.as_str()));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list_product(&API, "Accounts.json", "accounts", &opts)
+    }
+}
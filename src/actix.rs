@@ -0,0 +1,87 @@
+//! Extractors and responders for handling webhooks in [actix-web](https://actix.rs).
+//!
+//! The `Client` used to validate signatures is looked up from app data,
+//! registered either as `web::Data<Client>` or as a plain `Client`. Without
+//! one, the extractor fails with a 500, since the app is misconfigured.
+//!
+//! ```ignore
+//! async fn sms(TwilioRequest(msg): TwilioRequest<twilio::Message>) -> MessagingResponse {
+//!     ...
+//! }
+//!
+//! App::new()
+//!     .app_data(web::Data::new(client.clone()))
+//!     .route("/sms", web::post().to(sms))
+//! ```
+
+use crate::twiml::{MessagingResponse, Twiml};
+use crate::{Client, FromMap, TwilioError};
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::StatusCode;
+use actix_web::web::{Bytes, Data};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
+use std::future::Future;
+use std::pin::Pin;
+
+/// A webhook whose `X-Twilio-Signature` has been validated, parsed into `T`.
+pub struct TwilioRequest<T>(pub T);
+
+impl<T: FromMap + 'static> FromRequest for TwilioRequest<T> {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let client = req
+            .app_data::<Data<Client>>()
+            .map(|c| c.get_ref().clone())
+            .or_else(|| req.app_data::<Client>().cloned());
+        let mut builder = hyper::Request::builder()
+            .method(req.method().clone())
+            .uri(req.uri().clone());
+        for (name, value) in req.headers() {
+            builder = builder.header(name, value);
+        }
+        let body = Bytes::from_request(req, payload);
+
+        Box::pin(async move {
+            let client = client.ok_or_else(|| {
+                ErrorInternalServerError("no twilio::Client registered as app data")
+            })?;
+            let body = body.await.map_err(|_| TwilioError::BadRequest)?;
+            let req = builder
+                .body(hyper::Body::from(body))
+                .map_err(|_| TwilioError::BadRequest)?;
+            let t = client.parse_request::<T>(req).await?;
+            Ok(TwilioRequest(*t))
+        })
+    }
+}
+
+impl ResponseError for TwilioError {
+    fn status_code(&self) -> StatusCode {
+        match *self {
+            TwilioError::AuthError => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl Responder for Twiml {
+    type Body = BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse {
+        HttpResponse::Ok()
+            .content_type("text/xml")
+            .body(self.as_twiml())
+    }
+}
+
+impl Responder for MessagingResponse {
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse {
+        Twiml::from(self).respond_to(req)
+    }
+}
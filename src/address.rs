@@ -0,0 +1,198 @@
+use crate::{
+    bool_str, AccountSid, AddressSid, Client, IncomingPhoneNumber, PageIterator, Timestamp,
+    TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+/// A customer address kept on file. Many countries require one before a
+/// phone number can be purchased; see `PhoneNumberConfig::address_sid`.
+#[derive(Debug, Deserialize)]
+pub struct Address {
+    pub sid: AddressSid,
+    pub account_sid: AccountSid,
+    pub customer_name: String,
+    pub friendly_name: Option<String>,
+    pub street: String,
+    pub street_secondary: Option<String>,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+    pub iso_country: String,
+    pub emergency_enabled: Option<bool>,
+    pub validated: Option<bool>,
+    pub verified: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+pub struct NewAddress<'a> {
+    pub customer_name: &'a str,
+    pub street: &'a str,
+    pub city: &'a str,
+    pub region: &'a str,
+    pub postal_code: &'a str,
+    /// The ISO 3166-1 alpha-2 country code, e.g. `"DE"`.
+    pub iso_country: &'a str,
+    pub street_secondary: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    pub emergency_enabled: Option<bool>,
+    /// Lets Twilio correct the address if it can't be validated as given.
+    /// Defaults to `true`.
+    pub auto_correct_address: Option<bool>,
+}
+
+impl<'a> NewAddress<'a> {
+    pub fn new(
+        customer_name: &'a str,
+        street: &'a str,
+        city: &'a str,
+        region: &'a str,
+        postal_code: &'a str,
+        iso_country: &'a str,
+    ) -> NewAddress<'a> {
+        NewAddress {
+            customer_name,
+            street,
+            city,
+            region,
+            postal_code,
+            iso_country,
+            street_secondary: None,
+            friendly_name: None,
+            emergency_enabled: None,
+            auto_correct_address: None,
+        }
+    }
+}
+
+/// Changes applied by `Client::update_address`. The country of an address
+/// can't be changed.
+#[derive(Default)]
+pub struct AddressUpdate<'a> {
+    pub customer_name: Option<&'a str>,
+    pub street: Option<&'a str>,
+    pub street_secondary: Option<&'a str>,
+    pub city: Option<&'a str>,
+    pub region: Option<&'a str>,
+    pub postal_code: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    pub emergency_enabled: Option<bool>,
+    pub auto_correct_address: Option<bool>,
+}
+
+/// Filters accepted by `Client::list_addresses`.
+#[derive(Default)]
+pub struct AddressFilter<'a> {
+    pub customer_name: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    pub iso_country: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+impl Client {
+    pub async fn create_address(&self, address: NewAddress<'_>) -> Result<Address, TwilioError> {
+        let mut opts = vec![
+            ("CustomerName", address.customer_name),
+            ("Street", address.street),
+            ("City", address.city),
+            ("Region", address.region),
+            ("PostalCode", address.postal_code),
+            ("IsoCountry", address.iso_country),
+        ];
+        if let Some(street) = address.street_secondary {
+            opts.push(("StreetSecondary", street));
+        }
+        if let Some(name) = address.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(enabled) = address.emergency_enabled {
+            opts.push(("EmergencyEnabled", bool_str(enabled)));
+        }
+        if let Some(correct) = address.auto_correct_address {
+            opts.push(("AutoCorrectAddress", bool_str(correct)));
+        }
+        self.send_request(POST, "Addresses", &opts).await
+    }
+
+    pub fn list_addresses(&self, filter: &AddressFilter<'_>) -> PageIterator<Address> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(name) = filter.customer_name {
+            opts.push(("CustomerName", name));
+        }
+        if let Some(name) = filter.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(country) = filter.iso_country {
+            opts.push(("IsoCountry", country));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("Addresses", "addresses", &opts)
+    }
+
+    pub async fn retrieve_address(&self, sid: &AddressSid) -> Result<Address, TwilioError> {
+        self.send_request(GET, &format!("Addresses/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_address(
+        &self,
+        sid: &AddressSid,
+        update: AddressUpdate<'_>,
+    ) -> Result<Address, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = update.customer_name {
+            opts.push(("CustomerName", name));
+        }
+        if let Some(street) = update.street {
+            opts.push(("Street", street));
+        }
+        if let Some(street) = update.street_secondary {
+            opts.push(("StreetSecondary", street));
+        }
+        if let Some(city) = update.city {
+            opts.push(("City", city));
+        }
+        if let Some(region) = update.region {
+            opts.push(("Region", region));
+        }
+        if let Some(code) = update.postal_code {
+            opts.push(("PostalCode", code));
+        }
+        if let Some(name) = update.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(enabled) = update.emergency_enabled {
+            opts.push(("EmergencyEnabled", bool_str(enabled)));
+        }
+        if let Some(correct) = update.auto_correct_address {
+            opts.push(("AutoCorrectAddress", bool_str(correct)));
+        }
+        self.send_request(POST, &format!("Addresses/{sid}"), &opts)
+            .await
+    }
+
+    /// Deletes an address. Twilio refuses while numbers still depend on it;
+    /// see `list_dependent_phone_numbers`.
+    pub async fn delete_address(&self, sid: &AddressSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Addresses/{sid}")).await
+    }
+
+    /// Lists the phone numbers that require this address to stay on file.
+    pub fn list_dependent_phone_numbers(
+        &self,
+        sid: &AddressSid,
+    ) -> PageIterator<IncomingPhoneNumber> {
+        self.list(
+            &format!("Addresses/{sid}/DependentPhoneNumbers"),
+            "dependent_phone_numbers",
+            &[],
+        )
+    }
+}
@@ -0,0 +1,122 @@
+use crate::twiml::Method;
+use crate::{AccountSid, ApplicationSid, Client, PageIterator, Timestamp, TwilioError, GET, POST};
+use serde::Deserialize;
+
+/// A TwiML app: a set of voice and messaging URLs that phone numbers and
+/// client tokens can point at instead of configuring their own.
+#[derive(Debug, Deserialize)]
+pub struct Application {
+    pub sid: ApplicationSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub voice_fallback_url: Option<String>,
+    pub voice_fallback_method: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<String>,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub sms_fallback_url: Option<String>,
+    pub sms_fallback_method: Option<String>,
+    pub message_status_callback: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Settings applied when creating or updating an application. Fields left as
+/// `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct ApplicationConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub voice_url: Option<&'a str>,
+    pub voice_method: Option<Method>,
+    pub voice_fallback_url: Option<&'a str>,
+    pub voice_fallback_method: Option<Method>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub sms_url: Option<&'a str>,
+    pub sms_method: Option<Method>,
+    pub sms_fallback_url: Option<&'a str>,
+    pub sms_fallback_method: Option<Method>,
+    pub message_status_callback: Option<&'a str>,
+}
+
+impl<'a> ApplicationConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let urls = [
+            ("VoiceUrl", self.voice_url),
+            ("VoiceFallbackUrl", self.voice_fallback_url),
+            ("StatusCallback", self.status_callback),
+            ("SmsUrl", self.sms_url),
+            ("SmsFallbackUrl", self.sms_fallback_url),
+            ("MessageStatusCallback", self.message_status_callback),
+        ];
+        let methods = [
+            ("VoiceMethod", &self.voice_method),
+            ("VoiceFallbackMethod", &self.voice_fallback_method),
+            ("StatusCallbackMethod", &self.status_callback_method),
+            ("SmsMethod", &self.sms_method),
+            ("SmsFallbackMethod", &self.sms_fallback_method),
+        ];
+
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        for (key, url) in urls {
+            if let Some(url) = url {
+                opts.push((key, url));
+            }
+        }
+        for (key, method) in methods {
+            if let Some(method) = method {
+                opts.push((key, method.as_str()));
+            }
+        }
+        opts
+    }
+}
+
+impl Client {
+    pub async fn create_application(
+        &self,
+        config: ApplicationConfig<'_>,
+    ) -> Result<Application, TwilioError> {
+        self.send_request(POST, "Applications", &config.params())
+            .await
+    }
+
+    /// Lists applications, optionally only those named `friendly_name`.
+    pub fn list_applications(&self, friendly_name: Option<&str>) -> PageIterator<Application> {
+        let mut opts = Vec::new();
+        if let Some(name) = friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        self.list("Applications", "applications", &opts)
+    }
+
+    pub async fn retrieve_application(
+        &self,
+        sid: &ApplicationSid,
+    ) -> Result<Application, TwilioError> {
+        self.send_request(GET, &format!("Applications/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_application(
+        &self,
+        sid: &ApplicationSid,
+        config: ApplicationConfig<'_>,
+    ) -> Result<Application, TwilioError> {
+        self.send_request(POST, &format!("Applications/{sid}"), &config.params())
+            .await
+    }
+
+    pub async fn delete_application(&self, sid: &ApplicationSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Applications/{sid}")).await
+    }
+}
@@ -0,0 +1,375 @@
+//! How requests prove who sent them: HTTP basic auth with an auth token or
+//! API key, fixed or fetched from a `CredentialsProvider`, or an OAuth bearer
+//! token for accounts that have turned basic auth off. Public Key Client
+//! Validation signs requests on top of any of these.
+
+use crate::timer::Instant;
+use crate::TwilioError;
+use hyper::header::{HeaderValue, InvalidHeaderValue};
+use serde::Deserialize;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// A username and password for HTTP basic auth: an account SID and its auth
+/// token, or an API key SID and its secret.
+#[derive(Clone, Debug)]
+pub struct BasicCredentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl BasicCredentials {
+    pub fn new(username: &str, password: &str) -> BasicCredentials {
+        BasicCredentials {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
+}
+
+pub type CredentialsFuture =
+    Pin<Box<dyn Future<Output = Result<BasicCredentials, TwilioError>> + Send>>;
+
+/// Supplies the credentials for each request, so that an auth token or API
+/// key can be rotated without rebuilding the `Client`. Register one with
+/// `ClientBuilder::with_provider`.
+///
+/// The provider is asked before every attempt, so sources that are slow to
+/// read, such as a secrets manager, should cache what they fetch. Any async
+/// closure returning `BasicCredentials` is a provider:
+///
+/// ```ignore
+/// let client = ClientBuilder::with_provider(ACCOUNT_SID, move || {
+///     let vault = vault.clone();
+///     async move { vault.twilio_api_key().await }
+/// })
+/// .build()?;
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    fn credentials(&self) -> CredentialsFuture;
+}
+
+impl<F, Fut> CredentialsProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<BasicCredentials, TwilioError>> + Send + 'static,
+{
+    fn credentials(&self) -> CredentialsFuture {
+        Box::pin(self())
+    }
+}
+
+/// Reads credentials from environment variables on every request, by default
+/// `TWILIO_ACCOUNT_SID` and `TWILIO_AUTH_TOKEN`.
+pub struct EnvCredentials {
+    username_var: String,
+    password_var: String,
+}
+
+impl EnvCredentials {
+    pub fn new() -> EnvCredentials {
+        EnvCredentials::from_vars("TWILIO_ACCOUNT_SID", "TWILIO_AUTH_TOKEN")
+    }
+
+    /// Reads an API key from `TWILIO_API_KEY` and `TWILIO_API_SECRET`.
+    pub fn api_key() -> EnvCredentials {
+        EnvCredentials::from_vars("TWILIO_API_KEY", "TWILIO_API_SECRET")
+    }
+
+    pub fn from_vars(username_var: &str, password_var: &str) -> EnvCredentials {
+        EnvCredentials {
+            username_var: username_var.to_string(),
+            password_var: password_var.to_string(),
+        }
+    }
+}
+
+impl Default for EnvCredentials {
+    fn default() -> EnvCredentials {
+        EnvCredentials::new()
+    }
+}
+
+impl CredentialsProvider for EnvCredentials {
+    fn credentials(&self) -> CredentialsFuture {
+        let var = |name: &str| {
+            std::env::var(name)
+                .map_err(|e| TwilioError::Credentials(format!("reading `{name}`: {e}").into()))
+        };
+        let credentials = var(&self.username_var).and_then(|username| {
+            Ok(BasicCredentials {
+                username,
+                password: var(&self.password_var)?,
+            })
+        });
+        Box::pin(async move { credentials })
+    }
+}
+
+/// Reads the password from a file, such as a mounted Kubernetes secret.
+/// Leading and trailing whitespace is ignored.
+///
+/// So that requests don't each wait on the filesystem, the file is checked
+/// at most once per `check_interval`, 10 seconds by default, and only read
+/// again when its modification time has changed.
+pub struct FileCredentials {
+    username: String,
+    path: PathBuf,
+    check_interval: Duration,
+    cached: Mutex<Option<CachedPassword>>,
+}
+
+struct CachedPassword {
+    checked_at: Instant,
+    modified: SystemTime,
+    password: String,
+}
+
+impl FileCredentials {
+    pub fn new(username: &str, password_path: impl Into<PathBuf>) -> FileCredentials {
+        FileCredentials {
+            username: username.to_string(),
+            path: password_path.into(),
+            check_interval: Duration::from_secs(10),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// How long to use the password before checking the file for a new one.
+    /// A rotated password is picked up at most this long after it is written.
+    pub fn check_interval(mut self, interval: Duration) -> FileCredentials {
+        self.check_interval = interval;
+        self
+    }
+
+    fn read(&self) -> std::io::Result<String> {
+        let mut cached = self.cached.lock().unwrap();
+        let now = Instant::now();
+        if let Some(ref c) = *cached {
+            if now.duration_since(c.checked_at) < self.check_interval {
+                return Ok(c.password.clone());
+            }
+        }
+        let modified = std::fs::metadata(&self.path)?.modified()?;
+        let password = match cached.take() {
+            Some(c) if c.modified == modified => c.password,
+            _ => std::fs::read_to_string(&self.path)?.trim().to_string(),
+        };
+        *cached = Some(CachedPassword {
+            checked_at: now,
+            modified,
+            password: password.clone(),
+        });
+        Ok(password)
+    }
+}
+
+impl CredentialsProvider for FileCredentials {
+    fn credentials(&self) -> CredentialsFuture {
+        let credentials = self
+            .read()
+            .map(|password| BasicCredentials {
+                username: self.username.clone(),
+                password,
+            })
+            .map_err(|e| {
+                TwilioError::Credentials(format!("reading {}: {e}", self.path.display()).into())
+            });
+        Box::pin(async move { credentials })
+    }
+}
+
+/// The `Authorization` header for one request, and who it authenticates as.
+#[derive(Clone)]
+pub(crate) struct RequestAuth {
+    pub(crate) header: HeaderValue,
+    #[cfg_attr(not(feature = "client-validation"), allow(dead_code))]
+    pub(crate) username: String,
+}
+
+impl RequestAuth {
+    pub(crate) fn basic(credentials: &BasicCredentials) -> Result<RequestAuth, InvalidHeaderValue> {
+        let encoded = base64::encode(format!("{}:{}", credentials.username, credentials.password));
+        let mut header = HeaderValue::from_str(&format!("Basic {encoded}"))?;
+        header.set_sensitive(true);
+        Ok(RequestAuth {
+            header,
+            username: credentials.username.clone(),
+        })
+    }
+}
+
+pub(crate) enum Credentials {
+    Basic(RequestAuth),
+    OAuth(OAuthToken),
+    Provider(Box<dyn CredentialsProvider>),
+}
+
+impl Credentials {
+    pub(crate) fn basic(username: &str, password: &str) -> Credentials {
+        let auth = RequestAuth::basic(&BasicCredentials::new(username, password))
+            .expect("credentials must be valid header text");
+        Credentials::Basic(auth)
+    }
+}
+
+/// An OAuth 2.0 client credentials grant, holding the latest access token
+/// until shortly before it expires.
+pub(crate) struct OAuthToken {
+    pub(crate) client_id: String,
+    pub(crate) client_secret: String,
+    current: Mutex<Option<(HeaderValue, Instant)>>,
+}
+
+/// Tokens are refreshed this long before Twilio says they expire, so a token
+/// never runs out mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+pub(crate) struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+impl OAuthToken {
+    pub(crate) fn new(client_id: &str, client_secret: &str) -> OAuthToken {
+        OAuthToken {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            current: Mutex::new(None),
+        }
+    }
+
+    /// The cached `Authorization` header, unless it is missing or about to
+    /// expire.
+    pub(crate) fn current(&self) -> Option<RequestAuth> {
+        match *self.current.lock().unwrap() {
+            Some((ref header, expires)) if Instant::now() + EXPIRY_MARGIN < expires => {
+                Some(self.auth(header.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn store(&self, token: TokenResponse) -> Result<RequestAuth, InvalidHeaderValue> {
+        let mut header = HeaderValue::from_str(&format!("Bearer {}", token.access_token))?;
+        header.set_sensitive(true);
+        let expires = Instant::now() + Duration::from_secs(token.expires_in);
+        *self.current.lock().unwrap() = Some((header.clone(), expires));
+        Ok(self.auth(header))
+    }
+
+    fn auth(&self, header: HeaderValue) -> RequestAuth {
+        RequestAuth {
+            header,
+            username: self.client_id.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "client-validation")]
+pub use self::validation::ClientValidation;
+
+#[cfg(feature = "client-validation")]
+mod validation {
+    use crate::timer::{SystemTime, UNIX_EPOCH};
+    use openssl::error::ErrorStack;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::{PKey, Private};
+    use openssl::sign::Signer;
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+    use std::sync::Arc;
+
+    /// The headers covered by each signature, in canonical order.
+    const SIGNED_HEADERS: &str = "authorization;host";
+
+    /// Signs requests for [Public Key Client Validation](https://www.twilio.com/docs/iam/pkcv),
+    /// with the private half of a key uploaded as a public key credential
+    /// (`CR...`). Twilio only accepts signed requests authenticated with an
+    /// API key, so pair it with `ClientBuilder::with_api_key`.
+    #[derive(Clone)]
+    pub struct ClientValidation {
+        credential_sid: String,
+        key: Arc<PKey<Private>>,
+    }
+
+    impl ClientValidation {
+        /// Reads a PEM-encoded RSA private key, PKCS#1 or PKCS#8.
+        pub fn from_pem(
+            credential_sid: &str,
+            private_key_pem: &[u8],
+        ) -> Result<ClientValidation, ErrorStack> {
+            Ok(ClientValidation {
+                credential_sid: credential_sid.to_string(),
+                key: Arc::new(PKey::private_key_from_pem(private_key_pem)?),
+            })
+        }
+
+        /// The `Twilio-Client-Validation` JWT for a request, covering its
+        /// method, URI, `Authorization` header and body. Fails if the key
+        /// can't make an RS256 signature, e.g. because it isn't an RSA key.
+        pub(crate) fn sign(
+            &self,
+            api_key_sid: &str,
+            account_sid: &str,
+            method: &str,
+            uri: &hyper::Uri,
+            authorization: &str,
+            body: &[u8],
+        ) -> Result<String, ErrorStack> {
+            let mut query: Vec<_> = uri.query().unwrap_or("").split('&').collect();
+            query.retain(|q| !q.is_empty());
+            query.sort_unstable();
+            let canonical_request = [
+                method.to_uppercase(),
+                uri.path().to_string(),
+                query.join("&"),
+                format!(
+                    "authorization:{}\nhost:{}\n",
+                    authorization.trim(),
+                    uri.host().unwrap_or("")
+                ),
+                SIGNED_HEADERS.to_string(),
+                hex(&Sha256::digest(body)),
+            ]
+            .join("\n");
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let header = json!({
+                "typ": "JWT",
+                "alg": "RS256",
+                "cty": "twilio-pkrv;v=1",
+                "kid": self.credential_sid,
+            });
+            let payload = json!({
+                "iss": api_key_sid,
+                "sub": account_sid,
+                "exp": now + 300,
+                "hrh": SIGNED_HEADERS,
+                "rqh": hex(&Sha256::digest(canonical_request.as_bytes())),
+            });
+            let mut token = format!(
+                "{}.{}",
+                base64::encode_config(header.to_string(), base64::URL_SAFE_NO_PAD),
+                base64::encode_config(payload.to_string(), base64::URL_SAFE_NO_PAD)
+            );
+            let mut signer = Signer::new(MessageDigest::sha256(), &self.key)?;
+            signer.update(token.as_bytes())?;
+            let signature = signer.sign_to_vec()?;
+            token.push('.');
+            token.push_str(&base64::encode_config(signature, base64::URL_SAFE_NO_PAD));
+            Ok(token)
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{bool_str, Capabilities, Client, TwilioError, GET};
+use serde::Deserialize;
+
+pub enum NumberType {
+    Local,
+    TollFree,
+    Mobile,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AvailablePhoneNumber {
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub iso_country: Option<String>,
+    pub lata: Option<String>,
+    pub rate_center: Option<String>,
+    pub latitude: Option<String>,
+    pub longitude: Option<String>,
+    pub address_requirements: Option<String>,
+    pub beta: Option<bool>,
+    pub capabilities: Option<Capabilities>,
+}
+
+/// Filters accepted by `Client::search_available_numbers`. `contains` takes a
+/// pattern of digits and `*` wildcards, e.g. `"510555****"`.
+#[derive(Default)]
+pub struct AvailablePhoneNumberFilter<'a> {
+    pub area_code: Option<u32>,
+    pub contains: Option<&'a str>,
+    pub sms_enabled: Option<bool>,
+    pub mms_enabled: Option<bool>,
+    pub voice_enabled: Option<bool>,
+    pub in_region: Option<&'a str>,
+    pub in_postal_code: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct AvailablePhoneNumbers {
+    available_phone_numbers: Vec<AvailablePhoneNumber>,
+}
+
+impl Client {
+    /// Searches for numbers that can be purchased in `country` (an ISO 3166-1
+    /// alpha-2 code such as `"US"`).
+    pub async fn search_available_numbers(
+        &self,
+        country: &str,
+        number_type: NumberType,
+        filter: &AvailablePhoneNumberFilter<'_>,
+    ) -> Result<Vec<AvailablePhoneNumber>, TwilioError> {
+        let kind = match number_type {
+            NumberType::Local => "Local",
+            NumberType::TollFree => "TollFree",
+            NumberType::Mobile => "Mobile",
+        };
+        let area_code = filter.area_code.map(|a| a.to_string());
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref a) = area_code {
+            opts.push(("AreaCode", a.as_str()));
+        }
+        if let Some(contains) = filter.contains {
+            opts.push(("Contains", contains));
+        }
+        if let Some(sms) = filter.sms_enabled {
+            opts.push(("SmsEnabled", bool_str(sms)));
+        }
+        if let Some(mms) = filter.mms_enabled {
+            opts.push(("MmsEnabled", bool_str(mms)));
+        }
+        if let Some(voice) = filter.voice_enabled {
+            opts.push(("VoiceEnabled", bool_str(voice)));
+        }
+        if let Some(region) = filter.in_region {
+            opts.push(("InRegion", region));
+        }
+        if let Some(postal_code) = filter.in_postal_code {
+            opts.push(("InPostalCode", postal_code));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        let found: AvailablePhoneNumbers = self
+            .send_request(
+                GET,
+                &format!("AvailablePhoneNumbers/{country}/{kind}"),
+                &opts,
+            )
+            .await?;
+        Ok(found.available_phone_numbers)
+    }
+}
@@ -0,0 +1,72 @@
+//! Extractors and responses for handling webhooks in [axum](https://github.com/tokio-rs/axum).
+//!
+//! The `Client` used to validate signatures is taken from the router state, so
+//! either use it as the state directly or implement `FromRef<S> for Client`.
+//!
+//! ```ignore
+//! async fn sms(TwilioRequest(msg): TwilioRequest<twilio::Message>) -> MessagingResponse {
+//!     ...
+//! }
+//!
+//! let app = Router::new().route("/sms", post(sms)).with_state(client);
+//! ```
+
+use crate::twiml::{MessagingResponse, Twiml};
+use crate::{Client, FromMap, TwilioError};
+use ::axum::async_trait;
+use ::axum::body::HttpBody;
+use ::axum::extract::{FromRef, FromRequest};
+use ::axum::http::{header, Request, StatusCode};
+use ::axum::response::{IntoResponse, Response};
+use ::axum::BoxError;
+
+/// A webhook whose `X-Twilio-Signature` has been validated, parsed into `T`.
+pub struct TwilioRequest<T>(pub T);
+
+#[async_trait]
+impl<S, B, T> FromRequest<S, B> for TwilioRequest<T>
+where
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    Client: FromRef<S>,
+    T: FromMap,
+{
+    type Rejection = TwilioError;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let client = Client::from_ref(state);
+        let (parts, body) = req.into_parts();
+        let body = hyper::body::to_bytes(body)
+            .await
+            .map_err(|_| TwilioError::BadRequest)?;
+        let req = Request::from_parts(parts, hyper::Body::from(body));
+        client
+            .parse_request::<T>(req)
+            .await
+            .map(|t| TwilioRequest(*t))
+    }
+}
+
+impl IntoResponse for TwilioError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            TwilioError::AuthError => StatusCode::FORBIDDEN,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+impl IntoResponse for Twiml {
+    fn into_response(self) -> Response {
+        ([(header::CONTENT_TYPE, "text/xml")], self.as_twiml()).into_response()
+    }
+}
+
+impl IntoResponse for MessagingResponse {
+    fn into_response(self) -> Response {
+        Twiml::from(self).into_response()
+    }
+}
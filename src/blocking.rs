@@ -0,0 +1,468 @@
+//! A synchronous `Client` for scripts and command line tools, behind the
+//! `blocking` feature.
+//!
+//! Each call runs the async request to completion on a small runtime owned by
+//! the client, so no executor is needed. The common api.twilio.com, Lookup and
+//! Verify requests are mirrored here with the same names and arguments; for
+//! anything else, pass a future from `as_async` to `block_on`:
+//!
+//! ```ignore
+//! let client = twilio::blocking::Client::new(ACCOUNT_ID, AUTH_TOKEN);
+//! client.send_message(OutboundMessage::new(FROM, TO, "Hello"))?;
+//! let execution = client.block_on(client.as_async().retrieve_execution(&flow, &sid))?;
+//! ```
+//!
+//! Calling these methods from inside an async runtime panics.
+
+#[cfg(feature = "lookup")]
+use crate::lookup::{DataPackage, PhoneNumberLookup};
+#[cfg(feature = "voice")]
+use crate::twiml::Method;
+#[cfg(feature = "verify")]
+use crate::verify::{Channel, Service, ServiceUpdate, Verification, VerificationCheck};
+#[cfg(any(feature = "voice", feature = "messaging"))]
+use crate::WithMeta;
+use crate::{
+    Account, AccountFilter, AccountSid, AccountUpdate, AvailablePhoneNumber,
+    AvailablePhoneNumberFilter, Balance, IncomingPhoneNumber, IncomingPhoneNumberFilter,
+    NumberType, Page, PageIterator, PhoneNumberConfig, PhoneNumberSid, TwilioError,
+};
+#[cfg(feature = "voice")]
+use crate::{
+    Call, CallEventRecord, CallFeedback, CallFeedbackIssue, CallFilter, CallSid, CallUpdate,
+    Conference, ConferenceFilter, ConferenceSid, FeedbackSummary, FeedbackSummarySid, Member,
+    OutboundCall, OutboundParticipant, Participant, ParticipantUpdate, Queue, QueueSid,
+    QueueUpdate, Recording, RecordingFilter, RecordingFormat, RecordingSid, Transcription,
+    TranscriptionSid,
+};
+#[cfg(feature = "messaging")]
+use crate::{
+    FeedbackOutcome, Media, MediaContent, MediaSid, Message, MessageFeedback, MessageFilter,
+    MessageSid, MessageUpdate, OutboundMessage, ShortCode, ShortCodeFilter, ShortCodeSid,
+    ShortCodeUpdate,
+};
+#[cfg(feature = "verify")]
+use crate::{VerificationSid, VerifyServiceSid};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    #[cfg(any(feature = "native-tls", feature = "isahc", feature = "reqwest"))]
+    pub fn new(account_id: &str, auth_token: &str) -> Client {
+        Client::from(crate::Client::new(account_id, auth_token))
+    }
+
+    /// See `Client::with_account`.
+    pub fn with_account(&self, account_id: &AccountSid) -> Client {
+        Client {
+            inner: self.inner.with_account(account_id),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// See `Client::with_header`.
+    pub fn with_header(
+        &self,
+        name: hyper::header::HeaderName,
+        value: hyper::header::HeaderValue,
+    ) -> Client {
+        Client {
+            inner: self.inner.with_header(name, value),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// The async client requests are sent with.
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs any future, typically a request from `as_async` that has no
+    /// blocking counterpart, to completion.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Calls an endpoint that isn't wrapped yet. See `Client::request_json`.
+    pub fn request_json<T: DeserializeOwned>(
+        &self,
+        method: hyper::Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, TwilioError> {
+        self.runtime
+            .block_on(self.inner.request_json(method, path, params))
+    }
+
+    fn iter<T>(&self, pages: PageIterator<T>) -> Iter<T> {
+        Iter {
+            pages,
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// Wraps a client configured with `ClientBuilder`.
+impl From<crate::Client> for Client {
+    fn from(inner: crate::Client) -> Client {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start the blocking client's runtime");
+        Client {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+}
+
+/// Iterates over every item of a listing, fetching further pages as needed.
+pub struct Iter<T> {
+    pages: PageIterator<T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> Iter<T> {
+    /// Fetches the next page, or returns `None` once the last page has been seen.
+    pub fn next_page(&mut self) -> Result<Option<Page<T>>, TwilioError> {
+        self.runtime.block_on(self.pages.next_page())
+    }
+}
+
+impl<T: DeserializeOwned + Send + 'static> Iterator for Iter<T> {
+    type Item = Result<T, TwilioError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pages = &mut self.pages;
+        self.runtime.block_on(std::future::poll_fn(|cx| {
+            Pin::new(&mut *pages).poll_next(cx)
+        }))
+    }
+}
+
+macro_rules! blocking {
+    (
+        $($(#[$attr:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;)*
+        lists {
+            $($(#[$lattr:meta])* fn $list:ident(&self $(, $larg:ident: $lty:ty)* $(,)?) -> $item:ty;)*
+        }
+    ) => {
+        impl Client {
+            $(
+                $(#[$attr])*
+                pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                    self.runtime.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+            $(
+                $(#[$lattr])*
+                pub fn $list(&self $(, $larg: $lty)*) -> Iter<$item> {
+                    self.iter(self.inner.$list($($larg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking! {
+    fn request_value(
+        &self,
+        method: hyper::Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, TwilioError>;
+    fn retrieve_account(&self, sid: &AccountSid) -> Result<Account, TwilioError>;
+    fn balance(&self) -> Result<Balance, TwilioError>;
+    fn update_account(
+        &self,
+        sid: &AccountSid,
+        update: AccountUpdate<'_>,
+    ) -> Result<Account, TwilioError>;
+    fn suspend_account(&self, sid: &AccountSid) -> Result<Account, TwilioError>;
+    fn activate_account(&self, sid: &AccountSid) -> Result<Account, TwilioError>;
+    fn close_account(&self, sid: &AccountSid) -> Result<Account, TwilioError>;
+    fn create_subaccount(&self, friendly_name: Option<&str>) -> Result<Account, TwilioError>;
+
+    #[cfg(feature = "messaging")]
+    fn send_message(&self, msg: OutboundMessage<'_>) -> Result<Message, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn send_message_with_meta(
+        &self,
+        msg: OutboundMessage<'_>,
+    ) -> Result<WithMeta<Message>, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn retrieve_message(&self, sid: &MessageSid) -> Result<Message, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn create_message_feedback(
+        &self,
+        sid: &MessageSid,
+        outcome: FeedbackOutcome,
+    ) -> Result<MessageFeedback, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn redact_message(&self, sid: &MessageSid) -> Result<Message, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn delete_message(&self, sid: &MessageSid) -> Result<(), TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn retrieve_media(&self, message_sid: &MessageSid, sid: &MediaSid) -> Result<Media, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn delete_media(&self, message_sid: &MessageSid, sid: &MediaSid) -> Result<(), TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn download_media(
+        &self,
+        message_sid: &MessageSid,
+        sid: &MediaSid,
+    ) -> Result<MediaContent, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn update_message(
+        &self,
+        sid: &MessageSid,
+        update: MessageUpdate<'_>,
+    ) -> Result<Message, TwilioError>;
+
+    #[cfg(feature = "voice")]
+    fn make_call(&self, call: OutboundCall<'_>) -> Result<Call, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn make_call_with_meta(&self, call: OutboundCall<'_>) -> Result<WithMeta<Call>, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_call(&self, sid: &CallSid) -> Result<Call, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn update_call(&self, sid: &CallSid, update: CallUpdate<'_>) -> Result<Call, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn transfer_call(&self, sid: &CallSid, target: &str) -> Result<Call, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn create_call_feedback(
+        &self,
+        sid: &CallSid,
+        quality_score: u8,
+        issues: &[CallFeedbackIssue],
+    ) -> Result<CallFeedback, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_call_feedback(&self, sid: &CallSid) -> Result<CallFeedback, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn create_feedback_summary(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        include_subaccounts: bool,
+        status_callback: Option<&str>,
+    ) -> Result<FeedbackSummary, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_feedback_summary(
+        &self,
+        sid: &FeedbackSummarySid,
+    ) -> Result<FeedbackSummary, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn delete_feedback_summary(&self, sid: &FeedbackSummarySid) -> Result<(), TwilioError>;
+
+    #[cfg(feature = "voice")]
+    fn retrieve_recording(&self, sid: &RecordingSid) -> Result<Recording, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn delete_recording(&self, sid: &RecordingSid) -> Result<(), TwilioError>;
+    #[cfg(feature = "voice")]
+    fn download_recording(
+        &self,
+        sid: &RecordingSid,
+        format: RecordingFormat,
+    ) -> Result<Vec<u8>, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_transcription(&self, sid: &TranscriptionSid) -> Result<Transcription, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn delete_transcription(&self, sid: &TranscriptionSid) -> Result<(), TwilioError>;
+
+    #[cfg(feature = "voice")]
+    fn retrieve_conference(&self, sid: &ConferenceSid) -> Result<Conference, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn end_conference(&self, sid: &ConferenceSid) -> Result<Conference, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+    ) -> Result<Participant, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn add_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: OutboundParticipant<'_>,
+    ) -> Result<Participant, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn update_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        update: ParticipantUpdate<'_>,
+    ) -> Result<Participant, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn mute_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        muted: bool,
+    ) -> Result<Participant, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn hold_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        hold: bool,
+    ) -> Result<Participant, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn kick_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+    ) -> Result<(), TwilioError>;
+
+    #[cfg(feature = "voice")]
+    fn create_queue(
+        &self,
+        friendly_name: &str,
+        max_size: Option<u32>,
+    ) -> Result<Queue, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_queue(&self, sid: &QueueSid) -> Result<Queue, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn update_queue(&self, sid: &QueueSid, update: QueueUpdate<'_>) -> Result<Queue, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn delete_queue(&self, sid: &QueueSid) -> Result<(), TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: &CallSid,
+    ) -> Result<Member, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn retrieve_front_member(&self, queue_sid: &QueueSid) -> Result<Member, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn dequeue_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: &CallSid,
+        url: &str,
+        method: Option<Method>,
+    ) -> Result<Member, TwilioError>;
+    #[cfg(feature = "voice")]
+    fn dequeue_front_member(
+        &self,
+        queue_sid: &QueueSid,
+        url: &str,
+        method: Option<Method>,
+    ) -> Result<Member, TwilioError>;
+
+    fn search_available_numbers(
+        &self,
+        country: &str,
+        number_type: NumberType,
+        filter: &AvailablePhoneNumberFilter<'_>,
+    ) -> Result<Vec<AvailablePhoneNumber>, TwilioError>;
+    fn retrieve_number(&self, sid: &PhoneNumberSid) -> Result<IncomingPhoneNumber, TwilioError>;
+    fn purchase_number(
+        &self,
+        phone_number: &str,
+        config: PhoneNumberConfig<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError>;
+    fn update_number(
+        &self,
+        sid: &PhoneNumberSid,
+        config: PhoneNumberConfig<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError>;
+    fn release_number(&self, sid: &PhoneNumberSid) -> Result<(), TwilioError>;
+
+    #[cfg(feature = "messaging")]
+    fn retrieve_short_code(&self, sid: &ShortCodeSid) -> Result<ShortCode, TwilioError>;
+    #[cfg(feature = "messaging")]
+    fn update_short_code(
+        &self,
+        sid: &ShortCodeSid,
+        update: ShortCodeUpdate<'_>,
+    ) -> Result<ShortCode, TwilioError>;
+
+    #[cfg(feature = "lookup")]
+    fn lookup_phone_number(
+        &self,
+        phone_number: &str,
+        country_code: Option<&str>,
+        packages: &[DataPackage],
+    ) -> Result<PhoneNumberLookup, TwilioError>;
+
+    #[cfg(feature = "verify")]
+    fn start_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        to: &str,
+        channel: Channel,
+    ) -> Result<Verification, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn check_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        to: &str,
+        code: &str,
+    ) -> Result<VerificationCheck, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn cancel_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        verification_sid: &VerificationSid,
+    ) -> Result<Verification, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn create_verify_service(
+        &self,
+        friendly_name: &str,
+        code_length: Option<u32>,
+    ) -> Result<Service, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn retrieve_verify_service(&self, sid: &VerifyServiceSid) -> Result<Service, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn update_verify_service(
+        &self,
+        sid: &VerifyServiceSid,
+        update: ServiceUpdate<'_>,
+    ) -> Result<Service, TwilioError>;
+    #[cfg(feature = "verify")]
+    fn delete_verify_service(&self, sid: &VerifyServiceSid) -> Result<(), TwilioError>;
+
+    lists {
+        fn list_accounts(&self, filter: &AccountFilter<'_>) -> Account;
+        #[cfg(feature = "messaging")]
+        fn list_messages(&self, filter: &MessageFilter<'_>) -> Message;
+        #[cfg(feature = "messaging")]
+        fn list_message_media(&self, message_sid: &MessageSid) -> Media;
+        #[cfg(feature = "voice")]
+        fn list_calls(&self, filter: &CallFilter<'_>) -> Call;
+        #[cfg(feature = "voice")]
+        fn list_call_events(&self, sid: &CallSid) -> CallEventRecord;
+        #[cfg(feature = "voice")]
+        fn list_recordings(&self, filter: &RecordingFilter<'_>) -> Recording;
+        #[cfg(feature = "voice")]
+        fn list_transcriptions(&self) -> Transcription;
+        #[cfg(feature = "voice")]
+        fn list_recording_transcriptions(&self, recording_sid: &RecordingSid) -> Transcription;
+        #[cfg(feature = "voice")]
+        fn list_conferences(&self, filter: &ConferenceFilter<'_>) -> Conference;
+        #[cfg(feature = "voice")]
+        fn list_participants(&self, conference_sid: &ConferenceSid) -> Participant;
+        #[cfg(feature = "voice")]
+        fn list_queues(&self) -> Queue;
+        #[cfg(feature = "voice")]
+        fn list_members(&self, queue_sid: &QueueSid) -> Member;
+        fn list_incoming_phone_numbers(
+            &self,
+            filter: &IncomingPhoneNumberFilter<'_>,
+        ) -> IncomingPhoneNumber;
+        #[cfg(feature = "messaging")]
+        fn list_short_codes(&self, filter: &ShortCodeFilter<'_>) -> ShortCode;
+        #[cfg(feature = "verify")]
+        fn list_verify_services(&self) -> Service;
+    }
+}
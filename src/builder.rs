@@ -0,0 +1,407 @@
+use crate::auth::{Credentials, CredentialsProvider, OAuthToken};
+use crate::rate_limit::RateLimiter;
+#[cfg(feature = "client-validation")]
+use crate::ClientValidation;
+use crate::{Client, Redaction, RequestHook, RetryPolicy, Transport, TwilioError};
+use hyper::client::connect::Connect;
+#[cfg(feature = "native-tls")]
+use hyper::client::connect::HttpConnector;
+#[cfg(feature = "native-tls")]
+use hyper_tls::HttpsConnector;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Configures a `Client` beyond the defaults used by `Client::new`.
+///
+/// ```ignore
+/// let client = Client::builder(ACCOUNT_ID, AUTH_TOKEN)
+///     .region("ie1")
+///     .edge("dublin")
+///     .timeout(Duration::from_secs(10))
+///     .retry_policy(RetryPolicy::none())
+///     .build()?;
+/// ```
+pub struct ClientBuilder {
+    account_id: String,
+    credentials: Credentials,
+    auth_token: Option<String>,
+    #[cfg(feature = "client-validation")]
+    client_validation: Option<ClientValidation>,
+    region: Option<String>,
+    edge: Option<String>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<u32>,
+    #[cfg(feature = "voice")]
+    calls_per_second: Option<u32>,
+    hooks: Vec<Box<dyn RequestHook>>,
+    debug_logging: Option<Redaction>,
+    #[cfg(feature = "proxy")]
+    proxy: Option<hyper::Uri>,
+    #[cfg(feature = "native-tls")]
+    tls: Option<native_tls::TlsConnector>,
+    #[cfg(feature = "native-tls")]
+    pool: PoolSettings,
+    http_client: Option<Arc<dyn Transport>>,
+}
+
+impl ClientBuilder {
+    pub fn new(account_id: &str, auth_token: &str) -> ClientBuilder {
+        ClientBuilder::with_credentials(
+            account_id,
+            Credentials::basic(account_id, auth_token),
+            Some(auth_token),
+        )
+    }
+
+    /// Authenticates with an API key (`SK...`) and its secret instead of the
+    /// account's auth token. Webhooks are still signed with the auth token, so
+    /// set it with `webhook_auth_token` to validate them with this client.
+    pub fn with_api_key(
+        account_id: &str,
+        api_key_sid: &str,
+        api_key_secret: &str,
+    ) -> ClientBuilder {
+        ClientBuilder::with_credentials(
+            account_id,
+            Credentials::basic(api_key_sid, api_key_secret),
+            None,
+        )
+    }
+
+    /// Authenticates with OAuth 2.0 bearer tokens, for accounts where auth
+    /// token and API key access is turned off. The client fetches an access
+    /// token with the client credentials grant before its first request and
+    /// again whenever the token nears expiry. As with `with_api_key`, set
+    /// `webhook_auth_token` to validate webhooks.
+    pub fn with_oauth(account_id: &str, client_id: &str, client_secret: &str) -> ClientBuilder {
+        ClientBuilder::with_credentials(
+            account_id,
+            Credentials::OAuth(OAuthToken::new(client_id, client_secret)),
+            None,
+        )
+    }
+
+    /// Asks `provider` for the credentials to send with each request, so an
+    /// auth token or API key can be rotated while the client is running. Set
+    /// `webhook_auth_token` to validate webhooks, which are always signed
+    /// with the account's current auth token.
+    pub fn with_provider<P: CredentialsProvider + 'static>(
+        account_id: &str,
+        provider: P,
+    ) -> ClientBuilder {
+        ClientBuilder::with_credentials(account_id, Credentials::Provider(Box::new(provider)), None)
+    }
+
+    fn with_credentials(
+        account_id: &str,
+        credentials: Credentials,
+        auth_token: Option<&str>,
+    ) -> ClientBuilder {
+        ClientBuilder {
+            account_id: account_id.to_string(),
+            credentials,
+            auth_token: auth_token.map(|t| t.to_string()),
+            #[cfg(feature = "client-validation")]
+            client_validation: None,
+            region: None,
+            edge: None,
+            connect_timeout: None,
+            request_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: None,
+            #[cfg(feature = "voice")]
+            calls_per_second: None,
+            hooks: Vec::new(),
+            debug_logging: None,
+            #[cfg(feature = "proxy")]
+            proxy: None,
+            #[cfg(feature = "native-tls")]
+            tls: None,
+            #[cfg(feature = "native-tls")]
+            pool: PoolSettings::default(),
+            http_client: None,
+        }
+    }
+
+    /// The auth token used to validate `X-Twilio-Signature` on webhooks.
+    pub fn webhook_auth_token(mut self, auth_token: &str) -> ClientBuilder {
+        self.auth_token = Some(auth_token.to_string());
+        self
+    }
+
+    /// Signs every request with `validation`, for accounts that enforce
+    /// Public Key Client Validation.
+    #[cfg(feature = "client-validation")]
+    pub fn client_validation(mut self, validation: ClientValidation) -> ClientBuilder {
+        self.client_validation = Some(validation);
+        self
+    }
+
+    /// Processes requests in a Twilio region such as `"ie1"` or `"au1"`,
+    /// keeping data in that region.
+    pub fn region(mut self, region: &str) -> ClientBuilder {
+        self.region = Some(region.to_string());
+        self
+    }
+
+    /// Connects through a Twilio edge location such as `"dublin"` or
+    /// `"sydney"`. Without a region, this routes to `us1`.
+    pub fn edge(mut self, edge: &str) -> ClientBuilder {
+        self.edge = Some(edge.to_string());
+        self
+    }
+
+    /// Limits how long establishing a TCP connection may take. Ignored by the
+    /// `reqwest` backend on `wasm32`, where the browser's `fetch` connects.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Limits how long a whole request may take, from sending it to reading
+    /// the last byte of the response. Requests that run over fail with
+    /// `TwilioError::Timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Replaces the default `RetryPolicy`. Use `RetryPolicy::none()` to send
+    /// each request exactly once.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> ClientBuilder {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Holds requests back so that no more than `per_second` are sent each
+    /// second, counting retries, across this client and all of its clones.
+    pub fn rate_limit(mut self, per_second: u32) -> ClientBuilder {
+        self.rate_limit = Some(per_second);
+        self
+    }
+
+    /// Limits how many outbound calls are placed each second, by `make_call`
+    /// and `add_participant`, to match the account's CPS (calls per second).
+    #[cfg(feature = "voice")]
+    pub fn calls_per_second(mut self, cps: u32) -> ClientBuilder {
+        self.calls_per_second = Some(cps);
+        self
+    }
+
+    /// Adds a hook that is told about every request. Hooks run in the order
+    /// they were added.
+    pub fn hook<H: RequestHook + 'static>(mut self, hook: H) -> ClientBuilder {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Logs every request's parameters and every response's body at debug
+    /// level, under the `twilio` target of the `log` crate, for tracking down
+    /// integration problems. Credentials are always masked; `redaction` picks
+    /// whether phone numbers are too.
+    pub fn debug_logging(mut self, redaction: Redaction) -> ClientBuilder {
+        self.debug_logging = Some(redaction);
+        self
+    }
+
+    /// Sends every request through an HTTP or HTTPS proxy, tunnelling with
+    /// `CONNECT`.
+    #[cfg(feature = "proxy")]
+    pub fn proxy(mut self, uri: hyper::Uri) -> ClientBuilder {
+        self.proxy = Some(uri);
+        self
+    }
+
+    /// Uses custom TLS settings, such as extra root certificates or a client
+    /// identity, when connecting to Twilio.
+    #[cfg(feature = "native-tls")]
+    pub fn tls_connector(mut self, tls: native_tls::TlsConnector) -> ClientBuilder {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Caps how many idle connections are kept open to each Twilio host.
+    /// Clones of the client share one pool, so size it for all of them.
+    #[cfg(feature = "native-tls")]
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> ClientBuilder {
+        self.pool.max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// How long an idle connection is kept for reuse; hyper's default is 90
+    /// seconds.
+    #[cfg(feature = "native-tls")]
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.pool.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends TCP keep-alive probes on idle connections after `interval`, so
+    /// that NAT gateways and load balancers don't silently drop them.
+    #[cfg(feature = "native-tls")]
+    pub fn tcp_keepalive(mut self, interval: Duration) -> ClientBuilder {
+        self.pool.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Speaks HTTP/2 to Twilio, multiplexing concurrent requests over one
+    /// connection per host instead of opening one connection for each. Unless
+    /// `tls_connector` is set, `h2` is requested during the TLS handshake.
+    #[cfg(feature = "native-tls")]
+    pub fn http2_only(mut self, enabled: bool) -> ClientBuilder {
+        self.pool.http2_only = enabled;
+        self
+    }
+
+    /// Pings HTTP/2 connections every `interval`, even while idle, closing
+    /// them if a ping goes unanswered.
+    #[cfg(feature = "native-tls")]
+    pub fn http2_keep_alive(mut self, interval: Duration) -> ClientBuilder {
+        self.pool.http2_keep_alive = Some(interval);
+        self
+    }
+
+    /// Lets HTTP/2 flow control windows grow with the connection's measured
+    /// bandwidth.
+    #[cfg(feature = "native-tls")]
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> ClientBuilder {
+        self.pool.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Sends requests with a `hyper::Client` you have configured yourself. The
+    /// connect timeout, proxy and TLS settings on this builder are then ignored.
+    pub fn http_client<C>(mut self, client: hyper::Client<C>) -> ClientBuilder
+    where
+        C: Connect + Clone + Send + Sync + 'static,
+    {
+        self.http_client = Some(Arc::new(client));
+        self
+    }
+
+    /// Sends requests through a custom `Transport`, such as a `MockTransport`
+    /// in tests. As with `http_client`, connection settings on this builder are
+    /// then ignored.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> ClientBuilder {
+        self.http_client = Some(Arc::new(transport));
+        self
+    }
+
+    #[cfg(feature = "native-tls")]
+    fn build_http_client(&mut self) -> Result<Arc<dyn Transport>, TwilioError> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(self.connect_timeout);
+        http.set_keepalive(self.pool.tcp_keepalive);
+        let tls = match self.tls.take() {
+            Some(t) => t,
+            None => {
+                let mut tls = native_tls::TlsConnector::builder();
+                if self.pool.http2_only {
+                    tls.request_alpns(&["h2"]);
+                }
+                tls.build()
+                    .map_err(|e| TwilioError::TransportError(Box::new(e)))?
+            }
+        };
+        let hyper = self.pool.hyper_builder();
+
+        #[cfg(feature = "proxy")]
+        if let Some(uri) = self.proxy.take() {
+            let proxy = hyper_proxy::Proxy::new(hyper_proxy::Intercept::All, uri);
+            let https = HttpsConnector::from((http, tls.clone().into()));
+            let mut connector = hyper_proxy::ProxyConnector::from_proxy(https, proxy)
+                .map_err(|e| TwilioError::TransportError(Box::new(e)))?;
+            connector.set_tls(Some(tls));
+            return Ok(Arc::new(hyper.build::<_, hyper::Body>(connector)));
+        }
+
+        let https = HttpsConnector::from((http, tls.into()));
+        Ok(Arc::new(hyper.build::<_, hyper::Body>(https)))
+    }
+
+    #[cfg(all(feature = "isahc", not(feature = "native-tls")))]
+    fn build_http_client(&mut self) -> Result<Arc<dyn Transport>, TwilioError> {
+        let transport = crate::IsahcTransport::with_connect_timeout(self.connect_timeout)
+            .map_err(|e| TwilioError::TransportError(Box::new(e)))?;
+        Ok(Arc::new(transport))
+    }
+
+    #[cfg(all(
+        feature = "reqwest",
+        not(any(feature = "native-tls", feature = "isahc"))
+    ))]
+    fn build_http_client(&mut self) -> Result<Arc<dyn Transport>, TwilioError> {
+        let transport = crate::ReqwestTransport::with_connect_timeout(self.connect_timeout)
+            .map_err(|e| TwilioError::TransportError(Box::new(e)))?;
+        Ok(Arc::new(transport))
+    }
+
+    #[cfg(not(any(feature = "native-tls", feature = "isahc", feature = "reqwest")))]
+    fn build_http_client(&mut self) -> Result<Arc<dyn Transport>, TwilioError> {
+        Err(TwilioError::NoHttpBackend)
+    }
+
+    /// Fails if the HTTP backend's TLS can't be set up, or with
+    /// `TwilioError::NoHttpBackend` when no `transport` was given to a build
+    /// without a backend feature.
+    pub fn build(mut self) -> Result<Client, TwilioError> {
+        let http_client = match self.http_client.take() {
+            Some(c) => c,
+            None => self.build_http_client()?,
+        };
+        Ok(Client {
+            credentials: Arc::new(self.credentials),
+            #[cfg(feature = "client-validation")]
+            client_validation: self.client_validation,
+            account_id: self.account_id,
+            auth_token: self.auth_token,
+            region: self.region,
+            edge: self.edge,
+            request_timeout: self.request_timeout,
+            retry_policy: self.retry_policy,
+            http_client,
+            rate_limiter: self.rate_limit.map(|r| Arc::new(RateLimiter::new(r))),
+            #[cfg(feature = "voice")]
+            call_limiter: self.calls_per_second.map(|r| Arc::new(RateLimiter::new(r))),
+            hooks: self.hooks.into(),
+            debug_logging: self.debug_logging,
+            extra_headers: Arc::default(),
+        })
+    }
+}
+
+/// Connection pool and HTTP/2 settings for the default hyper backend.
+#[cfg(feature = "native-tls")]
+#[derive(Default)]
+struct PoolSettings {
+    max_idle_per_host: Option<usize>,
+    idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http2_only: bool,
+    http2_keep_alive: Option<Duration>,
+    http2_adaptive_window: bool,
+}
+
+#[cfg(feature = "native-tls")]
+impl PoolSettings {
+    fn hyper_builder(&self) -> hyper::client::Builder {
+        let mut builder = hyper::Client::builder();
+        if let Some(max) = self.max_idle_per_host {
+            builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.idle_timeout {
+            builder.pool_idle_timeout(timeout);
+        }
+        builder.http2_only(self.http2_only);
+        if let Some(interval) = self.http2_keep_alive {
+            builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+        builder.http2_adaptive_window(self.http2_adaptive_window);
+        builder
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{Client, FromMap, TwilioError, GET, POST};
+use crate::phone;
+use crate::twiml::{self, Dial, DialNoun, Method, Number, Sip, Twiml};
+use crate::{
+    bool_str, from_params, AccountSid, CallSid, Client, FeedbackSummarySid, FromMap, Money,
+    PageIterator, Timestamp, TwilioError, WithMeta, GET, POST,
+};
 use serde::Deserialize;
 use std::collections::BTreeMap;
 
@@ -7,27 +12,270 @@ pub enum CallInstructions<'a> {
     Twiml(&'a str),
 }
 
+/// The call progress events Twilio can report to a `status_callback`.
+pub enum CallEvent {
+    Initiated,
+    Ringing,
+    Answered,
+    Completed,
+}
+
+impl CallEvent {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            CallEvent::Initiated => "initiated",
+            CallEvent::Ringing => "ringing",
+            CallEvent::Answered => "answered",
+            CallEvent::Completed => "completed",
+        }
+    }
+}
+
+pub enum MachineDetection {
+    /// Returns as soon as a human or machine is detected.
+    Enable,
+    /// Waits for the end of a voicemail greeting, so a message can be left.
+    DetectMessageEnd,
+}
+
 pub struct OutboundCall<'a> {
     pub from: &'a str,
     pub to: &'a str,
     pub instructions: CallInstructions<'a>,
+    pub status_callback: Option<&'a str>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_events: Vec<CallEvent>,
+    pub timeout_seconds: Option<u32>,
+    pub record: Option<bool>,
+    pub trim: Option<bool>,
+    pub machine_detection: Option<MachineDetection>,
+    pub machine_detection_timeout: Option<u32>,
+    pub machine_detection_speech_threshold: Option<u32>,
+    pub machine_detection_speech_end_threshold: Option<u32>,
+    pub machine_detection_silence_timeout: Option<u32>,
+    pub async_amd_status_callback: Option<&'a str>,
+    pub async_amd_status_callback_method: Option<Method>,
+    pub send_digits: Option<&'a str>,
+    pub caller_id: Option<&'a str>,
+    pub sip_auth_username: Option<&'a str>,
+    pub sip_auth_password: Option<&'a str>,
+    /// Custom headers sent on the INVITE when `to` is a SIP address.
+    pub sip_headers: Vec<(&'a str, &'a str)>,
+    pub idempotency_token: Option<&'a str>,
 }
 
 impl<'a> OutboundCall<'a> {
     pub fn new(from: &'a str, to: &'a str, url: &'a str) -> OutboundCall<'a> {
-        OutboundCall {
-            from,
-            to,
-            instructions: CallInstructions::Url(url),
-        }
+        OutboundCall::with_instructions(from, to, CallInstructions::Url(url))
     }
 
     pub fn new_with_twiml(from: &'a str, to: &'a str, twiml: &'a str) -> OutboundCall<'a> {
+        OutboundCall::with_instructions(from, to, CallInstructions::Twiml(twiml))
+    }
+
+    fn with_instructions(
+        from: &'a str,
+        to: &'a str,
+        instructions: CallInstructions<'a>,
+    ) -> OutboundCall<'a> {
         OutboundCall {
             from,
             to,
-            instructions: CallInstructions::Twiml(twiml),
+            instructions,
+            status_callback: None,
+            status_callback_method: None,
+            status_callback_events: Vec::new(),
+            timeout_seconds: None,
+            record: None,
+            trim: None,
+            machine_detection: None,
+            machine_detection_timeout: None,
+            machine_detection_speech_threshold: None,
+            machine_detection_speech_end_threshold: None,
+            machine_detection_silence_timeout: None,
+            async_amd_status_callback: None,
+            async_amd_status_callback_method: None,
+            send_digits: None,
+            caller_id: None,
+            sip_auth_username: None,
+            sip_auth_password: None,
+            sip_headers: Vec::new(),
+            idempotency_token: None,
+        }
+    }
+
+    /// Asks Twilio to report `events` to `url`. With no events, Twilio only
+    /// reports `completed`.
+    pub fn status_callback(
+        &mut self,
+        url: &'a str,
+        events: Vec<CallEvent>,
+    ) -> &mut OutboundCall<'a> {
+        self.status_callback = Some(url);
+        self.status_callback_events = events;
+        self
+    }
+
+    pub fn status_callback_method(&mut self, method: Method) -> &mut OutboundCall<'a> {
+        self.status_callback_method = Some(method);
+        self
+    }
+
+    /// How long to let the call ring before giving up.
+    pub fn timeout(&mut self, seconds: u32) -> &mut OutboundCall<'a> {
+        self.timeout_seconds = Some(seconds);
+        self
+    }
+
+    pub fn record(&mut self, record: bool) -> &mut OutboundCall<'a> {
+        self.record = Some(record);
+        self
+    }
+
+    /// Whether to trim leading and trailing silence from the recording.
+    pub fn trim(&mut self, trim: bool) -> &mut OutboundCall<'a> {
+        self.trim = Some(trim);
+        self
+    }
+
+    pub fn machine_detection(&mut self, detection: MachineDetection) -> &mut OutboundCall<'a> {
+        self.machine_detection = Some(detection);
+        self
+    }
+
+    /// How long, in seconds, to wait for a detection result before reporting
+    /// `unknown`.
+    pub fn machine_detection_timeout(&mut self, seconds: u32) -> &mut OutboundCall<'a> {
+        self.machine_detection_timeout = Some(seconds);
+        self
+    }
+
+    /// Speech longer than this many milliseconds is classified as a machine.
+    pub fn machine_detection_speech_threshold(&mut self, millis: u32) -> &mut OutboundCall<'a> {
+        self.machine_detection_speech_threshold = Some(millis);
+        self
+    }
+
+    /// How much silence, in milliseconds, ends a stretch of speech.
+    pub fn machine_detection_speech_end_threshold(&mut self, millis: u32) -> &mut OutboundCall<'a> {
+        self.machine_detection_speech_end_threshold = Some(millis);
+        self
+    }
+
+    /// How much initial silence, in milliseconds, counts as `unknown`.
+    pub fn machine_detection_silence_timeout(&mut self, millis: u32) -> &mut OutboundCall<'a> {
+        self.machine_detection_silence_timeout = Some(millis);
+        self
+    }
+
+    /// Runs detection in the background, connecting the call straight away and
+    /// posting the `AnsweredBy` result to `url` once it is known.
+    pub fn async_amd(&mut self, url: &'a str, method: Option<Method>) -> &mut OutboundCall<'a> {
+        self.async_amd_status_callback = Some(url);
+        self.async_amd_status_callback_method = method;
+        self
+    }
+
+    /// Digits to play once the call connects, with `w` for a half-second pause.
+    pub fn send_digits(&mut self, digits: &'a str) -> &mut OutboundCall<'a> {
+        self.send_digits = Some(digits);
+        self
+    }
+
+    /// The caller ID to present when `to` is a SIP address.
+    pub fn caller_id(&mut self, caller_id: &'a str) -> &mut OutboundCall<'a> {
+        self.caller_id = Some(caller_id);
+        self
+    }
+
+    pub fn sip_auth(&mut self, username: &'a str, password: &'a str) -> &mut OutboundCall<'a> {
+        self.sip_auth_username = Some(username);
+        self.sip_auth_password = Some(password);
+        self
+    }
+
+    /// Adds a header to the SIP INVITE, for passing CTI data such as a ticket
+    /// number to the far end. Twilio only forwards names starting with `X-`.
+    pub fn sip_header(&mut self, name: &'a str, value: &'a str) -> &mut OutboundCall<'a> {
+        self.sip_headers.push((name, value));
+        self
+    }
+
+    /// A unique key for this call. If the request has to be repeated, Twilio
+    /// returns the call it already placed instead of dialing again, which
+    /// also lets the client retry it after a network failure.
+    pub fn idempotency_token(&mut self, token: &'a str) -> &mut OutboundCall<'a> {
+        self.idempotency_token = Some(token);
+        self
+    }
+
+    fn params(&self) -> Result<Vec<(&'static str, String)>, TwilioError> {
+        let to = phone::normalize_address(self.to)?;
+        let to = crate::twiml::sip_uri(&to, self.sip_headers.iter().copied());
+        let mut opts = vec![("To", to), ("From", phone::normalize_address(self.from)?)];
+
+        match self.instructions {
+            CallInstructions::Url(url) => opts.push(("Url", url.to_string())),
+            CallInstructions::Twiml(twiml) => opts.push(("Twiml", twiml.to_string())),
+        }
+        if let Some(url) = self.status_callback {
+            opts.push(("StatusCallback", url.to_string()));
+        }
+        if let Some(ref method) = self.status_callback_method {
+            opts.push(("StatusCallbackMethod", method.as_str().to_string()));
+        }
+        for event in &self.status_callback_events {
+            opts.push(("StatusCallbackEvent", event.as_str().to_string()));
+        }
+        if let Some(t) = self.timeout_seconds {
+            opts.push(("Timeout", t.to_string()));
+        }
+        if let Some(record) = self.record {
+            opts.push(("Record", bool_str(record).to_string()));
+        }
+        if let Some(trim) = self.trim {
+            let trim_str = if trim { "trim-silence" } else { "do-not-trim" };
+            opts.push(("Trim", trim_str.to_string()));
+        }
+        if let Some(ref detection) = self.machine_detection {
+            let detection_str = match detection {
+                MachineDetection::Enable => "Enable",
+                MachineDetection::DetectMessageEnd => "DetectMessageEnd",
+            };
+            opts.push(("MachineDetection", detection_str.to_string()));
+        }
+        if let Some(t) = self.machine_detection_timeout {
+            opts.push(("MachineDetectionTimeout", t.to_string()));
+        }
+        if let Some(ms) = self.machine_detection_speech_threshold {
+            opts.push(("MachineDetectionSpeechThreshold", ms.to_string()));
+        }
+        if let Some(ms) = self.machine_detection_speech_end_threshold {
+            opts.push(("MachineDetectionSpeechEndThreshold", ms.to_string()));
         }
+        if let Some(ms) = self.machine_detection_silence_timeout {
+            opts.push(("MachineDetectionSilenceTimeout", ms.to_string()));
+        }
+        if let Some(url) = self.async_amd_status_callback {
+            opts.push(("AsyncAmd", "true".to_string()));
+            opts.push(("AsyncAmdStatusCallback", url.to_string()));
+            if let Some(ref method) = self.async_amd_status_callback_method {
+                opts.push(("AsyncAmdStatusCallbackMethod", method.as_str().to_string()));
+            }
+        }
+        if let Some(digits) = self.send_digits {
+            opts.push(("SendDigits", digits.to_string()));
+        }
+        if let Some(caller_id) = self.caller_id {
+            opts.push(("CallerId", caller_id.to_string()));
+        }
+        if let Some(username) = self.sip_auth_username {
+            opts.push(("SipAuthUsername", username.to_string()));
+        }
+        if let Some(password) = self.sip_auth_password {
+            opts.push(("SipAuthPassword", password.to_string()));
+        }
+        Ok(opts)
     }
 }
 
@@ -44,61 +292,574 @@ pub enum CallStatus {
     NoAnswer,
 }
 
+impl CallStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            CallStatus::Queued => "queued",
+            CallStatus::Ringing => "ringing",
+            CallStatus::InProgress => "in-progress",
+            CallStatus::Canceled => "canceled",
+            CallStatus::Completed => "completed",
+            CallStatus::Failed => "failed",
+            CallStatus::Busy => "busy",
+            CallStatus::NoAnswer => "no-answer",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CallDirection {
+    Inbound,
+    /// Placed through the REST API.
+    OutboundApi,
+    /// Placed by `<Dial>` during another call.
+    OutboundDial,
+    TrunkingOriginating,
+    TrunkingTerminating,
+}
+
+/// Who answered a call placed with machine detection enabled.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnsweredBy {
+    Human,
+    MachineStart,
+    MachineEndBeep,
+    MachineEndSilence,
+    MachineEndOther,
+    Fax,
+    Unknown,
+}
+
+impl AnsweredBy {
+    pub fn is_machine(&self) -> bool {
+        matches!(
+            *self,
+            AnsweredBy::MachineStart
+                | AnsweredBy::MachineEndBeep
+                | AnsweredBy::MachineEndSilence
+                | AnsweredBy::MachineEndOther
+        )
+    }
+}
+
+/// A call, as returned by the REST API or posted to a voice webhook. Webhook
+/// parameters are accepted under their PascalCase names; anything without a
+/// dedicated field is kept in `extra`.
 #[derive(Debug, Deserialize)]
 pub struct Call {
+    #[serde(alias = "From")]
     pub from: String,
+    #[serde(alias = "To")]
     pub to: String,
-    pub sid: String,
+    #[serde(alias = "CallSid")]
+    pub sid: CallSid,
+    #[serde(alias = "CallStatus")]
     pub status: CallStatus,
+    #[serde(alias = "AccountSid")]
+    pub account_sid: Option<AccountSid>,
+    #[serde(alias = "ApiVersion")]
+    pub api_version: Option<String>,
+    #[serde(alias = "Direction")]
+    pub direction: Option<CallDirection>,
+    #[serde(alias = "ForwardedFrom")]
+    pub forwarded_from: Option<String>,
+    #[serde(alias = "CallerName")]
+    pub caller_name: Option<String>,
+    #[serde(alias = "ParentCallSid")]
+    pub parent_call_sid: Option<CallSid>,
+    #[serde(alias = "CallToken")]
+    pub call_token: Option<String>,
+    #[serde(alias = "Digits")]
+    pub digits: Option<String>,
+    #[serde(alias = "RecordingUrl")]
+    pub recording_url: Option<String>,
+    #[serde(alias = "AnsweredBy")]
+    pub answered_by: Option<AnsweredBy>,
+    /// Milliseconds it took to reach the `answered_by` verdict.
+    #[serde(
+        default,
+        alias = "MachineDetectionDuration",
+        deserialize_with = "crate::number_from_str"
+    )]
+    pub machine_detection_duration: Option<u32>,
+    #[serde(alias = "FromCity")]
+    pub from_city: Option<String>,
+    #[serde(alias = "FromState")]
+    pub from_state: Option<String>,
+    #[serde(alias = "FromZip")]
+    pub from_zip: Option<String>,
+    #[serde(alias = "FromCountry")]
+    pub from_country: Option<String>,
+    #[serde(alias = "ToCity")]
+    pub to_city: Option<String>,
+    #[serde(alias = "ToState")]
+    pub to_state: Option<String>,
+    #[serde(alias = "ToZip")]
+    pub to_zip: Option<String>,
+    #[serde(alias = "ToCountry")]
+    pub to_country: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    /// When the call was answered, or empty while it is queued or ringing.
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub start_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub end_time: Option<Timestamp>,
+    /// Seconds the call lasted, once it has ended.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub duration: Option<u32>,
+    /// Milliseconds the call waited in Twilio's queue before being placed.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub queue_time: Option<u32>,
+    /// The charge for the call, e.g. `-0.01750` USD, set a while after the
+    /// call ends.
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// A status callback, posted to a call's `StatusCallback` for each of the
+/// `CallEvent`s it subscribed to. Callbacks can arrive out of order; sort
+/// them by `sequence_number`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CallStatusEvent {
+    pub call_sid: CallSid,
+    pub call_status: CallStatus,
+    pub account_sid: Option<AccountSid>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub direction: Option<CallDirection>,
+    pub parent_call_sid: Option<CallSid>,
+    /// Seconds the call lasted, sent once it has `completed`.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub call_duration: Option<u32>,
+    /// An RFC 2822 timestamp, e.g. `Mon, 16 Aug 2010 03:45:01 +0000`.
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub timestamp: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub sequence_number: Option<u32>,
+    /// Where the callback originated, e.g. `call-progress-events`.
+    pub callback_source: Option<String>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub sip_response_code: Option<u16>,
+    pub answered_by: Option<AnsweredBy>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub machine_detection_duration: Option<u32>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for CallStatusEvent {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<CallStatusEvent>, TwilioError> {
+        from_params(m)
+    }
+}
+
+/// The parameters posted to a `<Gather>`'s `action` once the caller has
+/// finished entering digits or speaking. Besides these, the request carries
+/// the same call parameters as a voice webhook, which are kept in `extra`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GatherResult {
+    pub call_sid: CallSid,
+    pub digits: Option<String>,
+    pub speech_result: Option<String>,
+    /// How sure Twilio is of `speech_result`, from 0.0 to 1.0.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub confidence: Option<f64>,
+    /// The key that ended input, if the caller pressed `finishOnKey`.
+    pub finished_on_key: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for GatherResult {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<GatherResult>, TwilioError> {
+        from_params(m)
+    }
+}
+
+/// How a `<Pay>` ended.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PayOutcome {
+    Success,
+    TooManyFailedAttempts,
+    PaymentConnectorError,
+    CallerInterruptedWithStar,
+    CallerHungUp,
+    ValidationError,
+    InternalError,
+}
+
+/// The parameters posted to a `<Pay>`'s `action`. Card and account numbers
+/// arrive masked, e.g. `xxxx-xxxx-xxxx-1111`; anything else the connector
+/// returns is kept in `extra`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayResult {
+    pub call_sid: CallSid,
+    pub result: PayOutcome,
+    /// The connector's token, when `<Pay>` tokenized rather than charged.
+    pub payment_token: Option<String>,
+    pub profile_id: Option<String>,
+    /// The connector's reference for a successful charge.
+    pub payment_confirmation_code: Option<String>,
+    pub payment_card_number: Option<String>,
+    pub payment_card_type: Option<String>,
+    /// `MMYY`.
+    pub expiration_date: Option<String>,
+    pub payment_card_postal_code: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_error: Option<String>,
+    /// The error code the connector returned, on `payment-connector-error`.
+    pub connector_error: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for PayResult {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<PayResult>, TwilioError> {
+        from_params(m)
+    }
+}
+
+/// A request Twilio made to one of your webhooks during a call, along with
+/// how your server responded.
+#[derive(Debug, Deserialize)]
+pub struct CallEventRecord {
+    pub request: CallEventRequest,
+    pub response: Option<CallEventResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallEventRequest {
+    pub method: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub parameters: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallEventResponse {
+    pub response_code: Option<u16>,
+    pub response_body: Option<String>,
+    pub content_type: Option<String>,
+    /// Milliseconds your server took to respond.
+    pub request_duration: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+}
+
+/// The terminal states a live call can be moved to with `Client::update_call`.
+/// `Canceled` only affects calls that are still queued or ringing, while
+/// `Completed` hangs up a call that is in progress.
+pub enum CallUpdateStatus {
+    Canceled,
+    Completed,
+}
+
+#[derive(Default)]
+pub struct CallUpdate<'a> {
+    pub url: Option<&'a str>,
+    pub method: Option<Method>,
+    pub status: Option<CallUpdateStatus>,
+    pub twiml: Option<&'a str>,
+}
+
+impl<'a> CallUpdate<'a> {
+    pub fn redirect(url: &'a str) -> CallUpdate<'a> {
+        CallUpdate {
+            url: Some(url),
+            ..Default::default()
+        }
+    }
+
+    pub fn twiml(twiml: &'a str) -> CallUpdate<'a> {
+        CallUpdate {
+            twiml: Some(twiml),
+            ..Default::default()
+        }
+    }
+
+    pub fn cancel() -> CallUpdate<'a> {
+        CallUpdate {
+            status: Some(CallUpdateStatus::Canceled),
+            ..Default::default()
+        }
+    }
+
+    pub fn hang_up() -> CallUpdate<'a> {
+        CallUpdate {
+            status: Some(CallUpdateStatus::Completed),
+            ..Default::default()
+        }
+    }
+}
+
+/// A problem reported with `Client::create_call_feedback`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CallFeedbackIssue {
+    AudioLatency,
+    DigitsNotCaptured,
+    DroppedCall,
+    ImperfectAudio,
+    IncorrectCallerId,
+    OneWayAudio,
+    PostDialDelay,
+    UnsolicitedCall,
+}
+
+impl CallFeedbackIssue {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            CallFeedbackIssue::AudioLatency => "audio-latency",
+            CallFeedbackIssue::DigitsNotCaptured => "digits-not-captured",
+            CallFeedbackIssue::DroppedCall => "dropped-call",
+            CallFeedbackIssue::ImperfectAudio => "imperfect-audio",
+            CallFeedbackIssue::IncorrectCallerId => "incorrect-caller-id",
+            CallFeedbackIssue::OneWayAudio => "one-way-audio",
+            CallFeedbackIssue::PostDialDelay => "post-dial-delay",
+            CallFeedbackIssue::UnsolicitedCall => "unsolicited-call",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallFeedback {
+    pub account_sid: AccountSid,
+    /// From 1, the worst, to 5, the best.
+    pub quality_score: u8,
+    #[serde(default)]
+    pub issues: Vec<CallFeedbackIssue>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FeedbackSummaryStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// How often an issue was reported over a summary's date range.
+#[derive(Debug, Deserialize)]
+pub struct FeedbackIssueCount {
+    pub description: String,
+    pub count: u32,
+    pub percentage_of_total_calls: Option<String>,
+}
+
+/// Call quality feedback aggregated over a date range. Summaries are built
+/// asynchronously; the statistics are set once `status` is `completed`.
+#[derive(Debug, Deserialize)]
+pub struct FeedbackSummary {
+    pub sid: FeedbackSummarySid,
+    pub account_sid: AccountSid,
+    pub status: FeedbackSummaryStatus,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub include_subaccounts: Option<bool>,
+    pub call_count: Option<u32>,
+    pub call_feedback_count: Option<u32>,
+    pub quality_score_average: Option<f64>,
+    pub quality_score_median: Option<f64>,
+    pub quality_score_standard_deviation: Option<f64>,
+    #[serde(default)]
+    pub issues: Vec<FeedbackIssueCount>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+}
+
+/// Filters accepted by `Client::list_calls`. Timestamps are passed through to
+/// Twilio as-is, e.g. `"2023-01-31"` or `"2023-01-31T12:00:00Z"`.
+#[derive(Default)]
+pub struct CallFilter<'a> {
+    pub status: Option<CallStatus>,
+    pub from: Option<&'a str>,
+    pub to: Option<&'a str>,
+    pub start_time_after: Option<&'a str>,
+    pub start_time_before: Option<&'a str>,
+    pub page_size: Option<u32>,
 }
 
 impl Client {
     pub async fn make_call(&self, call: OutboundCall<'_>) -> Result<Call, TwilioError> {
-        let mut opts = vec![("To", call.to), ("From", call.from)];
+        Ok(self.make_call_with_meta(call).await?.value)
+    }
+
+    /// Like `make_call`, also returning the response's request ID and
+    /// concurrency headers.
+    pub async fn make_call_with_meta(
+        &self,
+        call: OutboundCall<'_>,
+    ) -> Result<WithMeta<Call>, TwilioError> {
+        let params = call.params()?;
+        let opts: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.acquire_call_slot().await;
+        self.send_create_request("Calls", &opts, call.idempotency_token)
+            .await
+    }
+
+    pub async fn retrieve_call(&self, sid: &CallSid) -> Result<Call, TwilioError> {
+        self.send_request(GET, &format!("Calls/{sid}"), &[]).await
+    }
 
-        match &call.instructions {
-            CallInstructions::Url(url) => opts.push(("Url", url)),
-            CallInstructions::Twiml(twiml) => opts.push(("Twiml", twiml)),
+    pub async fn update_call(
+        &self,
+        sid: &CallSid,
+        update: CallUpdate<'_>,
+    ) -> Result<Call, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(url) = update.url {
+            opts.push(("Url", url));
+        }
+        if let Some(ref method) = update.method {
+            opts.push(("Method", method.as_str()));
+        }
+        if let Some(ref status) = update.status {
+            let status_str = match status {
+                CallUpdateStatus::Canceled => "canceled",
+                CallUpdateStatus::Completed => "completed",
+            };
+            opts.push(("Status", status_str));
+        }
+        if let Some(twiml) = update.twiml {
+            opts.push(("Twiml", twiml));
         }
 
-        self.send_request(POST, "Calls", &opts).await
+        self.send_request(POST, &format!("Calls/{sid}"), &opts)
+            .await
     }
 
-    pub async fn retrieve_call(&self, sid: &str) -> Result<Call, TwilioError> {
-        self.send_request(GET, &format!("Calls/{sid}"), &[]).await
+    /// Transfers a live call to `target`, a phone number, a `sip:` URI or a
+    /// `client:` identity, by replacing its TwiML with a `<Dial>`. Whatever the
+    /// call was doing, such as waiting in a queue, stops, and it ends when the
+    /// new leg hangs up. Any other target is an `InvalidNumber`.
+    pub async fn transfer_call(&self, sid: &CallSid, target: &str) -> Result<Call, TwilioError> {
+        let noun = if target.starts_with("sip:") || target.starts_with("sips:") {
+            DialNoun::Sip(Sip::new(target))
+        } else if let Some(identity) = target.strip_prefix("client:") {
+            DialNoun::Client(twiml::Client::new(identity))
+        } else {
+            let number = phone::E164::new(target).map_err(TwilioError::InvalidNumber)?;
+            DialNoun::Number(Number::new(number.as_str()))
+        };
+        let dial = Dial {
+            nouns: vec![noun],
+            ..Default::default()
+        };
+        let twiml = Twiml::new().add_verb(&dial).as_twiml();
+        self.update_call(sid, CallUpdate::twiml(&twiml)).await
+    }
+
+    /// Lists the webhook requests made during a call, oldest first.
+    pub fn list_call_events(&self, sid: &CallSid) -> PageIterator<CallEventRecord> {
+        self.list(&format!("Calls/{sid}/Events"), "events", &[])
+    }
+
+    pub fn list_calls(&self, filter: &CallFilter<'_>) -> PageIterator<Call> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref status) = filter.status {
+            opts.push(("Status", status.as_str()));
+        }
+        if let Some(from) = filter.from {
+            opts.push(("From", from));
+        }
+        if let Some(to) = filter.to {
+            opts.push(("To", to));
+        }
+        if let Some(after) = filter.start_time_after {
+            opts.push(("StartTime>", after));
+        }
+        if let Some(before) = filter.start_time_before {
+            opts.push(("StartTime<", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("Calls", "calls", &opts)
+    }
+
+    /// Reports the quality of a completed call, from 1, the worst, to 5,
+    /// the best, along with any issues noticed.
+    pub async fn create_call_feedback(
+        &self,
+        sid: &CallSid,
+        quality_score: u8,
+        issues: &[CallFeedbackIssue],
+    ) -> Result<CallFeedback, TwilioError> {
+        let score = quality_score.to_string();
+        let mut opts = vec![("QualityScore", score.as_str())];
+        for issue in issues {
+            opts.push(("Issue", issue.as_str()));
+        }
+        self.send_request(POST, &format!("Calls/{sid}/Feedback"), &opts)
+            .await
+    }
+
+    pub async fn retrieve_call_feedback(&self, sid: &CallSid) -> Result<CallFeedback, TwilioError> {
+        self.send_request(GET, &format!("Calls/{sid}/Feedback"), &[])
+            .await
+    }
+
+    /// Starts building a summary of the feedback on calls between two dates,
+    /// e.g. `"2023-01-01"`. Poll `retrieve_feedback_summary` until it is
+    /// `completed`, or pass a `status_callback` to be notified.
+    pub async fn create_feedback_summary(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        include_subaccounts: bool,
+        status_callback: Option<&str>,
+    ) -> Result<FeedbackSummary, TwilioError> {
+        let mut opts = vec![
+            ("StartDate", start_date),
+            ("EndDate", end_date),
+            ("IncludeSubaccounts", bool_str(include_subaccounts)),
+        ];
+        if let Some(url) = status_callback {
+            opts.push(("StatusCallback", url));
+        }
+        self.send_request(POST, "Calls/FeedbackSummary", &opts)
+            .await
+    }
+
+    pub async fn retrieve_feedback_summary(
+        &self,
+        sid: &FeedbackSummarySid,
+    ) -> Result<FeedbackSummary, TwilioError> {
+        self.send_request(GET, &format!("Calls/FeedbackSummary/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_feedback_summary(
+        &self,
+        sid: &FeedbackSummarySid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Calls/FeedbackSummary/{sid}"))
+            .await
     }
 }
 
 impl FromMap for Call {
-    fn from_map(mut m: BTreeMap<String, String>) -> Result<Box<Call>, TwilioError> {
-        let from = match m.remove("From") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
-        };
-        let to = match m.remove("To") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
-        };
-        let sid = match m.remove("CallSid") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
-        };
-        let stat = match m.get("CallStatus").map(|s| s.as_str()) {
-            Some("queued") => CallStatus::Queued,
-            Some("ringing") => CallStatus::Ringing,
-            Some("in-progress") => CallStatus::InProgress,
-            Some("canceled") => CallStatus::Canceled,
-            Some("completed") => CallStatus::Completed,
-            Some("failed") => CallStatus::Failed,
-            Some("busy") => CallStatus::Busy,
-            Some("no-answer") => CallStatus::NoAnswer,
-            _ => return Err(TwilioError::ParsingError),
-        };
-        Ok(Box::new(Call {
-            from,
-            to,
-            sid,
-            status: stat,
-        }))
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<Call>, TwilioError> {
+        from_params(m)
     }
 }
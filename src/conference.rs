@@ -0,0 +1,290 @@
+use crate::phone;
+use crate::{
+    bool_str, AccountSid, CallSid, Client, ConferenceSid, PageIterator, Timestamp, TwilioError,
+    GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConferenceStatus {
+    Init,
+    InProgress,
+    Completed,
+}
+
+impl ConferenceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ConferenceStatus::Init => "init",
+            ConferenceStatus::InProgress => "in-progress",
+            ConferenceStatus::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Conference {
+    pub sid: ConferenceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    pub status: ConferenceStatus,
+    pub region: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Filters accepted by `Client::list_conferences`. Dates are passed through to
+/// Twilio as-is, e.g. `"2023-01-31"`.
+#[derive(Default)]
+pub struct ConferenceFilter<'a> {
+    pub status: Option<ConferenceStatus>,
+    pub friendly_name: Option<&'a str>,
+    pub date_created_after: Option<&'a str>,
+    pub date_created_before: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParticipantStatus {
+    Queued,
+    Connecting,
+    Ringing,
+    Connected,
+    Complete,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Participant {
+    pub call_sid: CallSid,
+    pub conference_sid: ConferenceSid,
+    pub account_sid: AccountSid,
+    pub label: Option<String>,
+    pub status: Option<ParticipantStatus>,
+    pub muted: bool,
+    pub hold: bool,
+    pub coaching: Option<bool>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Dials `to` from `from` and joins the call to a conference.
+pub struct OutboundParticipant<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub label: Option<&'a str>,
+    pub muted: Option<bool>,
+    pub beep: Option<bool>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    pub early_media: Option<bool>,
+    pub timeout_seconds: Option<u32>,
+    pub status_callback: Option<&'a str>,
+}
+
+impl<'a> OutboundParticipant<'a> {
+    pub fn new(from: &'a str, to: &'a str) -> OutboundParticipant<'a> {
+        OutboundParticipant {
+            from,
+            to,
+            label: None,
+            muted: None,
+            beep: None,
+            start_conference_on_enter: None,
+            end_conference_on_exit: None,
+            early_media: None,
+            timeout_seconds: None,
+            status_callback: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ParticipantUpdate<'a> {
+    pub muted: Option<bool>,
+    pub hold: Option<bool>,
+    pub hold_url: Option<&'a str>,
+    pub announce_url: Option<&'a str>,
+}
+
+impl Client {
+    pub fn list_conferences(&self, filter: &ConferenceFilter<'_>) -> PageIterator<Conference> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref status) = filter.status {
+            opts.push(("Status", status.as_str()));
+        }
+        if let Some(name) = filter.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(after) = filter.date_created_after {
+            opts.push(("DateCreated>", after));
+        }
+        if let Some(before) = filter.date_created_before {
+            opts.push(("DateCreated<", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("Conferences", "conferences", &opts)
+    }
+
+    pub async fn retrieve_conference(
+        &self,
+        sid: &ConferenceSid,
+    ) -> Result<Conference, TwilioError> {
+        self.send_request(GET, &format!("Conferences/{sid}"), &[])
+            .await
+    }
+
+    /// Ends a conference, disconnecting every participant.
+    pub async fn end_conference(&self, sid: &ConferenceSid) -> Result<Conference, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("Conferences/{sid}"),
+            &[("Status", "completed")],
+        )
+        .await
+    }
+
+    pub fn list_participants(&self, conference_sid: &ConferenceSid) -> PageIterator<Participant> {
+        self.list(
+            &format!("Conferences/{conference_sid}/Participants"),
+            "participants",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+    ) -> Result<Participant, TwilioError> {
+        self.send_request(
+            GET,
+            &format!("Conferences/{conference_sid}/Participants/{call_sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn add_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        participant: OutboundParticipant<'_>,
+    ) -> Result<Participant, TwilioError> {
+        let timeout = participant.timeout_seconds.map(|t| t.to_string());
+        let from = phone::normalize_address(participant.from)?;
+        let to = phone::normalize_address(participant.to)?;
+        let mut opts = vec![("From", from.as_str()), ("To", to.as_str())];
+        if let Some(label) = participant.label {
+            opts.push(("Label", label));
+        }
+        if let Some(muted) = participant.muted {
+            opts.push(("Muted", bool_str(muted)));
+        }
+        if let Some(beep) = participant.beep {
+            opts.push(("Beep", bool_str(beep)));
+        }
+        if let Some(start) = participant.start_conference_on_enter {
+            opts.push(("StartConferenceOnEnter", bool_str(start)));
+        }
+        if let Some(end) = participant.end_conference_on_exit {
+            opts.push(("EndConferenceOnExit", bool_str(end)));
+        }
+        if let Some(early) = participant.early_media {
+            opts.push(("EarlyMedia", bool_str(early)));
+        }
+        if let Some(ref t) = timeout {
+            opts.push(("Timeout", t));
+        }
+        if let Some(callback) = participant.status_callback {
+            opts.push(("StatusCallback", callback));
+        }
+
+        self.acquire_call_slot().await;
+        self.send_request(
+            POST,
+            &format!("Conferences/{conference_sid}/Participants"),
+            &opts,
+        )
+        .await
+    }
+
+    pub async fn update_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        update: ParticipantUpdate<'_>,
+    ) -> Result<Participant, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(muted) = update.muted {
+            opts.push(("Muted", bool_str(muted)));
+        }
+        if let Some(hold) = update.hold {
+            opts.push(("Hold", bool_str(hold)));
+        }
+        if let Some(url) = update.hold_url {
+            opts.push(("HoldUrl", url));
+        }
+        if let Some(url) = update.announce_url {
+            opts.push(("AnnounceUrl", url));
+        }
+
+        self.send_request(
+            POST,
+            &format!("Conferences/{conference_sid}/Participants/{call_sid}"),
+            &opts,
+        )
+        .await
+    }
+
+    pub async fn mute_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        muted: bool,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            muted: Some(muted),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, call_sid, update)
+            .await
+    }
+
+    pub async fn hold_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+        hold: bool,
+    ) -> Result<Participant, TwilioError> {
+        let update = ParticipantUpdate {
+            hold: Some(hold),
+            ..Default::default()
+        };
+        self.update_participant(conference_sid, call_sid, update)
+            .await
+    }
+
+    /// Removes a participant from a conference, hanging up their call.
+    pub async fn kick_participant(
+        &self,
+        conference_sid: &ConferenceSid,
+        call_sid: &CallSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!(
+            "Conferences/{conference_sid}/Participants/{call_sid}"
+        ))
+        .await
+    }
+}
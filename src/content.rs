@@ -0,0 +1,212 @@
+//! [Content API](https://www.twilio.com/docs/content/content-api-resources)
+//! message templates, for sending rich or pre-approved WhatsApp and RCS
+//! messages with `OutboundMessage::new_with_content`.
+
+use crate::endpoint::CONTENT;
+use crate::{AccountSid, Client, ContentSid, PageIterator, Timestamp, TwilioError, GET, POST};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize)]
+pub struct Content {
+    pub sid: ContentSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    pub language: Option<String>,
+    /// Default values for the template's placeholders, keyed by name.
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    pub types: ContentTypes,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// The variants of a template, one per content type. Channels pick the
+/// richest variant they support and fall back to `text`. Types without a
+/// dedicated field, such as `twilio/media` or `twilio/list-picker`, are kept
+/// as raw JSON in `other`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ContentTypes {
+    #[serde(rename = "twilio/text", skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(rename = "twilio/quick-reply", skip_serializing_if = "Option::is_none")]
+    pub quick_reply: Option<QuickReply>,
+    #[serde(rename = "twilio/card", skip_serializing_if = "Option::is_none")]
+    pub card: Option<Card>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Text {
+    pub body: String,
+}
+
+/// A message with up to ten buttons that send a reply back when tapped.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuickReply {
+    pub body: String,
+    pub actions: Vec<QuickReplyAction>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuickReplyAction {
+    pub title: String,
+    /// Returned as `ButtonPayload` on the reply's webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Card {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CardAction>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CardAction {
+    QuickReply {
+        title: String,
+        id: String,
+    },
+    Url {
+        title: String,
+        url: String,
+    },
+    PhoneNumber {
+        title: String,
+        phone: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// A template to create. `language` is a code such as `"en"` or `"pt_BR"`.
+#[derive(Serialize)]
+pub struct NewContent<'a> {
+    pub friendly_name: &'a str,
+    pub language: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<&'a str, &'a str>,
+    pub types: ContentTypes,
+}
+
+impl<'a> NewContent<'a> {
+    pub fn new(friendly_name: &'a str, language: &'a str, types: ContentTypes) -> NewContent<'a> {
+        NewContent {
+            friendly_name,
+            language,
+            variables: BTreeMap::new(),
+            types,
+        }
+    }
+
+    /// Sets the sample value for a placeholder, which WhatsApp requires
+    /// before it reviews the template.
+    pub fn add_variable(&mut self, name: &'a str, value: &'a str) -> &mut NewContent<'a> {
+        self.variables.insert(name, value);
+        self
+    }
+}
+
+/// The category a WhatsApp template is reviewed under.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WhatsappCategory {
+    Utility,
+    Marketing,
+    Authentication,
+}
+
+impl WhatsappCategory {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            WhatsappCategory::Utility => "UTILITY",
+            WhatsappCategory::Marketing => "MARKETING",
+            WhatsappCategory::Authentication => "AUTHENTICATION",
+        }
+    }
+}
+
+/// The state of a template's WhatsApp review.
+#[derive(Debug, Deserialize)]
+pub struct WhatsappApproval {
+    pub name: Option<String>,
+    pub category: Option<String>,
+    pub content_type: Option<String>,
+    /// `received`, `pending`, `approved`, `rejected`, `paused` or `disabled`.
+    pub status: Option<String>,
+    pub rejection_reason: Option<String>,
+    pub allow_category_change: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApprovalRequests {
+    pub sid: ContentSid,
+    pub account_sid: AccountSid,
+    pub whatsapp: Option<WhatsappApproval>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    pub async fn create_content(&self, content: &NewContent<'_>) -> Result<Content, TwilioError> {
+        let body = serde_json::to_value(content).map_err(TwilioError::ParsingError)?;
+        self.send_product_json(POST, &CONTENT, "Content", &body)
+            .await
+    }
+
+    pub async fn retrieve_content(&self, sid: &ContentSid) -> Result<Content, TwilioError> {
+        self.send_product_request(GET, &CONTENT, &format!("Content/{sid}"), &[])
+            .await
+    }
+
+    pub fn list_content(&self) -> PageIterator<Content> {
+        self.list_product(&CONTENT, "Content", "contents", &[])
+    }
+
+    pub async fn delete_content(&self, sid: &ContentSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&CONTENT, &format!("Content/{sid}"))
+            .await
+    }
+
+    /// Submits a template to WhatsApp for review under `name`, which may only
+    /// contain lowercase letters, numbers and underscores. Templates must be
+    /// approved before they can start a conversation.
+    pub async fn request_whatsapp_approval(
+        &self,
+        sid: &ContentSid,
+        name: &str,
+        category: WhatsappCategory,
+    ) -> Result<WhatsappApproval, TwilioError> {
+        let body = serde_json::json!({ "name": name, "category": category.as_str() });
+        self.send_product_json(
+            POST,
+            &CONTENT,
+            &format!("Content/{sid}/ApprovalRequests/whatsapp"),
+            &body,
+        )
+        .await
+    }
+
+    pub async fn retrieve_approval_requests(
+        &self,
+        sid: &ContentSid,
+    ) -> Result<ApprovalRequests, TwilioError> {
+        self.send_product_request(
+            GET,
+            &CONTENT,
+            &format!("Content/{sid}/ApprovalRequests"),
+            &[],
+        )
+        .await
+    }
+}
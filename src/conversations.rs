@@ -0,0 +1,351 @@
+//! The [Conversations](https://www.twilio.com/docs/conversations/api) API,
+//! for multi-party threads whose participants can be chat users or SMS and
+//! WhatsApp numbers.
+
+use crate::endpoint::CONVERSATIONS;
+use crate::twiml::Method;
+use crate::{
+    AccountSid, Client, ConversationMessageSid, ConversationParticipantSid, ConversationServiceSid,
+    ConversationSid, ConversationWebhookSid, FlowSid, MessagingServiceSid, PageIterator, Timestamp,
+    TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum State {
+    Active,
+    Inactive,
+    Closed,
+}
+
+impl State {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            State::Active => "active",
+            State::Inactive => "inactive",
+            State::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Conversation {
+    pub sid: ConversationSid,
+    pub account_sid: AccountSid,
+    pub chat_service_sid: Option<ConversationServiceSid>,
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    pub friendly_name: Option<String>,
+    pub unique_name: Option<String>,
+    /// A JSON string of your own metadata.
+    pub attributes: Option<String>,
+    pub state: Option<State>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Settings applied when creating or updating a conversation. Fields left as
+/// `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct ConversationConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub unique_name: Option<&'a str>,
+    pub attributes: Option<&'a str>,
+    pub messaging_service_sid: Option<&'a MessagingServiceSid>,
+    pub state: Option<State>,
+}
+
+impl<'a> ConversationConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(name) = self.unique_name {
+            opts.push(("UniqueName", name));
+        }
+        if let Some(attributes) = self.attributes {
+            opts.push(("Attributes", attributes));
+        }
+        if let Some(sid) = self.messaging_service_sid {
+            opts.push(("MessagingServiceSid", sid.as_str()));
+        }
+        if let Some(ref state) = self.state {
+            opts.push(("State", state.as_str()));
+        }
+        opts
+    }
+}
+
+/// How a non-chat participant is reached.
+#[derive(Debug, Deserialize)]
+pub struct MessagingBinding {
+    #[serde(rename = "type")]
+    pub binding_type: Option<String>,
+    pub address: Option<String>,
+    pub proxy_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Participant {
+    pub sid: ConversationParticipantSid,
+    pub account_sid: AccountSid,
+    pub conversation_sid: ConversationSid,
+    /// Set for chat participants.
+    pub identity: Option<String>,
+    /// Set for SMS and WhatsApp participants.
+    pub messaging_binding: Option<MessagingBinding>,
+    pub attributes: Option<String>,
+    pub role_sid: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+pub enum NewParticipant<'a> {
+    /// A chat user, identified the same way as in their access token.
+    Chat { identity: &'a str },
+    /// A phone number, reached from one of your numbers at `proxy_address`.
+    /// Prefix both with `whatsapp:` for WhatsApp.
+    Sms {
+        address: &'a str,
+        proxy_address: &'a str,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    pub sid: ConversationMessageSid,
+    pub account_sid: AccountSid,
+    pub conversation_sid: ConversationSid,
+    pub participant_sid: Option<ConversationParticipantSid>,
+    pub author: Option<String>,
+    pub body: Option<String>,
+    pub attributes: Option<String>,
+    /// The message's position in the conversation, starting at 0.
+    pub index: u32,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Default)]
+pub struct NewMessage<'a> {
+    pub body: Option<&'a str>,
+    /// Shown as the sender. Defaults to `system`.
+    pub author: Option<&'a str>,
+    pub attributes: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Webhook {
+    pub sid: ConversationWebhookSid,
+    pub account_sid: AccountSid,
+    pub conversation_sid: ConversationSid,
+    pub target: String,
+    pub configuration: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Where a conversation webhook delivers events.
+pub enum WebhookTarget<'a> {
+    Url {
+        url: &'a str,
+        method: Option<Method>,
+    },
+    Studio {
+        flow_sid: &'a FlowSid,
+    },
+}
+
+pub struct NewWebhook<'a> {
+    pub target: WebhookTarget<'a>,
+    /// The events to deliver, e.g. `"onMessageAdded"`.
+    pub filters: Vec<&'a str>,
+}
+
+impl Client {
+    pub async fn create_conversation(
+        &self,
+        config: ConversationConfig<'_>,
+    ) -> Result<Conversation, TwilioError> {
+        self.send_product_request(POST, &CONVERSATIONS, "Conversations", &config.params())
+            .await
+    }
+
+    pub fn list_conversations(&self) -> PageIterator<Conversation> {
+        self.list_product(&CONVERSATIONS, "Conversations", "conversations", &[])
+    }
+
+    pub async fn retrieve_conversation(
+        &self,
+        sid: &ConversationSid,
+    ) -> Result<Conversation, TwilioError> {
+        self.send_product_request(GET, &CONVERSATIONS, &format!("Conversations/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_conversation(
+        &self,
+        sid: &ConversationSid,
+        config: ConversationConfig<'_>,
+    ) -> Result<Conversation, TwilioError> {
+        self.send_product_request(
+            POST,
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}"),
+            &config.params(),
+        )
+        .await
+    }
+
+    pub async fn delete_conversation(&self, sid: &ConversationSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&CONVERSATIONS, &format!("Conversations/{sid}"))
+            .await
+    }
+
+    pub async fn add_conversation_participant(
+        &self,
+        sid: &ConversationSid,
+        participant: NewParticipant<'_>,
+    ) -> Result<Participant, TwilioError> {
+        let opts = match participant {
+            NewParticipant::Chat { identity } => vec![("Identity", identity)],
+            NewParticipant::Sms {
+                address,
+                proxy_address,
+            } => vec![
+                ("MessagingBinding.Address", address),
+                ("MessagingBinding.ProxyAddress", proxy_address),
+            ],
+        };
+        self.send_product_request(
+            POST,
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Participants"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_conversation_participants(
+        &self,
+        sid: &ConversationSid,
+    ) -> PageIterator<Participant> {
+        self.list_product(
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Participants"),
+            "participants",
+            &[],
+        )
+    }
+
+    pub async fn remove_conversation_participant(
+        &self,
+        sid: &ConversationSid,
+        participant_sid: &ConversationParticipantSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Participants/{participant_sid}"),
+        )
+        .await
+    }
+
+    pub async fn send_conversation_message(
+        &self,
+        sid: &ConversationSid,
+        message: NewMessage<'_>,
+    ) -> Result<Message, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(body) = message.body {
+            opts.push(("Body", body));
+        }
+        if let Some(author) = message.author {
+            opts.push(("Author", author));
+        }
+        if let Some(attributes) = message.attributes {
+            opts.push(("Attributes", attributes));
+        }
+        self.send_product_request(
+            POST,
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Messages"),
+            &opts,
+        )
+        .await
+    }
+
+    /// Lists a conversation's messages, oldest first.
+    pub fn list_conversation_messages(&self, sid: &ConversationSid) -> PageIterator<Message> {
+        self.list_product(
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Messages"),
+            "messages",
+            &[],
+        )
+    }
+
+    pub async fn add_conversation_webhook(
+        &self,
+        sid: &ConversationSid,
+        webhook: NewWebhook<'_>,
+    ) -> Result<Webhook, TwilioError> {
+        let mut opts = Vec::new();
+        match webhook.target {
+            WebhookTarget::Url { url, ref method } => {
+                opts.push(("Target", "webhook"));
+                opts.push(("Configuration.Url", url));
+                if let Some(method) = method {
+                    opts.push(("Configuration.Method", method.as_str()));
+                }
+            }
+            WebhookTarget::Studio { flow_sid } => {
+                opts.push(("Target", "studio"));
+                opts.push(("Configuration.FlowSid", flow_sid.as_str()));
+            }
+        }
+        for filter in &webhook.filters {
+            opts.push(("Configuration.Filters", filter));
+        }
+        self.send_product_request(
+            POST,
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Webhooks"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_conversation_webhooks(&self, sid: &ConversationSid) -> PageIterator<Webhook> {
+        self.list_product(
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Webhooks"),
+            "webhooks",
+            &[],
+        )
+    }
+
+    pub async fn remove_conversation_webhook(
+        &self,
+        sid: &ConversationSid,
+        webhook_sid: &ConversationWebhookSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &CONVERSATIONS,
+            &format!("Conversations/{sid}/Webhooks/{webhook_sid}"),
+        )
+        .await
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(any(feature = "lookup", feature = "pricing"))]
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// The host and API version a resource lives under. Paths passed alongside an
+/// `Endpoint` are relative to its version, e.g. `"Services/VA123"`.
+pub(crate) struct Endpoint {
+    pub host: &'static str,
+    pub version: &'static str,
+}
+
+impl Endpoint {
+    pub(crate) const fn new(host: &'static str, version: &'static str) -> Endpoint {
+        Endpoint { host, version }
+    }
+}
+
+/// Bytes escaped in a path segment: those the URL spec escapes, plus `/` and
+/// `%` so the segment stays one segment, and `+`, which some servers read as
+/// a space.
+#[cfg(any(feature = "lookup", feature = "pricing"))]
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%')
+    .add(b'+');
+
+/// Percent-encodes a value, such as a phone number, for use as one segment
+/// of a path. Unlike form encoding, a space becomes `%20` rather than `+`.
+#[cfg(any(feature = "lookup", feature = "pricing"))]
+pub(crate) fn path_segment(s: &str) -> String {
+    utf8_percent_encode(s, PATH_SEGMENT).to_string()
+}
+
+pub(crate) const API: Endpoint = Endpoint::new("api", "2010-04-01");
+#[cfg(feature = "content")]
+pub(crate) const CONTENT: Endpoint = Endpoint::new("content", "v1");
+#[cfg(feature = "conversations")]
+pub(crate) const CONVERSATIONS: Endpoint = Endpoint::new("conversations", "v1");
+#[cfg(feature = "events")]
+pub(crate) const EVENTS: Endpoint = Endpoint::new("events", "v1");
+#[cfg(feature = "fax")]
+pub(crate) const FAX: Endpoint = Endpoint::new("fax", "v1");
+#[cfg(feature = "flex")]
+pub(crate) const FLEX: Endpoint = Endpoint::new("flex-api", "v1");
+#[cfg(feature = "insights")]
+pub(crate) const INSIGHTS: Endpoint = Endpoint::new("insights", "v1");
+#[cfg(feature = "intelligence")]
+pub(crate) const INTELLIGENCE: Endpoint = Endpoint::new("intelligence", "v2");
+#[cfg(feature = "lookup")]
+pub(crate) const LOOKUPS: Endpoint = Endpoint::new("lookups", "v2");
+#[cfg(any(feature = "a2p", feature = "messaging"))]
+pub(crate) const MESSAGING: Endpoint = Endpoint::new("messaging", "v1");
+#[cfg(feature = "monitor")]
+pub(crate) const MONITOR: Endpoint = Endpoint::new("monitor", "v1");
+#[cfg(feature = "notify")]
+pub(crate) const NOTIFY: Endpoint = Endpoint::new("notify", "v1");
+pub(crate) const OAUTH: Endpoint = Endpoint::new("oauth", "v2");
+#[cfg(feature = "pricing")]
+pub(crate) const PRICING: Endpoint = Endpoint::new("pricing", "v1");
+/// Voice pricing moved to v2; messaging and phone number pricing are v1 only.
+#[cfg(feature = "pricing")]
+pub(crate) const PRICING_V2: Endpoint = Endpoint::new("pricing", "v2");
+#[cfg(feature = "proxy-service")]
+pub(crate) const PROXY: Endpoint = Endpoint::new("proxy", "v1");
+#[cfg(feature = "serverless")]
+pub(crate) const SERVERLESS: Endpoint = Endpoint::new("serverless", "v1");
+/// Function and asset versions are uploaded to a separate host.
+#[cfg(feature = "serverless")]
+pub(crate) const SERVERLESS_UPLOAD: Endpoint = Endpoint::new("serverless-upload", "v1");
+#[cfg(feature = "studio")]
+pub(crate) const STUDIO: Endpoint = Endpoint::new("studio", "v2");
+#[cfg(feature = "sync")]
+pub(crate) const SYNC: Endpoint = Endpoint::new("sync", "v1");
+#[cfg(feature = "taskrouter")]
+pub(crate) const TASKROUTER: Endpoint = Endpoint::new("taskrouter", "v1");
+#[cfg(feature = "trunking")]
+pub(crate) const TRUNKING: Endpoint = Endpoint::new("trunking", "v1");
+#[cfg(feature = "trusthub")]
+pub(crate) const TRUSTHUB: Endpoint = Endpoint::new("trusthub", "v1");
+#[cfg(feature = "verify")]
+pub(crate) const VERIFY: Endpoint = Endpoint::new("verify", "v2");
+#[cfg(feature = "video")]
+pub(crate) const VIDEO: Endpoint = Endpoint::new("video", "v1");
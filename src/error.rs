@@ -0,0 +1,167 @@
+use crate::phone::InvalidNumber;
+use hyper::{HeaderMap, StatusCode};
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// The error body Twilio returns with a failed API request. `code` can be
+/// looked up at <https://www.twilio.com/docs/api/errors>.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    pub code: Option<u32>,
+    pub message: String,
+    pub more_info: Option<String>,
+    pub status: u16,
+    /// The `Twilio-Request-Id` of the failed request, for support tickets.
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "Twilio error {code} ({}): {}", self.status, self.message),
+            None => write!(f, "Twilio error ({}): {}", self.status, self.message),
+        }
+    }
+}
+
+impl Error for ApiError {}
+
+/// New variants may be added in minor releases, so matches need a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TwilioError {
+    /// The request could not be sent, or its response could not be read.
+    NetworkError(hyper::Error),
+    /// A `Transport` other than hyper failed to send the request or read its
+    /// response.
+    TransportError(Box<dyn Error + Send + Sync>),
+    /// The request did not finish within the client's configured timeout.
+    Timeout,
+    /// Twilio rejected the request and explained why.
+    ApiError(ApiError),
+    /// Twilio returned an error status without a readable error body.
+    HTTPError(StatusCode),
+    /// A response or webhook didn't have the expected shape.
+    ParsingError(serde_json::Error),
+    /// A webhook's `X-Twilio-Signature` was missing or did not match.
+    AuthError,
+    /// A webhook request was malformed, e.g. missing its `Host` header.
+    BadRequest,
+    /// A `To` or `From` written as a phone number isn't valid E.164, so the
+    /// request wasn't sent.
+    InvalidNumber(InvalidNumber),
+    /// The `CredentialsProvider` couldn't supply credentials, or Twilio issued
+    /// an unusable OAuth token, so the request wasn't sent.
+    Credentials(Box<dyn Error + Send + Sync>),
+    /// A URL passed in, e.g. to `Client::request_json`, isn't a valid URI, or
+    /// is a full URL off Twilio's hosts, so the request wasn't sent.
+    InvalidUrl(Box<dyn Error + Send + Sync>),
+    /// `ClientBuilder::build` was given no `transport`, and none of the HTTP
+    /// backend features (`native-tls`, `isahc` or `reqwest`) is enabled.
+    NoHttpBackend,
+}
+
+impl TwilioError {
+    /// The HTTP status Twilio responded with, if it responded at all.
+    pub fn status(&self) -> Option<StatusCode> {
+        match *self {
+            TwilioError::ApiError(ref e) => StatusCode::from_u16(e.status).ok(),
+            TwilioError::HTTPError(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Twilio's numeric error code, such as `21211` for an invalid `To` number.
+    pub fn code(&self) -> Option<u32> {
+        match *self {
+            TwilioError::ApiError(ref e) => e.code,
+            _ => None,
+        }
+    }
+
+    /// True when the request never got a response, so retrying may help.
+    pub fn is_network(&self) -> bool {
+        matches!(
+            *self,
+            TwilioError::NetworkError(_) | TwilioError::TransportError(_) | TwilioError::Timeout
+        )
+    }
+
+    /// True for missing or rejected credentials, and for webhooks that failed
+    /// validation.
+    pub fn is_auth(&self) -> bool {
+        match *self {
+            TwilioError::AuthError | TwilioError::Credentials(_) => true,
+            _ => matches!(
+                self.status(),
+                Some(StatusCode::UNAUTHORIZED) | Some(StatusCode::FORBIDDEN)
+            ),
+        }
+    }
+
+    /// True when Twilio refused the request's parameters, e.g. a malformed
+    /// phone number or a missing required field.
+    pub fn is_validation(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(StatusCode::BAD_REQUEST) | Some(StatusCode::UNPROCESSABLE_ENTITY)
+        )
+    }
+
+    pub(crate) fn from_response(
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> TwilioError {
+        match serde_json::from_slice::<ApiError>(body) {
+            Ok(mut e) => {
+                e.request_id = headers
+                    .get("twilio-request-id")
+                    .and_then(|v| v.to_str().ok())
+                    .map(String::from);
+                TwilioError::ApiError(e)
+            }
+            Err(_) => TwilioError::HTTPError(status),
+        }
+    }
+}
+
+impl Display for TwilioError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            TwilioError::NetworkError(ref e) => e.fmt(f),
+            TwilioError::TransportError(ref e) => e.fmt(f),
+            TwilioError::Timeout => f.write_str("Request timed out"),
+            TwilioError::ApiError(ref e) => e.fmt(f),
+            TwilioError::HTTPError(ref s) => write!(f, "Invalid HTTP status code: {s}"),
+            TwilioError::ParsingError(ref e) => write!(f, "Parsing error: {e}"),
+            TwilioError::AuthError => {
+                f.write_str("Missing or invalid `X-Twilio-Signature` header in request")
+            }
+            TwilioError::BadRequest => f.write_str("Bad request"),
+            TwilioError::InvalidNumber(ref e) => e.fmt(f),
+            TwilioError::Credentials(ref e) => write!(f, "Could not load credentials: {e}"),
+            TwilioError::InvalidUrl(ref e) => write!(f, "Invalid request URL: {e}"),
+            TwilioError::NoHttpBackend => f.write_str(
+                "No HTTP backend: enable `native-tls`, `isahc` or `reqwest`, or set a `transport`",
+            ),
+        }
+    }
+}
+
+impl Error for TwilioError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TwilioError::NetworkError(ref e) => Some(e),
+            TwilioError::TransportError(ref e) => Some(e.as_ref()),
+            TwilioError::ApiError(ref e) => Some(e),
+            TwilioError::ParsingError(ref e) => Some(e),
+            TwilioError::InvalidNumber(ref e) => Some(e),
+            TwilioError::Credentials(ref e) => Some(e.as_ref()),
+            TwilioError::InvalidUrl(ref e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! [Event Streams](https://www.twilio.com/docs/events), for having Twilio
+//! deliver events such as message status changes to a webhook, Kinesis stream
+//! or Segment. A sink is the destination and a subscription picks the event
+//! types sent to it.
+//!
+//! Webhook sinks receive [CloudEvents](https://cloudevents.io) as JSON,
+//! which `parse_cloud_events` reads. Check the signature with
+//! `validate_request_with_body` first.
+
+use crate::endpoint::EVENTS;
+use crate::{
+    AccountSid, Client, PageIterator, SinkSid, SubscriptionSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Where a sink delivers events.
+pub enum SinkConfig<'a> {
+    Webhook {
+        destination: &'a str,
+        /// `POST` or `GET`.
+        method: &'a str,
+        /// Send several events per request, as a JSON array.
+        batch_events: bool,
+    },
+    Kinesis {
+        arn: &'a str,
+        /// An IAM role Twilio assumes to write to the stream.
+        role_arn: &'a str,
+        external_id: &'a str,
+    },
+    Segment {
+        write_key: &'a str,
+    },
+}
+
+impl<'a> SinkConfig<'a> {
+    fn sink_type(&self) -> &'static str {
+        match *self {
+            SinkConfig::Webhook { .. } => "webhook",
+            SinkConfig::Kinesis { .. } => "kinesis",
+            SinkConfig::Segment { .. } => "segment",
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match *self {
+            SinkConfig::Webhook {
+                destination,
+                method,
+                batch_events,
+            } => serde_json::json!({
+                "destination": destination,
+                "method": method,
+                "batch_events": batch_events,
+            }),
+            SinkConfig::Kinesis {
+                arn,
+                role_arn,
+                external_id,
+            } => serde_json::json!({
+                "arn": arn,
+                "role_arn": role_arn,
+                "external_id": external_id,
+            }),
+            SinkConfig::Segment { write_key } => serde_json::json!({ "write_key": write_key }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SinkStatus {
+    Initialized,
+    Validating,
+    Active,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Sink {
+    pub sid: SinkSid,
+    pub account_sid: AccountSid,
+    pub description: Option<String>,
+    /// `webhook`, `kinesis` or `segment`.
+    pub sink_type: String,
+    pub sink_configuration: serde_json::Value,
+    /// Kinesis sinks must be validated before they become `Active`.
+    pub status: SinkStatus,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Subscription {
+    pub sid: SubscriptionSid,
+    pub account_sid: AccountSid,
+    pub sink_sid: SinkSid,
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// An event type a subscription delivers, such as
+/// `com.twilio.messaging.message.delivered`.
+#[derive(Debug, Deserialize)]
+pub struct SubscribedEvent {
+    pub account_sid: AccountSid,
+    pub subscription_sid: SubscriptionSid,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// The version of the event's `data` schema.
+    pub schema_version: u32,
+    pub url: Option<String>,
+}
+
+/// One event delivered to a webhook sink.
+#[derive(Debug, Deserialize)]
+pub struct CloudEvent {
+    pub id: String,
+    /// E.g. `com.twilio.messaging.message.delivered`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// The resource the event is about, e.g.
+    /// `/2010-04-01/Accounts/AC.../Messages/SM....json`.
+    pub source: String,
+    pub specversion: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub time: Option<Timestamp>,
+    pub dataschema: Option<String>,
+    pub datacontenttype: Option<String>,
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+impl CloudEvent {
+    /// Deserializes `data` into a type matching the event's schema.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, TwilioError> {
+        T::deserialize(&self.data).map_err(TwilioError::ParsingError)
+    }
+}
+
+/// Parses the body of a request to a webhook sink, which holds either a
+/// single event or, with `batch_events`, an array of them.
+pub fn parse_cloud_events(body: &[u8]) -> Result<Vec<CloudEvent>, TwilioError> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<CloudEvent>),
+        One(CloudEvent),
+    }
+
+    match serde_json::from_slice(body).map_err(TwilioError::ParsingError)? {
+        OneOrMany::Many(events) => Ok(events),
+        OneOrMany::One(event) => Ok(vec![event]),
+    }
+}
+
+impl Client {
+    pub async fn create_sink(
+        &self,
+        description: &str,
+        config: &SinkConfig<'_>,
+    ) -> Result<Sink, TwilioError> {
+        let configuration = config.to_json().to_string();
+        let opts = [
+            ("Description", description),
+            ("SinkType", config.sink_type()),
+            ("SinkConfiguration", &configuration),
+        ];
+        self.send_product_request(POST, &EVENTS, "Sinks", &opts)
+            .await
+    }
+
+    pub fn list_sinks(&self) -> PageIterator<Sink> {
+        self.list_product(&EVENTS, "Sinks", "sinks", &[])
+    }
+
+    pub async fn retrieve_sink(&self, sid: &SinkSid) -> Result<Sink, TwilioError> {
+        self.send_product_request(GET, &EVENTS, &format!("Sinks/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_sink(&self, sid: &SinkSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&EVENTS, &format!("Sinks/{sid}"))
+            .await
+    }
+
+    /// Creates a subscription sending each of `types`, as `(type,
+    /// schema_version)` pairs, to a sink.
+    pub async fn create_subscription(
+        &self,
+        sink_sid: &SinkSid,
+        description: &str,
+        types: &[(&str, u32)],
+    ) -> Result<Subscription, TwilioError> {
+        let types: Vec<String> = types
+            .iter()
+            .map(|(t, v)| serde_json::json!({ "type": t, "schema_version": v }).to_string())
+            .collect();
+        let mut opts = vec![("SinkSid", sink_sid.as_str()), ("Description", description)];
+        for t in &types {
+            opts.push(("Types", t));
+        }
+        self.send_product_request(POST, &EVENTS, "Subscriptions", &opts)
+            .await
+    }
+
+    pub fn list_subscriptions(&self, sink_sid: Option<&SinkSid>) -> PageIterator<Subscription> {
+        let mut opts = Vec::new();
+        if let Some(sid) = sink_sid {
+            opts.push(("SinkSid", sid.as_str()));
+        }
+        self.list_product(&EVENTS, "Subscriptions", "subscriptions", &opts)
+    }
+
+    pub async fn retrieve_subscription(
+        &self,
+        sid: &SubscriptionSid,
+    ) -> Result<Subscription, TwilioError> {
+        self.send_product_request(GET, &EVENTS, &format!("Subscriptions/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_subscription(&self, sid: &SubscriptionSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&EVENTS, &format!("Subscriptions/{sid}"))
+            .await
+    }
+
+    pub async fn add_subscribed_event(
+        &self,
+        subscription_sid: &SubscriptionSid,
+        event_type: &str,
+        schema_version: u32,
+    ) -> Result<SubscribedEvent, TwilioError> {
+        let version = schema_version.to_string();
+        self.send_product_request(
+            POST,
+            &EVENTS,
+            &format!("Subscriptions/{subscription_sid}/SubscribedEvents"),
+            &[("Type", event_type), ("SchemaVersion", &version)],
+        )
+        .await
+    }
+
+    pub fn list_subscribed_events(
+        &self,
+        subscription_sid: &SubscriptionSid,
+    ) -> PageIterator<SubscribedEvent> {
+        self.list_product(
+            &EVENTS,
+            &format!("Subscriptions/{subscription_sid}/SubscribedEvents"),
+            "types",
+            &[],
+        )
+    }
+
+    pub async fn remove_subscribed_event(
+        &self,
+        subscription_sid: &SubscriptionSid,
+        event_type: &str,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &EVENTS,
+            &format!("Subscriptions/{subscription_sid}/SubscribedEvents/{event_type}"),
+        )
+        .await
+    }
+}
@@ -0,0 +1,212 @@
+//! [Programmable Fax](https://www.twilio.com/docs/fax/api), for sending PDFs
+//! to fax machines and tracking their delivery.
+
+use crate::endpoint::FAX;
+use crate::{
+    bool_str, from_params, AccountSid, Client, FaxSid, FromMap, Money, PageIterator, Timestamp,
+    TwilioError, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FaxStatus {
+    Queued,
+    Processing,
+    Sending,
+    Delivered,
+    Receiving,
+    Received,
+    NoAnswer,
+    Busy,
+    Failed,
+    Canceled,
+}
+
+/// Resolution to send at. Finer qualities take longer and may be downgraded
+/// if the receiving machine doesn't support them.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Quality {
+    Standard,
+    Fine,
+    Superfine,
+}
+
+impl Quality {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Quality::Standard => "standard",
+            Quality::Fine => "fine",
+            Quality::Superfine => "superfine",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Fax {
+    pub sid: FaxSid,
+    pub account_sid: AccountSid,
+    pub from: Option<String>,
+    pub to: String,
+    pub quality: Option<Quality>,
+    pub media_sid: Option<String>,
+    pub media_url: Option<String>,
+    pub num_pages: Option<u32>,
+    /// Transmission time in seconds.
+    pub duration: Option<u32>,
+    pub status: FaxStatus,
+    pub direction: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A fax to send. `to` may be a phone number or a `sip:` URI.
+pub struct NewFax<'a> {
+    pub to: &'a str,
+    /// A publicly reachable PDF.
+    pub media_url: &'a str,
+    pub from: Option<&'a str>,
+    pub quality: Option<Quality>,
+    pub status_callback: Option<&'a str>,
+    /// Set to `false` to have Twilio discard the media once sent.
+    pub store_media: Option<bool>,
+    /// Minutes to keep retrying a busy or unanswered fax.
+    pub ttl: Option<u32>,
+    pub sip_auth_username: Option<&'a str>,
+    pub sip_auth_password: Option<&'a str>,
+}
+
+impl<'a> NewFax<'a> {
+    pub fn new(to: &'a str, media_url: &'a str) -> NewFax<'a> {
+        NewFax {
+            to,
+            media_url,
+            from: None,
+            quality: None,
+            status_callback: None,
+            store_media: None,
+            ttl: None,
+            sip_auth_username: None,
+            sip_auth_password: None,
+        }
+    }
+}
+
+/// Filters accepted by `Client::list_faxes`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`.
+#[derive(Default)]
+pub struct FaxFilter<'a> {
+    pub from: Option<&'a str>,
+    pub to: Option<&'a str>,
+    pub date_created_after: Option<&'a str>,
+    pub date_created_on_or_before: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// Posted to a fax's `StatusCallback` as it is sent, and to a number's fax
+/// URL when one is received.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FaxStatusEvent {
+    pub fax_sid: FaxSid,
+    pub account_sid: Option<AccountSid>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub fax_status: FaxStatus,
+    /// The identifier the remote machine reported, if any.
+    pub remote_station_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub num_pages: Option<u32>,
+    pub original_media_url: Option<String>,
+    pub media_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub error_code: Option<u32>,
+    pub error_message: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for FaxStatusEvent {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<FaxStatusEvent>, TwilioError> {
+        from_params(m)
+    }
+}
+
+impl Client {
+    pub async fn send_fax(&self, fax: NewFax<'_>) -> Result<Fax, TwilioError> {
+        let ttl = fax.ttl.map(|t| t.to_string());
+        let mut opts = vec![("To", fax.to), ("MediaUrl", fax.media_url)];
+        if let Some(from) = fax.from {
+            opts.push(("From", from));
+        }
+        if let Some(ref q) = fax.quality {
+            opts.push(("Quality", q.as_str()));
+        }
+        if let Some(cb) = fax.status_callback {
+            opts.push(("StatusCallback", cb));
+        }
+        if let Some(s) = fax.store_media {
+            opts.push(("StoreMedia", bool_str(s)));
+        }
+        if let Some(ref t) = ttl {
+            opts.push(("Ttl", t));
+        }
+        if let Some(u) = fax.sip_auth_username {
+            opts.push(("SipAuthUsername", u));
+        }
+        if let Some(p) = fax.sip_auth_password {
+            opts.push(("SipAuthPassword", p));
+        }
+        self.send_product_request(POST, &FAX, "Faxes", &opts).await
+    }
+
+    pub fn list_faxes(&self, filter: &FaxFilter<'_>) -> PageIterator<Fax> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(from) = filter.from {
+            opts.push(("From", from));
+        }
+        if let Some(to) = filter.to {
+            opts.push(("To", to));
+        }
+        if let Some(after) = filter.date_created_after {
+            opts.push(("DateCreatedAfter", after));
+        }
+        if let Some(before) = filter.date_created_on_or_before {
+            opts.push(("DateCreatedOnOrBefore", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+        self.list_product(&FAX, "Faxes", "faxes", &opts)
+    }
+
+    pub async fn retrieve_fax(&self, sid: &FaxSid) -> Result<Fax, TwilioError> {
+        self.send_product_request(GET, &FAX, &format!("Faxes/{sid}"), &[])
+            .await
+    }
+
+    /// Cancels a fax that is still queued or being sent.
+    pub async fn cancel_fax(&self, sid: &FaxSid) -> Result<Fax, TwilioError> {
+        self.send_product_request(
+            POST,
+            &FAX,
+            &format!("Faxes/{sid}"),
+            &[("Status", "canceled")],
+        )
+        .await
+    }
+
+    /// Deletes the fax record along with its stored media.
+    pub async fn delete_fax(&self, sid: &FaxSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&FAX, &format!("Faxes/{sid}"))
+            .await
+    }
+}
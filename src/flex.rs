@@ -0,0 +1,211 @@
+//! [Flex Interactions](https://www.twilio.com/docs/flex/developer/conversations/interactions-api),
+//! for routing a customer contact on any channel to an agent through
+//! TaskRouter.
+
+use crate::endpoint::FLEX;
+use crate::{
+    Client, InteractionChannelSid, InteractionSid, PageIterator, TwilioError, WorkflowSid,
+    WorkspaceSid, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+pub enum ChannelType {
+    Voice,
+    Sms,
+    Email,
+    Web,
+    Whatsapp,
+    Chat,
+    Messenger,
+    Gbm,
+}
+
+impl ChannelType {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ChannelType::Voice => "voice",
+            ChannelType::Sms => "sms",
+            ChannelType::Email => "email",
+            ChannelType::Web => "web",
+            ChannelType::Whatsapp => "whatsapp",
+            ChannelType::Chat => "chat",
+            ChannelType::Messenger => "messenger",
+            ChannelType::Gbm => "gbm",
+        }
+    }
+}
+
+/// Who started the interaction: an outbound contact is `Agent` or `Api`, an
+/// inbound one `Customer`.
+pub enum InitiatedBy {
+    Api,
+    Agent,
+    Customer,
+}
+
+impl InitiatedBy {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            InitiatedBy::Api => "api",
+            InitiatedBy::Agent => "agent",
+            InitiatedBy::Customer => "customer",
+        }
+    }
+}
+
+/// An interaction to create with `Client::create_interaction`. A task is
+/// created in `workspace_sid` to route it to an agent.
+pub struct NewInteraction<'a> {
+    pub channel_type: ChannelType,
+    pub initiated_by: InitiatedBy,
+    /// The Conversation SID, for an interaction over an existing
+    /// conversation.
+    pub media_channel_sid: Option<&'a str>,
+    pub workspace_sid: &'a WorkspaceSid,
+    pub workflow_sid: Option<&'a WorkflowSid>,
+    pub task_channel_unique_name: Option<&'a str>,
+    /// Attributes for the task, used by the workflow to route it.
+    pub task_attributes: Option<serde_json::Value>,
+}
+
+impl<'a> NewInteraction<'a> {
+    pub fn new(
+        channel_type: ChannelType,
+        initiated_by: InitiatedBy,
+        workspace_sid: &'a WorkspaceSid,
+    ) -> NewInteraction<'a> {
+        NewInteraction {
+            channel_type,
+            initiated_by,
+            media_channel_sid: None,
+            workspace_sid,
+            workflow_sid: None,
+            task_channel_unique_name: None,
+            task_attributes: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Interaction {
+    pub sid: InteractionSid,
+    /// The channel as created, including its `type` and `properties`.
+    pub channel: serde_json::Value,
+    pub routing: Option<serde_json::Value>,
+    pub interaction_context_sid: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelStatus {
+    Setup,
+    Active,
+    Failed,
+    Closed,
+    Inactive,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InteractionChannel {
+    pub sid: InteractionChannelSid,
+    pub interaction_sid: InteractionSid,
+    /// E.g. `"sms"` or `"whatsapp"`.
+    #[serde(rename = "type")]
+    pub channel_type: String,
+    pub status: ChannelStatus,
+    pub error_code: Option<u32>,
+    pub error_message: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+impl Client {
+    pub async fn create_interaction(
+        &self,
+        interaction: &NewInteraction<'_>,
+    ) -> Result<Interaction, TwilioError> {
+        let mut channel = serde_json::json!({
+            "type": interaction.channel_type.as_str(),
+            "initiated_by": interaction.initiated_by.as_str(),
+        });
+        if let Some(sid) = interaction.media_channel_sid {
+            channel["properties"] = serde_json::json!({ "media_channel_sid": sid });
+        }
+        let mut properties = serde_json::json!({
+            "workspace_sid": interaction.workspace_sid.as_str(),
+        });
+        if let Some(sid) = interaction.workflow_sid {
+            properties["workflow_sid"] = sid.as_str().into();
+        }
+        if let Some(name) = interaction.task_channel_unique_name {
+            properties["task_channel_unique_name"] = name.into();
+        }
+        if let Some(ref attributes) = interaction.task_attributes {
+            properties["attributes"] = attributes.clone();
+        }
+        let channel = channel.to_string();
+        let routing = serde_json::json!({ "properties": properties }).to_string();
+        self.send_product_request(
+            POST,
+            &FLEX,
+            "Interactions",
+            &[("Channel", &channel), ("Routing", &routing)],
+        )
+        .await
+    }
+
+    pub async fn retrieve_interaction(
+        &self,
+        sid: &InteractionSid,
+    ) -> Result<Interaction, TwilioError> {
+        self.send_product_request(GET, &FLEX, &format!("Interactions/{sid}"), &[])
+            .await
+    }
+
+    pub fn list_interaction_channels(
+        &self,
+        interaction_sid: &InteractionSid,
+    ) -> PageIterator<InteractionChannel> {
+        self.list_product(
+            &FLEX,
+            &format!("Interactions/{interaction_sid}/Channels"),
+            "channels",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_interaction_channel(
+        &self,
+        interaction_sid: &InteractionSid,
+        sid: &InteractionChannelSid,
+    ) -> Result<InteractionChannel, TwilioError> {
+        self.send_product_request(
+            GET,
+            &FLEX,
+            &format!("Interactions/{interaction_sid}/Channels/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Closes an interaction's channel, which also completes its tasks and
+    /// ends the interaction once no channels are left open.
+    pub async fn close_interaction(
+        &self,
+        interaction_sid: &InteractionSid,
+        channel_sid: &InteractionChannelSid,
+    ) -> Result<InteractionChannel, TwilioError> {
+        self.send_product_request(
+            POST,
+            &FLEX,
+            &format!("Interactions/{interaction_sid}/Channels/{channel_sid}"),
+            &[("Status", "closed")],
+        )
+        .await
+    }
+}
@@ -0,0 +1,52 @@
+use crate::TwilioError;
+use hyper::{Method, StatusCode};
+use std::time::Duration;
+
+/// Observes every API call a `Client` makes, for example to record metrics.
+/// Register one with `ClientBuilder::hook`.
+///
+/// Hooks run once per call rather than once per attempt, so a request that
+/// was retried is reported once, with `attempts` counting the retries. They
+/// are called inline, so anything slow should be handed off elsewhere.
+///
+/// ```ignore
+/// struct Metrics;
+///
+/// impl RequestHook for Metrics {
+///     fn after_request(&self, request: &RequestInfo<'_>, outcome: &RequestOutcome<'_>) {
+///         histogram!("twilio_request_seconds", outcome.elapsed, "path" => request.path);
+///     }
+/// }
+/// ```
+pub trait RequestHook: Send + Sync {
+    fn before_request(&self, _request: &RequestInfo<'_>) {}
+
+    fn after_request(&self, request: &RequestInfo<'_>, outcome: &RequestOutcome<'_>);
+}
+
+/// The call a hook is being told about. `path` is the URL path without the
+/// query string, e.g. `/2010-04-01/Accounts/AC.../Messages.json`.
+#[derive(Debug)]
+pub struct RequestInfo<'a> {
+    pub method: &'a Method,
+    pub host: &'a str,
+    pub path: &'a str,
+    pub account_sid: &'a str,
+}
+
+#[derive(Debug)]
+pub struct RequestOutcome<'a> {
+    /// The status of the last response, if any response was received.
+    pub status: Option<StatusCode>,
+    /// Set when the call failed, after any retries.
+    pub error: Option<&'a TwilioError>,
+    pub attempts: u32,
+    /// Time from the first attempt to the final result, including backoff.
+    pub elapsed: Duration,
+}
+
+impl RequestOutcome<'_> {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
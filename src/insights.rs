@@ -0,0 +1,93 @@
+//! [Voice Insights](https://www.twilio.com/docs/voice/voice-insights/api),
+//! which reports quality metrics for calls. Summaries are only complete a
+//! few minutes after a call ends; check `processing_state` before relying on
+//! them.
+
+use crate::endpoint::INSIGHTS;
+use crate::{AccountSid, CallSid, Client, Timestamp, TwilioError, GET};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CallSummary {
+    pub account_sid: AccountSid,
+    pub call_sid: CallSid,
+    pub call_type: Option<String>,
+    pub call_state: Option<String>,
+    pub answered_by: Option<String>,
+    /// `partial` until all edges have reported, then `complete`.
+    pub processing_state: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub start_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub end_time: Option<Timestamp>,
+    pub duration: Option<u32>,
+    pub connect_duration: Option<u32>,
+    pub from: Option<Endpoint>,
+    pub to: Option<Endpoint>,
+    pub carrier_edge: Option<Edge>,
+    pub client_edge: Option<Edge>,
+    pub sdk_edge: Option<Edge>,
+    pub sip_edge: Option<Edge>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub properties: Option<Properties>,
+    pub url: Option<String>,
+}
+
+/// One party to a call.
+#[derive(Debug, Deserialize)]
+pub struct Endpoint {
+    pub caller: Option<String>,
+    pub callee: Option<String>,
+    pub carrier: Option<String>,
+    pub connection: Option<String>,
+    pub number_prefix: Option<String>,
+    pub country_code: Option<String>,
+}
+
+/// Metrics gathered at one point in the call path, such as the carrier edge
+/// or the Voice SDK.
+#[derive(Debug, Deserialize)]
+pub struct Edge {
+    pub metrics: Option<EdgeMetrics>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EdgeMetrics {
+    pub inbound: Option<StreamMetrics>,
+    pub outbound: Option<StreamMetrics>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StreamMetrics {
+    pub codec_name: Option<String>,
+    pub packets_received: Option<u64>,
+    pub packets_lost: Option<u64>,
+    pub packets_loss_percentage: Option<f64>,
+    pub jitter: Option<Jitter>,
+}
+
+/// Jitter in milliseconds.
+#[derive(Debug, Deserialize)]
+pub struct Jitter {
+    pub avg: Option<f64>,
+    pub max: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Properties {
+    pub direction: Option<String>,
+    /// Which party hung up: `caller` or `callee`.
+    pub disconnected_by: Option<String>,
+    pub last_sip_response_num: Option<u16>,
+    /// Post-dial delay in milliseconds.
+    pub pdd_ms: Option<u32>,
+    pub queue_time: Option<u32>,
+}
+
+impl Client {
+    pub async fn retrieve_call_summary(&self, sid: &CallSid) -> Result<CallSummary, TwilioError> {
+        self.send_product_request(GET, &INSIGHTS, &format!("Voice/{sid}/Summary"), &[])
+            .await
+    }
+}
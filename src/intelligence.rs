@@ -0,0 +1,192 @@
+//! [Voice Intelligence](https://www.twilio.com/docs/voice/intelligence/api)
+//! transcripts of recorded calls, with the results of the language operators
+//! (such as sentiment or PII extraction) configured on the service.
+
+use crate::endpoint::INTELLIGENCE;
+use crate::{
+    bool_str, AccountSid, Client, IntelligenceServiceSid, PageIterator, RecordingSid, Timestamp,
+    TranscriptSid, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranscriptStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Failed,
+    Canceled,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Transcript {
+    pub sid: TranscriptSid,
+    pub account_sid: AccountSid,
+    pub service_sid: IntelligenceServiceSid,
+    pub status: TranscriptStatus,
+    /// The media transcribed and who was on each channel.
+    pub channel: Option<serde_json::Value>,
+    pub language_code: Option<String>,
+    /// Your own identifier, set when the transcript was created.
+    pub customer_key: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub media_start_time: Option<Timestamp>,
+    /// Seconds of media transcribed.
+    pub duration: Option<u32>,
+    /// Whether PII is redacted from sentences by default.
+    pub redaction: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// Filters accepted by `Client::list_transcripts`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`.
+#[derive(Default)]
+pub struct TranscriptFilter<'a> {
+    pub service_sid: Option<&'a IntelligenceServiceSid>,
+    /// The recording the transcript was made from.
+    pub source_sid: Option<&'a str>,
+    pub customer_key: Option<&'a str>,
+    pub after_date_created: Option<&'a str>,
+    pub before_date_created: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// One sentence spoken on one channel of the media.
+#[derive(Debug, Deserialize)]
+pub struct Sentence {
+    pub sid: String,
+    /// The audio channel, which identifies the speaker.
+    pub media_channel: u32,
+    pub sentence_index: u32,
+    pub transcript: String,
+    /// Seconds from the start of the media.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub start_time: Option<f64>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub end_time: Option<f64>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub confidence: Option<f64>,
+    /// Per-word timings, when requested.
+    #[serde(default)]
+    pub words: Vec<serde_json::Value>,
+}
+
+/// What one language operator found in a transcript. Which fields are set
+/// depends on `operator_type`; e.g. a classifier sets `predicted_label`
+/// while an extractor sets `extract_results`.
+#[derive(Debug, Deserialize)]
+pub struct OperatorResult {
+    pub operator_sid: String,
+    pub transcript_sid: TranscriptSid,
+    pub name: Option<String>,
+    /// E.g. `conversation_classify`, `extract` or `pii_extract`.
+    pub operator_type: String,
+    pub extract_match: Option<bool>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub match_probability: Option<f64>,
+    pub normalized_result: Option<String>,
+    pub utterance_match: Option<bool>,
+    #[serde(default)]
+    pub utterance_results: Vec<serde_json::Value>,
+    pub predicted_label: Option<String>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub predicted_probability: Option<f64>,
+    #[serde(default)]
+    pub label_probabilities: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub extract_results: BTreeMap<String, serde_json::Value>,
+    pub text_generation_results: Option<serde_json::Value>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    /// Transcribes a call recording with the service's language and
+    /// operators. Transcription runs in the background; poll
+    /// `retrieve_transcript` until the status is `Completed`.
+    pub async fn create_transcript(
+        &self,
+        service_sid: &IntelligenceServiceSid,
+        recording_sid: &RecordingSid,
+        customer_key: Option<&str>,
+    ) -> Result<Transcript, TwilioError> {
+        let channel = serde_json::json!({
+            "media_properties": { "source_sid": recording_sid.as_str() },
+        })
+        .to_string();
+        let mut opts = vec![("ServiceSid", service_sid.as_str()), ("Channel", &channel)];
+        if let Some(key) = customer_key {
+            opts.push(("CustomerKey", key));
+        }
+        self.send_product_request(POST, &INTELLIGENCE, "Transcripts", &opts)
+            .await
+    }
+
+    pub fn list_transcripts(&self, filter: &TranscriptFilter<'_>) -> PageIterator<Transcript> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(sid) = filter.service_sid {
+            opts.push(("ServiceSid", sid.as_str()));
+        }
+        if let Some(sid) = filter.source_sid {
+            opts.push(("SourceSid", sid));
+        }
+        if let Some(key) = filter.customer_key {
+            opts.push(("CustomerKey", key));
+        }
+        if let Some(after) = filter.after_date_created {
+            opts.push(("AfterDateCreated", after));
+        }
+        if let Some(before) = filter.before_date_created {
+            opts.push(("BeforeDateCreated", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+        self.list_product(&INTELLIGENCE, "Transcripts", "transcripts", &opts)
+    }
+
+    pub async fn retrieve_transcript(
+        &self,
+        sid: &TranscriptSid,
+    ) -> Result<Transcript, TwilioError> {
+        self.send_product_request(GET, &INTELLIGENCE, &format!("Transcripts/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_transcript(&self, sid: &TranscriptSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&INTELLIGENCE, &format!("Transcripts/{sid}"))
+            .await
+    }
+
+    /// Lists the transcript's sentences in order. With `redacted`, PII is
+    /// masked as the service is configured to do.
+    pub fn list_transcript_sentences(
+        &self,
+        sid: &TranscriptSid,
+        redacted: bool,
+    ) -> PageIterator<Sentence> {
+        self.list_product(
+            &INTELLIGENCE,
+            &format!("Transcripts/{sid}/Sentences"),
+            "sentences",
+            &[("Redacted", bool_str(redacted))],
+        )
+    }
+
+    pub fn list_operator_results(&self, sid: &TranscriptSid) -> PageIterator<OperatorResult> {
+        self.list_product(
+            &INTELLIGENCE,
+            &format!("Transcripts/{sid}/OperatorResults"),
+            "operator_results",
+            &[],
+        )
+    }
+}
@@ -0,0 +1,316 @@
+//! [Access tokens](https://www.twilio.com/docs/iam/access-tokens) for the
+//! Voice, Video, Conversations and Sync client SDKs. Tokens are JWTs signed
+//! with an API key secret, so mint them on your server and hand them to the
+//! client. `CapabilityToken` covers apps still on twilio.js 1.x.
+//!
+//! ```ignore
+//! let token = AccessToken::new(&account_sid, &api_key_sid, api_key_secret)
+//!     .identity("alice")
+//!     .ttl(Duration::from_secs(600))
+//!     .voice_grant(VoiceGrant {
+//!         outgoing_application_sid: Some(app_sid),
+//!         incoming_allow: true,
+//!         ..Default::default()
+//!     })
+//!     .to_jwt();
+//! ```
+
+use crate::timer::{SystemTime, UNIX_EPOCH};
+use crate::{AccountSid, ApiKeySid, ApplicationSid, ConversationServiceSid, SyncServiceSid};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Map, Value};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Lets the client make and, with `incoming_allow`, receive calls.
+#[derive(Default)]
+pub struct VoiceGrant {
+    pub incoming_allow: bool,
+    /// The TwiML app whose voice URL handles outgoing calls.
+    pub outgoing_application_sid: Option<ApplicationSid>,
+    /// Passed to the TwiML app's voice URL along with each outgoing call.
+    pub outgoing_application_params: BTreeMap<String, String>,
+    /// The `CR` sid of an APNs or FCM credential for incoming call pushes.
+    pub push_credential_sid: Option<String>,
+}
+
+/// Lets the client join video rooms, or only `room` when set.
+#[derive(Default)]
+pub struct VideoGrant {
+    pub room: Option<String>,
+}
+
+/// Lets the client use Conversations in `service_sid`.
+#[derive(Default)]
+pub struct ChatGrant {
+    pub service_sid: Option<ConversationServiceSid>,
+    pub endpoint_id: Option<String>,
+    pub deployment_role_sid: Option<String>,
+    pub push_credential_sid: Option<String>,
+}
+
+/// Lets the client read and write Sync objects in `service_sid`.
+#[derive(Default)]
+pub struct SyncGrant {
+    pub service_sid: Option<SyncServiceSid>,
+    pub endpoint_id: Option<String>,
+}
+
+pub struct AccessToken {
+    account_sid: AccountSid,
+    api_key_sid: ApiKeySid,
+    api_key_secret: String,
+    identity: Option<String>,
+    ttl: Duration,
+    voice: Option<VoiceGrant>,
+    video: Option<VideoGrant>,
+    chat: Option<ChatGrant>,
+    sync: Option<SyncGrant>,
+}
+
+impl AccessToken {
+    /// A token valid for an hour, with no identity or grants.
+    pub fn new(
+        account_sid: &AccountSid,
+        api_key_sid: &ApiKeySid,
+        api_key_secret: &str,
+    ) -> AccessToken {
+        AccessToken {
+            account_sid: account_sid.clone(),
+            api_key_sid: api_key_sid.clone(),
+            api_key_secret: api_key_secret.to_string(),
+            identity: None,
+            ttl: Duration::from_secs(3600),
+            voice: None,
+            video: None,
+            chat: None,
+            sync: None,
+        }
+    }
+
+    /// The user the token is for. Required by every grant except video.
+    pub fn identity(mut self, identity: &str) -> AccessToken {
+        self.identity = Some(identity.to_string());
+        self
+    }
+
+    /// How long the token is valid for. Twilio rejects anything over 24 hours.
+    pub fn ttl(mut self, ttl: Duration) -> AccessToken {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn voice_grant(mut self, grant: VoiceGrant) -> AccessToken {
+        self.voice = Some(grant);
+        self
+    }
+
+    pub fn video_grant(mut self, grant: VideoGrant) -> AccessToken {
+        self.video = Some(grant);
+        self
+    }
+
+    pub fn chat_grant(mut self, grant: ChatGrant) -> AccessToken {
+        self.chat = Some(grant);
+        self
+    }
+
+    pub fn sync_grant(mut self, grant: SyncGrant) -> AccessToken {
+        self.sync = Some(grant);
+        self
+    }
+
+    /// Signs the token, issued now.
+    pub fn to_jwt(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let header = json!({
+            "typ": "JWT",
+            "alg": "HS256",
+            "cty": "twilio-fpa;v=1",
+        });
+        let payload = json!({
+            "jti": format!("{}-{}", self.api_key_sid, now),
+            "iss": self.api_key_sid.as_str(),
+            "sub": self.account_sid.as_str(),
+            "iat": now,
+            "nbf": now,
+            "exp": now + self.ttl.as_secs(),
+            "grants": self.grants(),
+        });
+        sign(&header, &payload, &self.api_key_secret)
+    }
+
+    fn grants(&self) -> Value {
+        let mut grants = Map::new();
+        if let Some(ref identity) = self.identity {
+            grants.insert("identity".to_string(), json!(identity));
+        }
+        if let Some(ref voice) = self.voice {
+            let mut grant = Map::new();
+            if voice.incoming_allow {
+                grant.insert("incoming".to_string(), json!({ "allow": true }));
+            }
+            if let Some(ref sid) = voice.outgoing_application_sid {
+                let mut outgoing = Map::new();
+                outgoing.insert("application_sid".to_string(), json!(sid));
+                if !voice.outgoing_application_params.is_empty() {
+                    outgoing.insert(
+                        "params".to_string(),
+                        json!(voice.outgoing_application_params),
+                    );
+                }
+                grant.insert("outgoing".to_string(), Value::Object(outgoing));
+            }
+            if let Some(ref sid) = voice.push_credential_sid {
+                grant.insert("push_credential_sid".to_string(), json!(sid));
+            }
+            grants.insert("voice".to_string(), Value::Object(grant));
+        }
+        if let Some(ref video) = self.video {
+            let mut grant = Map::new();
+            if let Some(ref room) = video.room {
+                grant.insert("room".to_string(), json!(room));
+            }
+            grants.insert("video".to_string(), Value::Object(grant));
+        }
+        if let Some(ref chat) = self.chat {
+            let mut grant = Map::new();
+            if let Some(ref sid) = chat.service_sid {
+                grant.insert("service_sid".to_string(), json!(sid));
+            }
+            if let Some(ref id) = chat.endpoint_id {
+                grant.insert("endpoint_id".to_string(), json!(id));
+            }
+            if let Some(ref sid) = chat.deployment_role_sid {
+                grant.insert("deployment_role_sid".to_string(), json!(sid));
+            }
+            if let Some(ref sid) = chat.push_credential_sid {
+                grant.insert("push_credential_sid".to_string(), json!(sid));
+            }
+            grants.insert("chat".to_string(), Value::Object(grant));
+        }
+        if let Some(ref sync) = self.sync {
+            let mut grant = Map::new();
+            if let Some(ref sid) = sync.service_sid {
+                grant.insert("service_sid".to_string(), json!(sid));
+            }
+            if let Some(ref id) = sync.endpoint_id {
+                grant.insert("endpoint_id".to_string(), json!(id));
+            }
+            grants.insert("data_sync".to_string(), Value::Object(grant));
+        }
+        Value::Object(grants)
+    }
+}
+
+/// Encodes and signs a JWT with HS256.
+fn sign(header: &Value, payload: &Value, secret: &str) -> String {
+    let mut token = format!(
+        "{}.{}",
+        base64::encode_config(header.to_string(), base64::URL_SAFE_NO_PAD),
+        base64::encode_config(payload.to_string(), base64::URL_SAFE_NO_PAD)
+    );
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(token.as_bytes());
+    let signature = mac.finalize().into_bytes();
+    token.push('.');
+    token.push_str(&base64::encode_config(signature, base64::URL_SAFE_NO_PAD));
+    token
+}
+
+/// A legacy [capability token](https://www.twilio.com/docs/voice/sdks/javascript/v1/capability-token)
+/// for twilio.js 1.x, signed with the account's auth token.
+pub struct CapabilityToken {
+    account_sid: AccountSid,
+    auth_token: String,
+    ttl: Duration,
+    client_name: Option<String>,
+    outgoing: Option<(ApplicationSid, BTreeMap<String, String>)>,
+}
+
+impl CapabilityToken {
+    /// A token valid for an hour that allows nothing.
+    pub fn new(account_sid: &AccountSid, auth_token: &str) -> CapabilityToken {
+        CapabilityToken {
+            account_sid: account_sid.clone(),
+            auth_token: auth_token.to_string(),
+            ttl: Duration::from_secs(3600),
+            client_name: None,
+            outgoing: None,
+        }
+    }
+
+    pub fn ttl(mut self, ttl: Duration) -> CapabilityToken {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Lets the client receive calls dialed to `<Client>client_name</Client>`.
+    pub fn allow_client_incoming(mut self, client_name: &str) -> CapabilityToken {
+        self.client_name = Some(client_name.to_string());
+        self
+    }
+
+    /// Lets the client make calls handled by the TwiML app `application_sid`,
+    /// which receives `params` alongside each call.
+    pub fn allow_client_outgoing(
+        mut self,
+        application_sid: &ApplicationSid,
+        params: BTreeMap<String, String>,
+    ) -> CapabilityToken {
+        self.outgoing = Some((application_sid.clone(), params));
+        self
+    }
+
+    /// Signs the token, issued now.
+    pub fn to_jwt(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let header = json!({ "typ": "JWT", "alg": "HS256" });
+        let payload = json!({
+            "iss": self.account_sid.as_str(),
+            "exp": now + self.ttl.as_secs(),
+            "scope": self.scopes().join(" "),
+        });
+        sign(&header, &payload, &self.auth_token)
+    }
+
+    fn scopes(&self) -> Vec<String> {
+        let mut scopes = Vec::new();
+        if let Some(ref name) = self.client_name {
+            scopes.push(scope("incoming", &[("clientName", name)]));
+        }
+        if let Some((ref sid, ref params)) = self.outgoing {
+            let app_params = form_urlencode(params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+            let mut query = vec![("appSid", sid.as_str())];
+            if !params.is_empty() {
+                query.push(("appParams", &app_params));
+            }
+            if let Some(ref name) = self.client_name {
+                query.push(("clientName", name));
+            }
+            scopes.push(scope("outgoing", &query));
+        }
+        scopes
+    }
+}
+
+fn scope(privilege: &str, params: &[(&str, &str)]) -> String {
+    format!(
+        "scope:client:{}?{}",
+        privilege,
+        form_urlencode(params.iter().copied())
+    )
+}
+
+fn form_urlencode<'a>(pairs: impl Iterator<Item = (&'a str, &'a str)>) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish()
+}
@@ -1,30 +1,254 @@
+#[cfg(feature = "a2p")]
+pub mod a2p;
+mod account;
+#[cfg(feature = "actix")]
+pub mod actix;
+mod address;
+mod application;
+mod auth;
+mod available_phone_number;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod builder;
+#[cfg(feature = "voice")]
 mod call;
+#[cfg(feature = "voice")]
+mod conference;
+#[cfg(feature = "content")]
+pub mod content;
+#[cfg(feature = "conversations")]
+pub mod conversations;
+mod endpoint;
+mod error;
+#[cfg(feature = "events")]
+pub mod events;
+#[cfg(feature = "fax")]
+pub mod fax;
+#[cfg(feature = "flex")]
+pub mod flex;
+mod hook;
+#[cfg(feature = "insights")]
+pub mod insights;
+#[cfg(feature = "intelligence")]
+pub mod intelligence;
+pub mod jwt;
+#[cfg(feature = "lookup")]
+pub mod lookup;
+#[cfg(feature = "messaging")]
 mod message;
+#[cfg(feature = "messaging")]
+pub mod messaging;
+mod money;
+#[cfg(feature = "monitor")]
+pub mod monitor;
+#[cfg(feature = "notify")]
+pub mod notify;
+mod page;
+pub mod phone;
+mod phone_number;
+#[cfg(feature = "pricing")]
+pub mod pricing;
+#[cfg(feature = "proxy-service")]
+pub mod proxy;
+#[cfg(feature = "voice")]
+mod queue;
+mod rate_limit;
+#[cfg(feature = "voice")]
+mod recording;
+mod redact;
+mod response;
+mod retry;
+#[cfg(feature = "serverless")]
+pub mod serverless;
+#[cfg(feature = "messaging")]
+mod short_code;
+mod sid;
+mod sip;
+#[cfg(feature = "streams")]
+pub mod streams;
+#[cfg(feature = "studio")]
+pub mod studio;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "taskrouter")]
+pub mod taskrouter;
+mod timer;
+mod timestamp;
+#[cfg(feature = "voice")]
+mod transcription;
+mod transport;
+#[cfg(feature = "trunking")]
+pub mod trunking;
+#[cfg(feature = "trusthub")]
+pub mod trusthub;
 pub mod twiml;
+mod usage;
+#[cfg(feature = "verify")]
+pub mod verify;
+#[cfg(feature = "video")]
+pub mod video;
+#[cfg(feature = "warp")]
+pub mod warp;
 mod webhook;
 
-pub use call::{Call, CallStatus, OutboundCall};
-use headers::authorization::{Authorization, Basic};
+pub use account::{Account, AccountFilter, AccountStatus, AccountType, AccountUpdate, Balance};
+pub use address::{Address, AddressFilter, AddressUpdate, NewAddress};
+pub use application::{Application, ApplicationConfig};
+#[cfg(feature = "client-validation")]
+pub use auth::ClientValidation;
+pub use auth::{
+    BasicCredentials, CredentialsFuture, CredentialsProvider, EnvCredentials, FileCredentials,
+};
+use auth::{Credentials, RequestAuth};
+pub use available_phone_number::{AvailablePhoneNumber, AvailablePhoneNumberFilter, NumberType};
+pub use builder::ClientBuilder;
+#[cfg(feature = "voice")]
+pub use call::{
+    AnsweredBy, Call, CallDirection, CallEvent, CallEventRecord, CallEventRequest,
+    CallEventResponse, CallFeedback, CallFeedbackIssue, CallFilter, CallInstructions, CallStatus,
+    CallStatusEvent, CallUpdate, CallUpdateStatus, FeedbackIssueCount, FeedbackSummary,
+    FeedbackSummaryStatus, GatherResult, MachineDetection, OutboundCall, PayOutcome, PayResult,
+};
+#[cfg(feature = "voice")]
+pub use conference::{
+    Conference, ConferenceFilter, ConferenceStatus, OutboundParticipant, Participant,
+    ParticipantStatus, ParticipantUpdate,
+};
+use endpoint::Endpoint;
+pub use error::{ApiError, TwilioError};
 use headers::{ContentType, HeaderMapExt};
-use hyper::client::connect::HttpConnector;
+pub use hook::{RequestHook, RequestInfo, RequestOutcome};
+use hyper::header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, LOCATION};
 use hyper::{Body, Method, StatusCode};
-use hyper_tls::HttpsConnector;
-pub use message::{Message, OutboundMessage};
+#[cfg(feature = "messaging")]
+pub use message::{
+    BulkOptions, FeedbackOutcome, Media, MediaContent, Message, MessageAddress, MessageFeedback,
+    MessageFilter, MessageStatus, MessageStatusEvent, MessageUpdate, MessageUpdateStatus,
+    OutboundMessage, SenderKind,
+};
+pub use money::{Decimal, Money};
+pub use page::{Page, PageIterator};
+pub use phone_number::{
+    Capabilities, IncomingPhoneNumber, IncomingPhoneNumberFilter, PhoneNumberConfig,
+};
+#[cfg(feature = "voice")]
+pub use queue::{Member, Queue, QueueUpdate};
+use rate_limit::RateLimiter;
+#[cfg(feature = "voice")]
+pub use recording::{
+    Recording, RecordingFilter, RecordingFormat, RecordingStatus, RecordingStatusEvent,
+};
+pub use redact::Redaction;
+pub use response::{ResponseMeta, WithMeta};
+pub use retry::RetryPolicy;
+#[cfg(feature = "messaging")]
+pub use short_code::{ShortCode, ShortCodeFilter, ShortCodeUpdate};
+pub use sid::{
+    AccountSid, ActivitySid, AddressSid, AlertSid, ApiKeySid, ApplicationSid, AssetSid,
+    AssetVersionSid, BrandRegistrationSid, BuildSid, BundleSid, CallSid, CompositionSid,
+    ConferenceSid, ContentSid, ConversationMessageSid, ConversationParticipantSid,
+    ConversationServiceSid, ConversationSid, ConversationWebhookSid, CredentialListSid,
+    DeploymentSid, EndUserSid, EnvironmentSid, ExecutionSid, FaxSid, FeedbackSummarySid, FlowSid,
+    FunctionSid, FunctionVersionSid, IntelligenceServiceSid, InteractionChannelSid, InteractionSid,
+    InvalidSid, IpAccessControlListSid, IpAddressSid, MediaSid, MessageSid, MessagingServiceSid,
+    MonitorEventSid, NotificationSid, NotifyBindingSid, NotifyServiceSid, OriginationUrlSid,
+    PhoneNumberSid, ProxyParticipantSid, ProxyServiceSid, ProxySessionSid, QueueSid, RecordingSid,
+    ReservationSid, RoomParticipantSid, RoomRecordingSid, RoomSid, ServerlessServiceSid,
+    ShortCodeSid, SinkSid, SipCredentialSid, SipDomainSid, StreamSid, SubscriptionSid,
+    SupportingDocumentSid, SyncDocumentSid, SyncListSid, SyncMapSid, SyncServiceSid, TaskQueueSid,
+    TaskSid, TranscriptSid, TranscriptionSid, TrunkSid, UsAppToPersonSid, UsageTriggerSid,
+    VerificationSid, VerifyServiceSid, WorkerSid, WorkflowSid, WorkspaceSid,
+};
+pub use sip::{
+    CredentialList, IpAccessControlList, IpAddress, SipCredential, SipDomain, SipDomainConfig,
+};
 use std::collections::BTreeMap;
-use std::error::Error;
-use std::fmt::{self, Display, Formatter};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use timer::Instant;
+pub use timestamp::Timestamp;
+#[cfg(feature = "voice")]
+pub use transcription::{Transcription, TranscriptionStatus};
+#[cfg(feature = "isahc")]
+pub use transport::IsahcTransport;
+#[cfg(feature = "reqwest")]
+pub use transport::ReqwestTransport;
+pub use transport::{MockTransport, RecordedRequest, Transport, TransportFuture};
 use url::form_urlencoded;
+pub use usage::{
+    NewUsageTrigger, TriggerBy, TriggerRecurrence, UsageFilter, UsageGranularity, UsageRecord,
+    UsageTrigger, UsageTriggerUpdate,
+};
+pub use webhook::{validate_request, validate_request_with_body};
 
 pub const GET: Method = Method::GET;
 pub const POST: Method = Method::POST;
 pub const PUT: Method = Method::PUT;
+pub const DELETE: Method = Method::DELETE;
 
 #[derive(Clone)]
 pub struct Client {
     account_id: String,
-    auth_token: String,
-    auth_header: Authorization<Basic>,
-    http_client: hyper::Client<HttpsConnector<HttpConnector>>,
+    auth_token: Option<String>,
+    credentials: Arc<Credentials>,
+    #[cfg(feature = "client-validation")]
+    client_validation: Option<ClientValidation>,
+    region: Option<String>,
+    edge: Option<String>,
+    request_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    http_client: Arc<dyn Transport>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    #[cfg(feature = "voice")]
+    call_limiter: Option<Arc<RateLimiter>>,
+    hooks: Arc<[Box<dyn RequestHook>]>,
+    debug_logging: Option<Redaction>,
+    extra_headers: Arc<hyper::HeaderMap>,
+}
+
+/// What happened across the attempts at one request, for hooks and tracing.
+#[derive(Default)]
+struct AttemptStats {
+    attempts: u32,
+    status: Option<StatusCode>,
+}
+
+/// An encoded request body, tagged with the content type it is sent as.
+enum RequestBody {
+    Form(String),
+    #[cfg_attr(not(feature = "content"), allow(dead_code))]
+    Json(String),
+    #[cfg_attr(not(feature = "serverless"), allow(dead_code))]
+    Multipart {
+        boundary: String,
+        data: hyper::body::Bytes,
+    },
+}
+
+/// A file sent in a `multipart/form-data` upload.
+#[cfg(feature = "serverless")]
+struct FilePart<'a> {
+    name: &'a str,
+    filename: &'a str,
+    content_type: &'a str,
+    data: &'a [u8],
+}
+
+/// Writes a request out at debug level for `ClientBuilder::debug_logging`.
+/// Headers, and so the credentials, are never included.
+fn log_request(redaction: Redaction, method: &Method, url: &str, body: &Option<RequestBody>) {
+    let body = match body {
+        Some(RequestBody::Form(b)) => redaction.form(b),
+        Some(RequestBody::Json(b)) => redaction.body(b.as_bytes()),
+        Some(RequestBody::Multipart { data, .. }) => format!("<multipart, {} bytes>", data.len()),
+        None => String::new(),
+    };
+    log::debug!(target: "twilio", "{method} {} {body}", redaction.url(url));
 }
 
 fn url_encode(params: &[(&str, &str)]) -> String {
@@ -36,50 +260,206 @@ fn url_encode(params: &[(&str, &str)]) -> String {
     url.finish()
 }
 
-#[derive(Debug)]
-pub enum TwilioError {
-    NetworkError(hyper::Error),
-    HTTPError(StatusCode),
-    ParsingError,
-    AuthError,
-    BadRequest,
+/// Encodes `fields` followed by `file` as `multipart/form-data`, returning the
+/// boundary along with the body. The boundary is lengthened until it no
+/// longer appears in the file.
+#[cfg(feature = "serverless")]
+fn multipart_encode(fields: &[(&str, &str)], file: &FilePart<'_>) -> (String, Vec<u8>) {
+    let mut boundary = String::from("twilio-rs-boundary");
+    while file
+        .data
+        .windows(boundary.len())
+        .any(|w| w == boundary.as_bytes())
+    {
+        boundary.push('x');
+    }
+    let mut data = Vec::with_capacity(file.data.len() + 256);
+    for (name, value) in fields {
+        data.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+            )
+            .as_bytes(),
+        );
+    }
+    data.extend_from_slice(
+        format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+             Content-Type: {}\r\n\r\n",
+            file.name, file.filename, file.content_type
+        )
+        .as_bytes(),
+    );
+    data.extend_from_slice(file.data);
+    data.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+    (boundary, data)
 }
 
-impl Display for TwilioError {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match *self {
-            TwilioError::NetworkError(ref e) => e.fmt(f),
-            TwilioError::HTTPError(ref s) => write!(f, "Invalid HTTP status code: {s}"),
-            TwilioError::ParsingError => f.write_str("Parsing error"),
-            TwilioError::AuthError => f.write_str("Missing `X-Twilio-Signature` header in request"),
-            TwilioError::BadRequest => f.write_str("Bad request"),
-        }
-    }
+/// Whether `uri` is an https URL on a Twilio host, and so may be sent the
+/// client's credentials.
+fn is_twilio_url(uri: &hyper::Uri) -> bool {
+    let host = uri.host().unwrap_or_default().to_ascii_lowercase();
+    uri.scheme_str() == Some("https") && (host == "twilio.com" || host.ends_with(".twilio.com"))
 }
 
-impl Error for TwilioError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match *self {
-            TwilioError::NetworkError(ref e) => Some(e),
-            _ => None,
-        }
+fn bool_str(b: bool) -> &'static str {
+    if b {
+        "true"
+    } else {
+        "false"
     }
 }
 
+/// The future returned by handlers built with `Client::webhook_handler`.
+pub type WebhookFuture =
+    Pin<Box<dyn Future<Output = Result<hyper::Response<Body>, Infallible>> + Send>>;
+
 pub trait FromMap {
     fn from_map(m: BTreeMap<String, String>) -> Result<Box<Self>, TwilioError>;
 }
 
+/// Deserializes webhook parameters into any `Deserialize` type, which makes
+/// implementing `FromMap` for your own types a one-liner.
+pub fn from_params<T: serde::de::DeserializeOwned>(
+    m: BTreeMap<String, String>,
+) -> Result<Box<T>, TwilioError> {
+    let obj = m
+        .into_iter()
+        .map(|(k, v)| (k, serde_json::Value::String(v)))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(obj))
+        .map(Box::new)
+        .map_err(TwilioError::ParsingError)
+}
+
+/// Deserializes an optional number that webhooks send as a string, e.g.
+/// `ErrorCode=30003`. Empty strings are treated as missing.
+#[cfg_attr(not(any(feature = "voice", feature = "messaging")), allow(dead_code))]
+fn number_from_str<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    use serde::de::Error;
+    use serde::Deserialize;
+
+    let s = match Option::<serde_json::Value>::deserialize(d)? {
+        Some(serde_json::Value::String(s)) => s,
+        Some(serde_json::Value::Number(n)) => n.to_string(),
+        Some(serde_json::Value::Null) | None => return Ok(None),
+        Some(v) => return Err(D::Error::custom(format!("expected a number, got {v}"))),
+    };
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(D::Error::custom)
+}
+
 impl Client {
+    /// Uses the default HTTP backend; without a backend feature, give
+    /// `ClientBuilder::transport` a `Transport` instead.
+    #[cfg(any(feature = "native-tls", feature = "isahc", feature = "reqwest"))]
     pub fn new(account_id: &str, auth_token: &str) -> Client {
+        ClientBuilder::new(account_id, auth_token)
+            .build()
+            .expect("failed to initialize the HTTP client")
+    }
+
+    pub fn builder(account_id: &str, auth_token: &str) -> ClientBuilder {
+        ClientBuilder::new(account_id, auth_token)
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "isahc", feature = "reqwest"))]
+    pub fn with_api_key(account_id: &str, api_key_sid: &str, api_key_secret: &str) -> Client {
+        ClientBuilder::with_api_key(account_id, api_key_sid, api_key_secret)
+            .build()
+            .expect("failed to initialize the HTTP client")
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "isahc", feature = "reqwest"))]
+    pub fn with_oauth(account_id: &str, client_id: &str, client_secret: &str) -> Client {
+        ClientBuilder::with_oauth(account_id, client_id, client_secret)
+            .build()
+            .expect("failed to initialize the HTTP client")
+    }
+
+    /// Returns a client that acts on a subaccount (`AC...`) using this client's
+    /// credentials, which must belong to the subaccount or its parent. Note that
+    /// the subaccount's webhooks are signed with its own auth token.
+    pub fn with_account(&self, account_id: &AccountSid) -> Client {
         Client {
             account_id: account_id.to_string(),
-            auth_token: auth_token.to_string(),
-            auth_header: Authorization::basic(account_id, auth_token),
-            http_client: hyper::Client::builder().build(HttpsConnector::new()),
+            ..self.clone()
         }
     }
 
+    /// Returns a client that sends `name: value` with every request, on top
+    /// of this client's headers. Like `with_account`, this is cheap, so a
+    /// multi-tenant service can make one per request to tag it with e.g. a
+    /// tenant or trace ID. Headers the client sets itself, such as
+    /// `Authorization`, can't be replaced.
+    pub fn with_header(&self, name: HeaderName, value: HeaderValue) -> Client {
+        let mut extra_headers = (*self.extra_headers).clone();
+        extra_headers.insert(name, value);
+        Client {
+            extra_headers: Arc::new(extra_headers),
+            ..self.clone()
+        }
+    }
+
+    /// Calls an endpoint this crate doesn't wrap yet, with the client's
+    /// credentials, retries, rate limits and hooks. `path` is either a full
+    /// URL, such as `https://video.twilio.com/v1/Rooms`, or a path on
+    /// api.twilio.com, such as `/2010-04-01/Accounts/AC.../Calls.json`.
+    /// `params` are sent as the query of a `GET` and as a form body otherwise.
+    pub async fn request_json<T>(
+        &self,
+        method: hyper::Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.send_request_to_url(method, self.resolve_uri(path)?, params)
+            .await
+    }
+
+    /// Like `request_json`, also returning the response's status and headers.
+    pub async fn request_json_with_meta<T>(
+        &self,
+        method: hyper::Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<WithMeta<T>, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let (meta, bytes) = self
+            .send_raw_request_with_meta(method, self.resolve_uri(path)?, params)
+            .await?;
+        let value = serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)?;
+        Ok(WithMeta { value, meta })
+    }
+
+    /// Like `request_json`, for responses without a type to deserialize into.
+    /// An empty response, such as a `DELETE`'s, becomes `Value::Null`.
+    pub async fn request_value(
+        &self,
+        method: hyper::Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, TwilioError> {
+        let bytes = self
+            .send_raw_request(method, self.resolve_uri(path)?, params)
+            .await?;
+        if bytes.is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)
+    }
+
     async fn send_request<T>(
         &self,
         method: hyper::Method,
@@ -89,71 +469,521 @@ impl Client {
     where
         T: serde::de::DeserializeOwned,
     {
-        let mut url = format!(
-            "https://api.twilio.com/2010-04-01/Accounts/{}/{}.json",
-            self.account_id, endpoint
-        );
+        let url = self.account_url(endpoint, "json");
+        self.send_request_to_url(method, url, params).await
+    }
+
+    fn account_url(&self, endpoint: &str, extension: &str) -> String {
+        self.product_url(
+            &endpoint::API,
+            &format!("Accounts/{}/{}.{}", self.account_id, endpoint, extension),
+        )
+    }
+
+    /// Sends a `POST` that creates a resource, tagged with an idempotency token
+    /// if the caller gave one. Twilio returns the original result for a
+    /// repeated token instead of creating a duplicate.
+    #[cfg(any(feature = "voice", feature = "messaging"))]
+    async fn send_create_request<T>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, &str)],
+        idempotency_token: Option<&str>,
+    ) -> Result<WithMeta<T>, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.account_url(endpoint, "json");
+        let body = Some(RequestBody::Form(url_encode(params)));
+        let (meta, bytes) = self.send_body(POST, url, body, idempotency_token).await?;
+        let value = serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)?;
+        Ok(WithMeta { value, meta })
+    }
+
+    /// Sends a request to one of Twilio's product APIs hosted outside of
+    /// api.twilio.com, such as `endpoint::VERIFY`.
+    async fn send_product_request<T>(
+        &self,
+        method: hyper::Method,
+        endpoint: &Endpoint,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.product_url(endpoint, path);
+        self.send_request_to_url(method, url, params).await
+    }
+
+    fn product_url(&self, endpoint: &Endpoint, path: &str) -> String {
+        format!(
+            "https://{}/{}/{}",
+            self.hostname(endpoint.host),
+            endpoint.version,
+            path
+        )
+    }
+
+    /// Applies the configured edge and region, giving e.g.
+    /// `api.dublin.ie1.twilio.com`. An edge without a region implies `us1`.
+    fn hostname(&self, product: &str) -> String {
+        let region = match (&self.region, &self.edge) {
+            (Some(r), _) => Some(r.as_str()),
+            (None, Some(_)) => Some("us1"),
+            (None, None) => None,
+        };
+        let mut pieces = vec![product];
+        if let Some(ref e) = self.edge {
+            pieces.push(e);
+        }
+        if let Some(r) = region {
+            pieces.push(r);
+        }
+        pieces.push("twilio.com");
+        pieces.join(".")
+    }
+
+    // Only product APIs delete through here.
+    #[cfg_attr(not(feature = "all-products"), allow(dead_code))]
+    async fn send_product_delete(
+        &self,
+        endpoint: &Endpoint,
+        path: &str,
+    ) -> Result<(), TwilioError> {
+        let url = self.product_url(endpoint, path);
+        self.send_raw_request(DELETE, url, &[]).await.map(|_| ())
+    }
+
+    /// Sends a `DELETE`, for which Twilio responds with an empty body.
+    async fn send_delete(&self, endpoint: &str) -> Result<(), TwilioError> {
+        let url = self.account_url(endpoint, "json");
+        self.send_raw_request(DELETE, url, &[]).await.map(|_| ())
+    }
+
+    /// Follows a `next_page_uri` returned by Twilio, which may be relative or absolute.
+    async fn send_request_to_uri<T>(&self, uri: &str) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.send_request_to_url(GET, self.resolve_uri(uri)?, &[])
+            .await
+    }
+
+    /// Turns a path relative to api.twilio.com into a URL, leaving full URLs
+    /// as they are. Full URLs must be https on a Twilio host, since the
+    /// request carries the client's credentials.
+    fn resolve_uri(&self, uri: &str) -> Result<String, TwilioError> {
+        if !uri.contains("://") {
+            return Ok(format!(
+                "https://{}{uri}",
+                self.hostname(endpoint::API.host)
+            ));
+        }
+        let parsed: hyper::Uri = uri
+            .parse()
+            .map_err(|e| TwilioError::InvalidUrl(Box::new(e)))?;
+        if !is_twilio_url(&parsed) {
+            return Err(TwilioError::InvalidUrl(
+                format!("{uri} is not an https URL on a Twilio host").into(),
+            ));
+        }
+        Ok(uri.to_string())
+    }
+
+    async fn send_request_to_url<T>(
+        &self,
+        method: hyper::Method,
+        url: String,
+        params: &[(&str, &str)],
+    ) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let bytes = self.send_raw_request(method, url, params).await?;
+        serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)
+    }
+
+    /// Sends a JSON body, for the newer APIs (such as Content) that don't take
+    /// form parameters.
+    #[cfg(feature = "content")]
+    async fn send_product_json<T>(
+        &self,
+        method: hyper::Method,
+        endpoint: &Endpoint,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.product_url(endpoint, path);
+        let (_, bytes) = self
+            .send_body(method, url, Some(RequestBody::Json(body.to_string())), None)
+            .await?;
+        serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)
+    }
+
+    /// Uploads a file, for the few endpoints (such as Serverless versions) that
+    /// take `multipart/form-data`.
+    #[cfg(feature = "serverless")]
+    async fn send_product_multipart<T>(
+        &self,
+        endpoint: &Endpoint,
+        path: &str,
+        fields: &[(&str, &str)],
+        file: FilePart<'_>,
+    ) -> Result<T, TwilioError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.product_url(endpoint, path);
+        let (boundary, data) = multipart_encode(fields, &file);
+        let body = RequestBody::Multipart {
+            boundary,
+            data: data.into(),
+        };
+        let (_, bytes) = self.send_body(POST, url, Some(body), None).await?;
+        serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)
+    }
 
+    /// Waits for the `calls_per_second` limit, before a request that places a call.
+    #[cfg(feature = "voice")]
+    async fn acquire_call_slot(&self) {
+        if let Some(ref limiter) = self.call_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    async fn send_raw_request(
+        &self,
+        method: hyper::Method,
+        url: String,
+        params: &[(&str, &str)],
+    ) -> Result<hyper::body::Bytes, TwilioError> {
+        self.send_raw_request_with_meta(method, url, params)
+            .await
+            .map(|(_, bytes)| bytes)
+    }
+
+    async fn send_raw_request_with_meta(
+        &self,
+        method: hyper::Method,
+        mut url: String,
+        params: &[(&str, &str)],
+    ) -> Result<(ResponseMeta, hyper::body::Bytes), TwilioError> {
         let body = if method == hyper::Method::GET {
             if !params.is_empty() {
                 url.push('?');
                 url.push_str(&url_encode(params));
             }
-            Body::empty()
+            None
         } else {
-            Body::from(url_encode(params))
+            Some(RequestBody::Form(url_encode(params)))
         };
+        self.send_body(method, url, body, None).await
+    }
 
-        // Build request with headers BEFORE setting the body
-        let mut req_builder = hyper::Request::builder().method(method.clone()).uri(&*url);
-
-        // Get mutable reference to headers before body is set
-        let headers = req_builder.headers_mut().unwrap();
-        if method != hyper::Method::GET {
-            let mime: mime::Mime = "application/x-www-form-urlencoded".parse().unwrap();
-            headers.typed_insert(ContentType::from(mime));
+    /// Sends the request, then reports it to any hooks and, with the `tracing`
+    /// feature, to a `twilio.request` span. Both see the account SID but never
+    /// the credentials, and the path but not the query, which may hold phone
+    /// numbers. The span's target is `twilio::request`, so that where tracing
+    /// is bridged to `log` it stays apart from `debug_logging`'s `twilio`.
+    async fn send_body(
+        &self,
+        method: hyper::Method,
+        url: String,
+        body: Option<RequestBody>,
+        idempotency_token: Option<&str>,
+    ) -> Result<(ResponseMeta, hyper::body::Bytes), TwilioError> {
+        let uri: hyper::Uri = url
+            .parse()
+            .map_err(|e| TwilioError::InvalidUrl(Box::new(e)))?;
+        let info = RequestInfo {
+            method: &method,
+            host: uri.host().unwrap_or_default(),
+            path: uri.path(),
+            account_sid: &self.account_id,
+        };
+        for hook in self.hooks.iter() {
+            hook.before_request(&info);
         }
-        headers.typed_insert(self.auth_header.clone());
 
-        // Now create the request with body
-        let req = req_builder.body(body).unwrap();
+        let mut stats = AttemptStats::default();
+        let started = Instant::now();
+        let sent = self.send_with_retries(&method, &uri, &body, idempotency_token, &mut stats);
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            target: "twilio::request",
+            "twilio.request",
+            method = %method,
+            path = %info.path,
+            account_sid = %self.account_id,
+            status = tracing::field::Empty,
+            error_code = tracing::field::Empty,
+            attempts = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let sent = tracing::Instrument::instrument(sent, span.clone());
+        let result = sent.await;
 
-        let resp = self
-            .http_client
-            .request(req)
-            .await
-            .map_err(TwilioError::NetworkError)?;
+        let outcome = RequestOutcome {
+            status: stats.status,
+            error: result.as_ref().err(),
+            attempts: stats.attempts,
+            elapsed: started.elapsed(),
+        };
+        #[cfg(feature = "tracing")]
+        {
+            span.record("attempts", outcome.attempts);
+            span.record("latency_ms", outcome.elapsed.as_millis() as u64);
+            if let Some(status) = outcome.status {
+                span.record("status", status.as_u16());
+            }
+            match outcome.error {
+                None => {
+                    tracing::debug!(target: "twilio::request", parent: &span, "request succeeded")
+                }
+                Some(e) => {
+                    if let Some(code) = e.code() {
+                        span.record("error_code", code);
+                    }
+                    tracing::warn!(
+                        target: "twilio::request",
+                        parent: &span,
+                        error = %e,
+                        "request failed"
+                    );
+                }
+            }
+        }
+        for hook in self.hooks.iter() {
+            hook.after_request(&info, &outcome);
+        }
+        result
+    }
+
+    async fn send_with_retries(
+        &self,
+        method: &hyper::Method,
+        uri: &hyper::Uri,
+        body: &Option<RequestBody>,
+        idempotency_token: Option<&str>,
+        stats: &mut AttemptStats,
+    ) -> Result<(ResponseMeta, hyper::body::Bytes), TwilioError> {
+        let url = uri.to_string();
+        let idempotent = retry::is_idempotent(method) || idempotency_token.is_some();
+        let started = Instant::now();
+        let mut attempt = 1;
+        loop {
+            if let Some(ref limiter) = self.rate_limiter {
+                limiter.acquire().await;
+            }
+            if let Some(redaction) = self.debug_logging {
+                log_request(redaction, method, &url, body);
+            }
+            let authorization = self.authorization().await?;
+            let req = self.build_request(method, uri, body, idempotency_token, authorization)?;
+            let result = match self.request_timeout {
+                Some(t) => timer::timeout(t, self.execute(req))
+                    .await
+                    .unwrap_or(Err(TwilioError::Timeout)),
+                None => self.execute(req).await,
+            };
+            if let Some(redaction) = self.debug_logging {
+                match result {
+                    Ok((status, _, ref bytes)) => log::debug!(
+                        target: "twilio",
+                        "{method} {} -> {status} {}",
+                        redaction.url(&url),
+                        redaction.body(bytes)
+                    ),
+                    Err(ref e) => log::debug!(
+                        target: "twilio",
+                        "{method} {} -> {e}",
+                        redaction.url(&url)
+                    ),
+                }
+            }
+            stats.attempts = attempt;
+            if let Ok((status, _, _)) = result {
+                stats.status = Some(status);
+            }
 
-        match resp.status() {
-            StatusCode::CREATED | StatusCode::OK => {}
-            other => {
-                // Try to get error details from response body
-                if let Ok(body_bytes) = hyper::body::to_bytes(resp.into_body()).await {
-                    if let Ok(error_text) = std::str::from_utf8(&body_bytes) {
-                        println!("Twilio error response: {error_text}");
+            let (retryable, wait) = match result {
+                Ok((status, headers, bytes)) if status.is_success() => {
+                    return Ok((ResponseMeta { status, headers }, bytes))
+                }
+                Ok((status, ref headers, _)) if status.is_redirection() && *method == GET => {
+                    if let Some(location) = headers.get(LOCATION).and_then(|l| l.to_str().ok()) {
+                        return self.get_without_credentials(location).await;
                     }
+                    (false, None)
                 }
-                return Err(TwilioError::HTTPError(other));
+                Ok((status, ref headers, _)) => (
+                    retry::should_retry_status(idempotent, status),
+                    retry::retry_after(headers),
+                ),
+                Err(ref e) if e.is_network() => (retry::should_retry_error(idempotent, e), None),
+                Err(e) => return Err(e),
+            };
+            let wait = wait.unwrap_or_else(|| self.retry_policy.backoff(attempt));
+            if !retryable
+                || attempt >= self.retry_policy.max_attempts
+                || started.elapsed() + wait > self.retry_policy.max_elapsed
+            {
+                return match result {
+                    Ok((status, headers, bytes)) => {
+                        Err(TwilioError::from_response(status, &headers, &bytes))
+                    }
+                    Err(e) => Err(e),
+                };
+            }
+            timer::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+
+    /// Fetches a URL off Twilio's hosts, such as the target of a redirect to
+    /// media that Twilio serves from a pre-signed storage URL. The client's
+    /// credentials aren't sent, since they must never leave Twilio's hosts
+    /// and the target is usually signed already.
+    pub(crate) async fn get_without_credentials(
+        &self,
+        location: &str,
+    ) -> Result<(ResponseMeta, hyper::body::Bytes), TwilioError> {
+        let req = hyper::Request::get(location)
+            .body(Body::empty())
+            .map_err(|e| TwilioError::TransportError(e.into()))?;
+        match self.execute(req).await? {
+            (status, headers, bytes) if status.is_success() => {
+                Ok((ResponseMeta { status, headers }, bytes))
+            }
+            (status, headers, bytes) => Err(TwilioError::from_response(status, &headers, &bytes)),
+        }
+    }
+
+    /// The `Authorization` header for the next request, asking the
+    /// credentials provider, or first fetching a new OAuth access token if the
+    /// last one is about to expire.
+    async fn authorization(&self) -> Result<RequestAuth, TwilioError> {
+        let oauth = match *self.credentials {
+            Credentials::Basic(ref auth) => return Ok(auth.clone()),
+            Credentials::Provider(ref provider) => {
+                let credentials = provider.credentials().await?;
+                return RequestAuth::basic(&credentials)
+                    .map_err(|e| TwilioError::Credentials(e.into()));
             }
+            Credentials::OAuth(ref oauth) => oauth,
         };
+        if let Some(auth) = oauth.current() {
+            return Ok(auth);
+        }
+        let body = url_encode(&[
+            ("grant_type", "client_credentials"),
+            ("client_id", &oauth.client_id),
+            ("client_secret", &oauth.client_secret),
+        ]);
+        let req = hyper::Request::post(self.product_url(&endpoint::OAUTH, "token"))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(body))
+            .map_err(|e| TwilioError::TransportError(e.into()))?;
+        match self.execute(req).await? {
+            (status, _, bytes) if status.is_success() => {
+                let token = serde_json::from_slice(&bytes).map_err(TwilioError::ParsingError)?;
+                oauth
+                    .store(token)
+                    .map_err(|e| TwilioError::Credentials(e.into()))
+            }
+            (status, headers, bytes) => Err(TwilioError::from_response(status, &headers, &bytes)),
+        }
+    }
 
-        let decoded: T = hyper::body::to_bytes(resp.into_body())
-            .await
-            .map_err(TwilioError::NetworkError)
-            .and_then(|bytes| {
-                serde_json::from_slice(&bytes).map_err(|_| TwilioError::ParsingError)
-            })?;
+    fn build_request(
+        &self,
+        method: &hyper::Method,
+        uri: &hyper::Uri,
+        body: &Option<RequestBody>,
+        idempotency_token: Option<&str>,
+        authorization: RequestAuth,
+    ) -> Result<hyper::Request<Body>, TwilioError> {
+        let mut req = hyper::Request::new(match body {
+            Some(RequestBody::Form(b) | RequestBody::Json(b)) => Body::from(b.clone()),
+            Some(RequestBody::Multipart { data, .. }) => Body::from(data.clone()),
+            None => Body::empty(),
+        });
+        *req.method_mut() = method.clone();
+        *req.uri_mut() = uri.clone();
+
+        let headers = req.headers_mut();
+        headers.extend(
+            self.extra_headers
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone())),
+        );
+        match body {
+            Some(RequestBody::Form(_)) => {
+                let mime: mime::Mime = "application/x-www-form-urlencoded".parse().unwrap();
+                headers.typed_insert(ContentType::from(mime));
+            }
+            Some(RequestBody::Json(_)) => headers.typed_insert(ContentType::json()),
+            Some(RequestBody::Multipart { boundary, .. }) => {
+                let mime: mime::Mime = format!("multipart/form-data; boundary={boundary}")
+                    .parse()
+                    .unwrap();
+                headers.typed_insert(ContentType::from(mime));
+            }
+            None => {}
+        }
+        #[cfg(feature = "client-validation")]
+        if let Some(ref validation) = self.client_validation {
+            let body_bytes: &[u8] = match body {
+                Some(RequestBody::Form(b) | RequestBody::Json(b)) => b.as_bytes(),
+                Some(RequestBody::Multipart { data, .. }) => data,
+                None => &[],
+            };
+            let jwt = validation
+                .sign(
+                    &authorization.username,
+                    &self.account_id,
+                    method.as_str(),
+                    uri,
+                    authorization.header.to_str().unwrap_or_default(),
+                    body_bytes,
+                )
+                .map_err(|e| TwilioError::Credentials(e.into()))?;
+            if let Ok(value) = HeaderValue::from_str(&jwt) {
+                headers.insert("Twilio-Client-Validation", value);
+            }
+        }
+        headers.insert(AUTHORIZATION, authorization.header);
+        if let Some(token) = idempotency_token.and_then(|t| HeaderValue::from_str(t).ok()) {
+            headers.insert("I-Twilio-Idempotency-Token", token);
+        }
+        Ok(req)
+    }
 
-        Ok(decoded)
+    async fn execute(
+        &self,
+        req: hyper::Request<Body>,
+    ) -> Result<(StatusCode, hyper::HeaderMap, hyper::body::Bytes), TwilioError> {
+        let resp = self.http_client.send(req).await?;
+        let (parts, body) = resp.into_parts();
+        let bytes = hyper::body::to_bytes(body)
+            .await
+            .map_err(TwilioError::NetworkError)?;
+        Ok((parts.status, parts.headers, bytes))
     }
 
-    pub async fn respond_to_webhook<T: FromMap, F>(
+    pub async fn respond_to_webhook<T: FromMap, R, F>(
         &self,
         req: hyper::Request<Body>,
         mut logic: F,
     ) -> hyper::Response<Body>
     where
-        F: FnMut(T) -> twiml::Twiml,
+        R: Into<twiml::Twiml>,
+        F: FnMut(T) -> R,
     {
         let o: T = match self.parse_request::<T>(req).await {
             Ok(obj) => *obj,
@@ -164,7 +994,7 @@ impl Client {
             }
         };
 
-        let t = logic(o);
+        let t: twiml::Twiml = logic(o).into();
         let body = t.as_twiml();
         let len = body.len() as u64;
         let mut res = hyper::Response::new(Body::from(body));
@@ -172,4 +1002,23 @@ impl Client {
         res.headers_mut().typed_insert(headers::ContentLength(len));
         res
     }
+
+    /// Wraps `respond_to_webhook` in a function that can be handed straight to
+    /// `hyper::service::service_fn`, for servers that don't use a framework.
+    pub fn webhook_handler<T, R, F>(
+        &self,
+        logic: F,
+    ) -> impl Fn(hyper::Request<Body>) -> WebhookFuture + Clone
+    where
+        T: FromMap + Send + 'static,
+        R: Into<twiml::Twiml>,
+        F: Fn(T) -> R + Clone + Send + Sync + 'static,
+    {
+        let client = self.clone();
+        move |req| {
+            let client = client.clone();
+            let logic = logic.clone();
+            Box::pin(async move { Ok(client.respond_to_webhook(req, logic).await) })
+        }
+    }
 }
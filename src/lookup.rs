@@ -0,0 +1,106 @@
+//! The [Lookup v2](https://www.twilio.com/docs/lookup/v2-api) API, which
+//! validates phone numbers and optionally returns extra data packages.
+
+use crate::endpoint::{self, LOOKUPS};
+use crate::{Client, Timestamp, TwilioError, GET};
+use serde::Deserialize;
+
+/// Optional data packages. Most of these are billed per lookup.
+pub enum DataPackage {
+    LineTypeIntelligence,
+    CallerName,
+    SimSwap,
+    CallForwarding,
+    IdentityMatch,
+}
+
+impl DataPackage {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DataPackage::LineTypeIntelligence => "line_type_intelligence",
+            DataPackage::CallerName => "caller_name",
+            DataPackage::SimSwap => "sim_swap",
+            DataPackage::CallForwarding => "call_forwarding",
+            DataPackage::IdentityMatch => "identity_match",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CallerName {
+    pub caller_name: Option<String>,
+    pub caller_type: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LineTypeIntelligence {
+    pub carrier_name: Option<String>,
+    pub mobile_country_code: Option<String>,
+    pub mobile_network_code: Option<String>,
+    #[serde(rename = "type")]
+    pub line_type: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LastSimSwap {
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub last_sim_swapped_date: Option<Timestamp>,
+    pub swapped_period: Option<String>,
+    pub swapped_in_period: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimSwap {
+    pub last_sim_swap: Option<LastSimSwap>,
+    pub carrier_name: Option<String>,
+    pub mobile_country_code: Option<String>,
+    pub mobile_network_code: Option<String>,
+    pub error_code: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PhoneNumberLookup {
+    pub phone_number: String,
+    pub valid: bool,
+    #[serde(default)]
+    pub validation_errors: Vec<String>,
+    pub calling_country_code: Option<String>,
+    pub country_code: Option<String>,
+    pub national_format: Option<String>,
+    pub caller_name: Option<CallerName>,
+    pub line_type_intelligence: Option<LineTypeIntelligence>,
+    pub sim_swap: Option<SimSwap>,
+    pub call_forwarding: Option<serde_json::Value>,
+    pub identity_match: Option<serde_json::Value>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    /// Looks up `phone_number`, which may be in E.164 or national format. For a
+    /// national number, pass the ISO country it belongs to as `country_code`.
+    pub async fn lookup_phone_number(
+        &self,
+        phone_number: &str,
+        country_code: Option<&str>,
+        packages: &[DataPackage],
+    ) -> Result<PhoneNumberLookup, TwilioError> {
+        let fields = packages
+            .iter()
+            .map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut opts = Vec::new();
+        if !fields.is_empty() {
+            opts.push(("Fields", fields.as_str()));
+        }
+        if let Some(country) = country_code {
+            opts.push(("CountryCode", country));
+        }
+        let number = endpoint::path_segment(phone_number);
+
+        self.send_product_request(GET, &LOOKUPS, &format!("PhoneNumbers/{number}"), &opts)
+            .await
+    }
+}
@@ -1,22 +1,252 @@
-use crate::{Client, FromMap, TwilioError, POST};
+use crate::endpoint::API;
+use crate::phone::{self, E164};
+use crate::timer;
+use crate::{
+    from_params, is_twilio_url, AccountSid, Client, ContentSid, FromMap, MediaSid, MessageSid,
+    MessagingServiceSid, Money, PageIterator, Timestamp, TwilioError, WithMeta, GET, POST,
+};
+use hyper::header::CONTENT_TYPE;
 use serde::Deserialize;
 use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
 
+/// The sender or recipient of a message. Plain strings convert with `parse`
+/// rules, so `"whatsapp:+15551234567"` becomes `Whatsapp("+15551234567")`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MessageAddress<'a> {
+    /// A phone number or short code, sent over SMS or MMS.
+    Phone(&'a str),
+    /// A WhatsApp user or sender, by phone number. Formats as `whatsapp:<number>`.
+    Whatsapp(&'a str),
+}
+
+impl<'a> MessageAddress<'a> {
+    pub fn parse(address: &'a str) -> MessageAddress<'a> {
+        match address.strip_prefix("whatsapp:") {
+            Some(number) => MessageAddress::Whatsapp(number),
+            None => MessageAddress::Phone(address),
+        }
+    }
+
+    /// The address without any channel prefix.
+    pub fn number(&self) -> &'a str {
+        match *self {
+            MessageAddress::Phone(n) => n,
+            MessageAddress::Whatsapp(n) => n.strip_prefix("whatsapp:").unwrap_or(n),
+        }
+    }
+
+    pub fn is_whatsapp(&self) -> bool {
+        matches!(self, MessageAddress::Whatsapp(_))
+    }
+
+    /// What kind of sender this is, or `None` if it isn't a valid one. A
+    /// WhatsApp sender must be an E.164 number.
+    pub fn sender_kind(&self) -> Option<SenderKind> {
+        let kind = SenderKind::of(self.number())?;
+        match self {
+            MessageAddress::Whatsapp(_) if kind != SenderKind::PhoneNumber => None,
+            _ => Some(kind),
+        }
+    }
+
+    /// The address to send, with the number in E.164 form. A `sender` may
+    /// also be a short code or an alphanumeric sender ID, and is empty when
+    /// a Messaging Service picks it.
+    fn normalize(&self, sender: bool) -> Result<String, TwilioError> {
+        match *self {
+            MessageAddress::Whatsapp(_) => E164::new(self.number())
+                .map(|n| format!("whatsapp:{n}"))
+                .map_err(TwilioError::InvalidNumber),
+            MessageAddress::Phone("") if sender => Ok(String::new()),
+            MessageAddress::Phone(n)
+                if sender
+                    && matches!(
+                        SenderKind::of(n),
+                        Some(SenderKind::ShortCode | SenderKind::Alphanumeric)
+                    ) =>
+            {
+                Ok(n.to_string())
+            }
+            MessageAddress::Phone(n) => phone::normalize_address(n),
+        }
+    }
+}
+
+/// The forms a message's `From` can take.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SenderKind {
+    /// An E.164 number such as `+15551234567`.
+    PhoneNumber,
+    /// A 3 to 8 digit short code such as `894546`.
+    ShortCode,
+    /// An alphanumeric sender ID such as `ACME Corp`: up to 11 letters,
+    /// digits and spaces, with at least one letter. Recipients can't reply,
+    /// and not every country supports them.
+    Alphanumeric,
+}
+
+impl SenderKind {
+    pub fn of(sender: &str) -> Option<SenderKind> {
+        let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if sender.starts_with('+') {
+            return phone::is_e164(sender).then_some(SenderKind::PhoneNumber);
+        }
+        if all_digits(sender) {
+            return (3..=8)
+                .contains(&sender.len())
+                .then_some(SenderKind::ShortCode);
+        }
+        let alphanumeric = sender.len() <= 11
+            && sender
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b' ')
+            && sender.bytes().any(|b| b.is_ascii_alphabetic());
+        alphanumeric.then_some(SenderKind::Alphanumeric)
+    }
+}
+
+impl<'a> From<&'a str> for MessageAddress<'a> {
+    fn from(address: &'a str) -> MessageAddress<'a> {
+        MessageAddress::parse(address)
+    }
+}
+
+impl<'a> From<&'a String> for MessageAddress<'a> {
+    fn from(address: &'a String) -> MessageAddress<'a> {
+        MessageAddress::parse(address)
+    }
+}
+
+impl<'a> From<&'a E164> for MessageAddress<'a> {
+    fn from(number: &'a E164) -> MessageAddress<'a> {
+        MessageAddress::Phone(number.as_str())
+    }
+}
+
+impl fmt::Display for MessageAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageAddress::Phone(n) => f.write_str(n),
+            MessageAddress::Whatsapp(_) => write!(f, "whatsapp:{}", self.number()),
+        }
+    }
+}
+
+/// A message to send. Either `from` or `messaging_service_sid` must be set;
+/// when both are, Twilio sends from `from`, which may be a phone number, a
+/// short code or an alphanumeric sender ID (see `SenderKind`).
 pub struct OutboundMessage<'a> {
-    pub from: &'a str,
-    pub to: &'a str,
+    pub from: MessageAddress<'a>,
+    pub to: MessageAddress<'a>,
     pub body: &'a str,
+    pub media_urls: Vec<&'a str>,
+    pub messaging_service_sid: Option<&'a MessagingServiceSid>,
+    /// A Content API template to send instead of `body`. WhatsApp requires
+    /// one to start a conversation outside the 24 hour session window.
+    pub content_sid: Option<&'a ContentSid>,
+    /// Values for the template's placeholders, keyed by placeholder name,
+    /// e.g. `("1", "Alice")` for `{{1}}`.
+    pub content_variables: Vec<(&'a str, &'a str)>,
+    /// When to send, as an ISO 8601 timestamp between 15 minutes and 35 days
+    /// away. Scheduling requires `messaging_service_sid`.
+    pub send_at: Option<&'a str>,
+    /// A unique key for this send. If the request has to be repeated, Twilio
+    /// returns the message it already created instead of sending another.
+    pub idempotency_token: Option<&'a str>,
+    /// Asks Twilio to track whether the message reached the user, to be
+    /// reported later with `Client::create_message_feedback`.
+    pub provide_feedback: bool,
 }
 
 impl<'a> OutboundMessage<'a> {
-    pub fn new(from: &'a str, to: &'a str, body: &'a str) -> OutboundMessage<'a> {
-        OutboundMessage { from, to, body }
+    pub fn new(
+        from: impl Into<MessageAddress<'a>>,
+        to: impl Into<MessageAddress<'a>>,
+        body: &'a str,
+    ) -> OutboundMessage<'a> {
+        OutboundMessage {
+            from: from.into(),
+            to: to.into(),
+            body,
+            media_urls: Vec::new(),
+            messaging_service_sid: None,
+            content_sid: None,
+            content_variables: Vec::new(),
+            send_at: None,
+            idempotency_token: None,
+            provide_feedback: false,
+        }
+    }
+
+    pub fn new_with_media(
+        from: impl Into<MessageAddress<'a>>,
+        to: impl Into<MessageAddress<'a>>,
+        body: &'a str,
+        media_urls: &[&'a str],
+    ) -> OutboundMessage<'a> {
+        let mut msg = OutboundMessage::new(from, to, body);
+        msg.media_urls = media_urls.to_vec();
+        msg
+    }
+
+    /// Sends through a Messaging Service, which picks a sender from its pool.
+    pub fn new_with_service(
+        service_sid: &'a MessagingServiceSid,
+        to: impl Into<MessageAddress<'a>>,
+        body: &'a str,
+    ) -> OutboundMessage<'a> {
+        let mut msg = OutboundMessage::new("", to, body);
+        msg.messaging_service_sid = Some(service_sid);
+        msg
+    }
+
+    /// Sends a Content API template, filled in with `add_content_variable`.
+    pub fn new_with_content(
+        from: impl Into<MessageAddress<'a>>,
+        to: impl Into<MessageAddress<'a>>,
+        content_sid: &'a ContentSid,
+    ) -> OutboundMessage<'a> {
+        let mut msg = OutboundMessage::new(from, to, "");
+        msg.content_sid = Some(content_sid);
+        msg
+    }
+
+    pub fn add_media(&mut self, url: &'a str) -> &mut OutboundMessage<'a> {
+        self.media_urls.push(url);
+        self
+    }
+
+    /// Schedules the message instead of sending it now. It stays `scheduled`
+    /// until then, and can be called off with `MessageUpdate::cancel`.
+    pub fn schedule(&mut self, send_at: &'a str) -> &mut OutboundMessage<'a> {
+        self.send_at = Some(send_at);
+        self
+    }
+
+    /// Makes the send safe to repeat, including by the client's own retries.
+    pub fn idempotency_token(&mut self, token: &'a str) -> &mut OutboundMessage<'a> {
+        self.idempotency_token = Some(token);
+        self
+    }
+
+    pub fn add_content_variable(
+        &mut self,
+        name: &'a str,
+        value: &'a str,
+    ) -> &mut OutboundMessage<'a> {
+        self.content_variables.push((name, value));
+        self
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[allow(non_camel_case_types)]
 pub enum MessageStatus {
+    accepted,
+    scheduled,
+    canceled,
     queued,
     sending,
     sent,
@@ -25,45 +255,482 @@ pub enum MessageStatus {
     undelivered,
     receiving,
     received,
+    read,
+    partially_delivered,
+}
+
+/// Controls how `Client::send_messages` spreads a batch out over time.
+#[derive(Clone, Debug)]
+pub struct BulkOptions {
+    /// How many sends may be waiting on Twilio at once.
+    pub concurrency: usize,
+    /// The most sends to start in any one second. Twilio queues messages
+    /// beyond a sender's throughput and fails them after four hours, so keep
+    /// this near the account's rate. `None` only limits `concurrency`.
+    pub per_second: Option<u32>,
+}
+
+impl Default for BulkOptions {
+    fn default() -> BulkOptions {
+        BulkOptions {
+            concurrency: 10,
+            per_second: Some(10),
+        }
+    }
+}
+
+/// The only status a message can be moved to with `Client::update_message`.
+/// Only messages that are still `scheduled` can be canceled.
+pub enum MessageUpdateStatus {
+    Canceled,
+}
+
+#[derive(Default)]
+pub struct MessageUpdate<'a> {
+    /// Replaces the message body. Twilio only accepts `""`, which redacts a
+    /// message that has already been sent.
+    pub body: Option<&'a str>,
+    pub status: Option<MessageUpdateStatus>,
+}
+
+impl<'a> MessageUpdate<'a> {
+    pub fn cancel() -> MessageUpdate<'a> {
+        MessageUpdate {
+            status: Some(MessageUpdateStatus::Canceled),
+            ..Default::default()
+        }
+    }
+
+    pub fn redact() -> MessageUpdate<'a> {
+        MessageUpdate {
+            body: Some(""),
+            ..Default::default()
+        }
+    }
 }
 
+/// Filters accepted by `Client::list_messages`. Dates are passed through to
+/// Twilio as-is, e.g. `"2023-01-31"` or `"2023-01-31T12:00:00Z"`, and both
+/// bounds are inclusive.
+#[derive(Default)]
+pub struct MessageFilter<'a> {
+    pub from: Option<&'a str>,
+    pub to: Option<&'a str>,
+    /// Only messages sent on this day.
+    pub date_sent: Option<&'a str>,
+    pub date_sent_after: Option<&'a str>,
+    pub date_sent_before: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedbackOutcome {
+    Confirmed,
+    Unconfirmed,
+}
+
+impl FeedbackOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            FeedbackOutcome::Confirmed => "confirmed",
+            FeedbackOutcome::Unconfirmed => "unconfirmed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessageFeedback {
+    pub account_sid: AccountSid,
+    pub message_sid: MessageSid,
+    pub outcome: FeedbackOutcome,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// An image, video or other file attached to an MMS message.
+#[derive(Debug, Deserialize)]
+pub struct Media {
+    pub sid: MediaSid,
+    pub account_sid: AccountSid,
+    /// The message the media belongs to.
+    pub parent_sid: MessageSid,
+    pub content_type: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Media downloaded with `Client::download_media`.
+#[derive(Debug)]
+pub struct MediaContent {
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// A message, as returned by the REST API or posted to a messaging webhook.
+/// Webhook parameters are accepted under their PascalCase names; anything
+/// without a dedicated field is kept in `extra`.
 #[derive(Debug, Deserialize)]
 pub struct Message {
-    pub from: String,
+    /// The sender. Null on a message sent through a messaging service until
+    /// Twilio has picked a number from its sender pool.
+    #[serde(alias = "From")]
+    pub from: Option<String>,
+    #[serde(alias = "To")]
     pub to: String,
+    #[serde(alias = "Body")]
     pub body: Option<String>,
-    pub sid: String,
+    #[serde(alias = "MessageSid")]
+    pub sid: MessageSid,
+    #[serde(alias = "SmsStatus")]
     pub status: Option<MessageStatus>,
+    #[serde(alias = "NumMedia")]
+    pub num_media: Option<String>,
+    /// Where to download the attached media, e.g. with
+    /// `Client::download_media_url`. Filled in from a webhook's `MediaUrl0`,
+    /// `MediaUrl1`, ... parameters and by `Client::retrieve_message`; empty
+    /// on messages from
+    /// `send_message` and `list_messages`, whose media can be listed with
+    /// `Client::list_message_media`.
+    #[serde(default)]
+    pub media_urls: Vec<String>,
+    #[serde(alias = "AccountSid")]
+    pub account_sid: Option<AccountSid>,
+    #[serde(alias = "MessagingServiceSid")]
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    #[serde(alias = "NumSegments")]
+    pub num_segments: Option<String>,
+    #[serde(alias = "ApiVersion")]
+    pub api_version: Option<String>,
+    #[serde(alias = "FromCity")]
+    pub from_city: Option<String>,
+    #[serde(alias = "FromState")]
+    pub from_state: Option<String>,
+    #[serde(alias = "FromZip")]
+    pub from_zip: Option<String>,
+    #[serde(alias = "FromCountry")]
+    pub from_country: Option<String>,
+    #[serde(alias = "ToCity")]
+    pub to_city: Option<String>,
+    #[serde(alias = "ToState")]
+    pub to_state: Option<String>,
+    #[serde(alias = "ToZip")]
+    pub to_zip: Option<String>,
+    #[serde(alias = "ToCountry")]
+    pub to_country: Option<String>,
+    /// The sender's WhatsApp profile name, on inbound WhatsApp messages.
+    #[serde(alias = "ProfileName")]
+    pub profile_name: Option<String>,
+    /// The sender's WhatsApp ID, usually their number without a `+`.
+    #[serde(alias = "WaId")]
+    pub wa_id: Option<String>,
+    /// `text`, `button`, `interactive`, `location` and so on, on inbound
+    /// WhatsApp messages.
+    #[serde(alias = "MessageType")]
+    pub message_type: Option<String>,
+    /// The label of the quick reply button the user tapped.
+    #[serde(alias = "ButtonText")]
+    pub button_text: Option<String>,
+    /// The id or payload of the quick reply button the user tapped.
+    #[serde(alias = "ButtonPayload")]
+    pub button_payload: Option<String>,
+    /// The charge for the message, e.g. `-0.00790` USD, set once it has
+    /// been sent.
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// A delivery status callback, posted to a message's `StatusCallback` each
+/// time its status changes. `error_code` is set once a message is `failed`
+/// or `undelivered`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MessageStatusEvent {
+    pub message_sid: MessageSid,
+    /// The same sid as `message_sid`, sent for backwards compatibility.
+    pub sms_sid: Option<MessageSid>,
+    pub message_status: MessageStatus,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub error_code: Option<u32>,
+    pub account_sid: Option<AccountSid>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    pub api_version: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for MessageStatusEvent {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<MessageStatusEvent>, TwilioError> {
+        from_params(m)
+    }
 }
 
 impl Client {
     pub async fn send_message(&self, msg: OutboundMessage<'_>) -> Result<Message, TwilioError> {
-        let opts = [("To", &*msg.to), ("From", &*msg.from), ("Body", &*msg.body)];
-        self.send_request(POST, "Messages", &opts).await
+        Ok(self.send_message_with_meta(msg).await?.value)
     }
-}
 
-impl FromMap for Message {
-    fn from_map(mut m: BTreeMap<String, String>) -> Result<Box<Message>, TwilioError> {
-        let from = match m.remove("From") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
-        };
-        let to = match m.remove("To") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
+    /// Like `send_message`, also returning the response's request ID and
+    /// concurrency headers.
+    pub async fn send_message_with_meta(
+        &self,
+        msg: OutboundMessage<'_>,
+    ) -> Result<WithMeta<Message>, TwilioError> {
+        let to = msg.to.normalize(false)?;
+        let from = msg.from.normalize(true)?;
+        let content_variables = if msg.content_variables.is_empty() {
+            None
+        } else {
+            let vars: serde_json::Map<_, _> = msg
+                .content_variables
+                .iter()
+                .map(|(k, v)| (k.to_string(), serde_json::Value::from(*v)))
+                .collect();
+            Some(serde_json::Value::Object(vars).to_string())
         };
-        let sid = match m.remove("MessageSid") {
-            Some(v) => v,
-            None => return Err(TwilioError::ParsingError),
+
+        let mut opts = vec![("To", to.as_str())];
+        if !from.is_empty() {
+            opts.push(("From", &from));
+        }
+        if let Some(sid) = msg.messaging_service_sid {
+            opts.push(("MessagingServiceSid", sid.as_str()));
+        }
+        if let Some(sid) = msg.content_sid {
+            opts.push(("ContentSid", sid.as_str()));
+        }
+        if let Some(ref vars) = content_variables {
+            opts.push(("ContentVariables", vars));
+        }
+        if !msg.body.is_empty() || (msg.media_urls.is_empty() && msg.content_sid.is_none()) {
+            opts.push(("Body", msg.body));
+        }
+        for url in &msg.media_urls {
+            opts.push(("MediaUrl", url));
+        }
+        if msg.provide_feedback {
+            opts.push(("ProvideFeedback", "true"));
+        }
+        if let Some(send_at) = msg.send_at {
+            opts.push(("SendAt", send_at));
+            opts.push(("ScheduleType", "fixed"));
+        }
+        self.send_create_request("Messages", &opts, msg.idempotency_token)
+            .await
+    }
+
+    /// Sends each message in `messages`, returning one result per message in
+    /// the same order. A failed send doesn't stop the rest of the batch.
+    pub async fn send_messages<'a, I>(
+        &self,
+        messages: I,
+        options: &BulkOptions,
+    ) -> Vec<Result<Message, TwilioError>>
+    where
+        I: IntoIterator<Item = OutboundMessage<'a>>,
+    {
+        use futures_util::stream::{self, StreamExt};
+
+        let interval = options
+            .per_second
+            .filter(|r| *r > 0)
+            .map(|r| Duration::from_secs(1) / r);
+        stream::iter(messages.into_iter().enumerate())
+            .then(|(i, msg)| async move {
+                match interval {
+                    Some(interval) if i > 0 => timer::sleep(interval).await,
+                    _ => {}
+                }
+                msg
+            })
+            .map(|msg| self.send_message(msg))
+            .buffered(options.concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Retrieves a message. If it has media, the media list is fetched too,
+    /// to fill in `media_urls`.
+    pub async fn retrieve_message(&self, sid: &MessageSid) -> Result<Message, TwilioError> {
+        let mut msg: Message = self
+            .send_request(GET, &format!("Messages/{sid}"), &[])
+            .await?;
+        let num_media = msg
+            .num_media
+            .as_deref()
+            .and_then(|n| n.parse::<usize>().ok());
+        if num_media.unwrap_or(0) > 0 {
+            let mut media = self.list_message_media(sid);
+            while let Some(page) = media.next_page().await? {
+                msg.media_urls
+                    .extend(page.items.iter().map(|m| self.media_url(sid, &m.sid)));
+            }
+        }
+        Ok(msg)
+    }
+
+    /// Lists messages sent and received by the account, newest first.
+    pub fn list_messages(&self, filter: &MessageFilter<'_>) -> PageIterator<Message> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(from) = filter.from {
+            opts.push(("From", from));
+        }
+        if let Some(to) = filter.to {
+            opts.push(("To", to));
+        }
+        if let Some(date) = filter.date_sent {
+            opts.push(("DateSent", date));
+        }
+        if let Some(after) = filter.date_sent_after {
+            opts.push(("DateSent>", after));
+        }
+        if let Some(before) = filter.date_sent_before {
+            opts.push(("DateSent<", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("Messages", "messages", &opts)
+    }
+
+    /// Reports whether a message sent with `provide_feedback` reached the
+    /// user, such as when they enter the code it contained. Twilio uses this
+    /// to measure and improve delivery on each route.
+    pub async fn create_message_feedback(
+        &self,
+        sid: &MessageSid,
+        outcome: FeedbackOutcome,
+    ) -> Result<MessageFeedback, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("Messages/{sid}/Feedback"),
+            &[("Outcome", outcome.as_str())],
+        )
+        .await
+    }
+
+    /// Clears the body of a message that has been sent or received, leaving
+    /// the rest of the record in place.
+    pub async fn redact_message(&self, sid: &MessageSid) -> Result<Message, TwilioError> {
+        self.update_message(sid, MessageUpdate::redact()).await
+    }
+
+    /// Deletes the message record and any media attached to it.
+    pub async fn delete_message(&self, sid: &MessageSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Messages/{sid}")).await
+    }
+
+    pub fn list_message_media(&self, message_sid: &MessageSid) -> PageIterator<Media> {
+        self.list(&format!("Messages/{message_sid}/Media"), "media_list", &[])
+    }
+
+    pub async fn retrieve_media(
+        &self,
+        message_sid: &MessageSid,
+        sid: &MediaSid,
+    ) -> Result<Media, TwilioError> {
+        self.send_request(GET, &format!("Messages/{message_sid}/Media/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_media(
+        &self,
+        message_sid: &MessageSid,
+        sid: &MediaSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Messages/{message_sid}/Media/{sid}"))
+            .await
+    }
+
+    /// Downloads the file itself using the client's credentials, which
+    /// Twilio requires when HTTP authentication for media is enabled.
+    pub async fn download_media(
+        &self,
+        message_sid: &MessageSid,
+        sid: &MediaSid,
+    ) -> Result<MediaContent, TwilioError> {
+        self.download_media_url(&self.media_url(message_sid, sid))
+            .await
+    }
+
+    /// Where a media file can be downloaded, in the form Twilio sends as
+    /// `MediaUrl0` on webhooks.
+    fn media_url(&self, message_sid: &MessageSid, sid: &MediaSid) -> String {
+        let path = format!(
+            "Accounts/{}/Messages/{message_sid}/Media/{sid}",
+            self.account_id
+        );
+        self.product_url(&API, &path)
+    }
+
+    /// Like `download_media`, for a media URL taken from an incoming
+    /// message's `media_urls`. The client's credentials are only sent to
+    /// https URLs on Twilio's hosts; other URLs are fetched without them.
+    pub async fn download_media_url(&self, url: &str) -> Result<MediaContent, TwilioError> {
+        let uri: hyper::Uri = url
+            .parse()
+            .map_err(|e| TwilioError::InvalidUrl(Box::new(e)))?;
+        let (meta, bytes) = if is_twilio_url(&uri) {
+            self.send_body(GET, url.to_string(), None, None).await?
+        } else {
+            self.get_without_credentials(url).await?
         };
-        let body = m.remove("Body");
-        Ok(Box::new(Message {
-            from,
-            to,
-            sid,
-            body,
-            status: None,
-        }))
+        Ok(MediaContent {
+            content_type: meta
+                .headers
+                .get(CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string()),
+            data: bytes.to_vec(),
+        })
+    }
+
+    pub async fn update_message(
+        &self,
+        sid: &MessageSid,
+        update: MessageUpdate<'_>,
+    ) -> Result<Message, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(body) = update.body {
+            opts.push(("Body", body));
+        }
+        if let Some(ref status) = update.status {
+            let status_str = match status {
+                MessageUpdateStatus::Canceled => "canceled",
+            };
+            opts.push(("Status", status_str));
+        }
+        self.send_request(POST, &format!("Messages/{sid}"), &opts)
+            .await
+    }
+}
+
+impl FromMap for Message {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<Message>, TwilioError> {
+        let mut msg: Box<Message> = from_params(m)?;
+        let count = msg
+            .num_media
+            .as_ref()
+            .and_then(|n| n.parse::<usize>().ok())
+            .unwrap_or(0);
+        for i in 0..count {
+            if let Some(serde_json::Value::String(url)) = msg.extra.remove(&format!("MediaUrl{i}"))
+            {
+                msg.media_urls.push(url);
+            }
+        }
+        Ok(msg)
     }
 }
@@ -0,0 +1,173 @@
+//! [Messaging Services](https://www.twilio.com/docs/messaging/api/service-resource),
+//! which group senders so messages can be sent from a pool instead of a
+//! single `From` number. See `OutboundMessage::new_with_service`.
+
+use crate::endpoint::MESSAGING;
+use crate::twiml::Method;
+use crate::{
+    bool_str, AccountSid, Client, MessagingServiceSid, PageIterator, PhoneNumberSid, Timestamp,
+    TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: MessagingServiceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    pub inbound_request_url: Option<String>,
+    pub inbound_method: Option<String>,
+    pub fallback_url: Option<String>,
+    pub status_callback: Option<String>,
+    pub sticky_sender: Option<bool>,
+    pub use_inbound_webhook_on_number: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Settings applied when creating or updating a service. Fields left as
+/// `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct ServiceConfig<'a> {
+    pub inbound_request_url: Option<&'a str>,
+    pub inbound_method: Option<Method>,
+    pub fallback_url: Option<&'a str>,
+    pub status_callback: Option<&'a str>,
+    pub sticky_sender: Option<bool>,
+    pub use_inbound_webhook_on_number: Option<bool>,
+}
+
+impl<'a> ServiceConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let mut opts = Vec::new();
+        if let Some(url) = self.inbound_request_url {
+            opts.push(("InboundRequestUrl", url));
+        }
+        if let Some(ref method) = self.inbound_method {
+            let method_str = match method {
+                Method::Get => "GET",
+                Method::Post => "POST",
+            };
+            opts.push(("InboundMethod", method_str));
+        }
+        if let Some(url) = self.fallback_url {
+            opts.push(("FallbackUrl", url));
+        }
+        if let Some(url) = self.status_callback {
+            opts.push(("StatusCallback", url));
+        }
+        if let Some(sticky) = self.sticky_sender {
+            opts.push(("StickySender", bool_str(sticky)));
+        }
+        if let Some(inbound) = self.use_inbound_webhook_on_number {
+            opts.push(("UseInboundWebhookOnNumber", bool_str(inbound)));
+        }
+        opts
+    }
+}
+
+/// A phone number in a service's sender pool.
+#[derive(Debug, Deserialize)]
+pub struct ServicePhoneNumber {
+    pub sid: PhoneNumberSid,
+    pub service_sid: MessagingServiceSid,
+    pub account_sid: AccountSid,
+    pub phone_number: String,
+    pub country_code: Option<String>,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    pub async fn create_messaging_service(
+        &self,
+        friendly_name: &str,
+        config: ServiceConfig<'_>,
+    ) -> Result<Service, TwilioError> {
+        let mut opts = vec![("FriendlyName", friendly_name)];
+        opts.extend(config.params());
+        self.send_product_request(POST, &MESSAGING, "Services", &opts)
+            .await
+    }
+
+    pub fn list_messaging_services(&self) -> PageIterator<Service> {
+        self.list_product(&MESSAGING, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_messaging_service(
+        &self,
+        sid: &MessagingServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &MESSAGING, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_messaging_service(
+        &self,
+        sid: &MessagingServiceSid,
+        friendly_name: Option<&str>,
+        config: ServiceConfig<'_>,
+    ) -> Result<Service, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        opts.extend(config.params());
+        self.send_product_request(POST, &MESSAGING, &format!("Services/{sid}"), &opts)
+            .await
+    }
+
+    pub async fn delete_messaging_service(
+        &self,
+        sid: &MessagingServiceSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&MESSAGING, &format!("Services/{sid}"))
+            .await
+    }
+
+    pub fn list_service_phone_numbers(
+        &self,
+        service_sid: &MessagingServiceSid,
+    ) -> PageIterator<ServicePhoneNumber> {
+        self.list_product(
+            &MESSAGING,
+            &format!("Services/{service_sid}/PhoneNumbers"),
+            "phone_numbers",
+            &[],
+        )
+    }
+
+    /// Adds one of the account's incoming phone numbers (by its `PN` SID) to
+    /// the service's sender pool.
+    pub async fn add_service_phone_number(
+        &self,
+        service_sid: &MessagingServiceSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<ServicePhoneNumber, TwilioError> {
+        self.send_product_request(
+            POST,
+            &MESSAGING,
+            &format!("Services/{service_sid}/PhoneNumbers"),
+            &[("PhoneNumberSid", phone_number_sid.as_str())],
+        )
+        .await
+    }
+
+    pub async fn remove_service_phone_number(
+        &self,
+        service_sid: &MessagingServiceSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &MESSAGING,
+            &format!("Services/{service_sid}/PhoneNumbers/{phone_number_sid}"),
+        )
+        .await
+    }
+}
@@ -0,0 +1,66 @@
+//! Prices on resources. Twilio sends a charge as a decimal string, e.g.
+//! `"price": "-0.00750"`, with its currency in a separate `price_unit`. With
+//! the `rust_decimal` feature the amount is parsed into a
+//! `rust_decimal::Decimal`; otherwise it is kept as the string Twilio sent.
+//! Neither goes through a float, so no rounding creeps into billing data.
+
+use serde::Deserialize;
+
+/// The amount of a `Money`: `rust_decimal::Decimal` with the `rust_decimal`
+/// feature, otherwise the string Twilio sent.
+#[cfg(feature = "rust_decimal")]
+pub type Decimal = rust_decimal::Decimal;
+#[cfg(not(feature = "rust_decimal"))]
+pub type Decimal = String;
+
+/// A charge, such as a call's or message's `price`. Charges to the account
+/// are negative.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Money {
+    pub amount: Decimal,
+    /// The ISO 4217 code Twilio sent as `price_unit`, e.g. `USD`. Usage
+    /// records send it in lower case.
+    pub currency: String,
+}
+
+/// Deserializes a flattened `price` and `price_unit` pair. A missing, null
+/// or empty `price`, as on a message that hasn't been sent yet, is `None`.
+pub(crate) fn option<'de, D>(d: D) -> Result<Option<Money>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde_json::Value;
+
+    #[derive(Deserialize)]
+    struct Price {
+        #[serde(default)]
+        price: Option<Value>,
+        #[serde(default)]
+        price_unit: Option<String>,
+    }
+
+    let p = Price::deserialize(d)?;
+    let amount = match p.price {
+        Some(Value::String(s)) if !s.trim().is_empty() => s,
+        Some(Value::Number(n)) => n.to_string(),
+        None | Some(Value::Null) | Some(Value::String(_)) => return Ok(None),
+        Some(other) => return Err(D::Error::custom(format!("invalid price {other}"))),
+    };
+    Ok(Some(Money {
+        amount: parse(amount.trim())?,
+        currency: p.price_unit.unwrap_or_default(),
+    }))
+}
+
+#[cfg(feature = "rust_decimal")]
+fn parse<E: serde::de::Error>(s: &str) -> Result<Decimal, E> {
+    s.parse()
+        .or_else(|_| Decimal::from_scientific(s))
+        .map_err(|_| E::custom(format!("invalid price {s:?}")))
+}
+
+#[cfg(not(feature = "rust_decimal"))]
+fn parse<E: serde::de::Error>(s: &str) -> Result<Decimal, E> {
+    Ok(s.to_string())
+}
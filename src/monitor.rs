@@ -0,0 +1,261 @@
+//! [Monitor](https://www.twilio.com/docs/usage/monitor-alert) debugger alerts
+//! and audit events, for pulling failed webhook requests and account changes
+//! into your own tooling. `DebuggerEvent` parses the alerts Twilio posts to a
+//! Debugger webhook as they happen.
+
+use crate::endpoint::MONITOR;
+use crate::{
+    from_params, AccountSid, AlertSid, Client, FromMap, MonitorEventSid, PageIterator, Timestamp,
+    TwilioError, GET,
+};
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warning,
+    Notice,
+    Debug,
+}
+
+impl LogLevel {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            LogLevel::Error => "error",
+            LogLevel::Warning => "warning",
+            LogLevel::Notice => "notice",
+            LogLevel::Debug => "debug",
+        }
+    }
+}
+
+/// A problem Twilio hit while handling your account's traffic, such as a
+/// webhook that timed out or returned invalid TwiML. The request and
+/// response fields are only filled in by `Client::retrieve_alert`.
+#[derive(Debug, Deserialize)]
+pub struct Alert {
+    pub sid: AlertSid,
+    pub account_sid: AccountSid,
+    /// Twilio's error code, e.g. `"11200"` for an HTTP retrieval failure.
+    pub error_code: Option<String>,
+    pub log_level: Option<LogLevel>,
+    /// The alert's details, URL-encoded like a form body.
+    pub alert_text: Option<String>,
+    pub more_info: Option<String>,
+    /// The SID of the call, message or other resource that triggered it.
+    pub resource_sid: Option<String>,
+    pub service_sid: Option<String>,
+    pub request_method: Option<String>,
+    pub request_url: Option<String>,
+    pub request_variables: Option<String>,
+    pub request_headers: Option<String>,
+    pub response_headers: Option<String>,
+    pub response_body: Option<String>,
+    pub api_version: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_generated: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Filters accepted by `Client::list_alerts`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`. Alerts are kept for 30 days.
+#[derive(Default)]
+pub struct AlertFilter<'a> {
+    pub log_level: Option<LogLevel>,
+    pub start_date: Option<&'a str>,
+    pub end_date: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// A change made to the account, by a user in the Console or through the
+/// API, such as a phone number being reconfigured.
+#[derive(Debug, Deserialize)]
+pub struct Event {
+    pub sid: MonitorEventSid,
+    pub account_sid: AccountSid,
+    /// E.g. `"phone-number.updated"`.
+    pub event_type: String,
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub event_date: Option<Timestamp>,
+    /// The SID of the user or API key that made the change.
+    pub actor_sid: Option<String>,
+    pub actor_type: Option<String>,
+    pub resource_sid: Option<String>,
+    pub resource_type: Option<String>,
+    /// `"web"` for the Console or `"api"`.
+    pub source: Option<String>,
+    pub source_ip_address: Option<String>,
+    #[serde(default)]
+    pub event_data: BTreeMap<String, serde_json::Value>,
+    pub url: Option<String>,
+}
+
+/// Filters accepted by `Client::list_events`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`.
+#[derive(Default)]
+pub struct EventFilter<'a> {
+    pub actor_sid: Option<&'a str>,
+    pub event_type: Option<&'a str>,
+    pub resource_sid: Option<&'a str>,
+    pub source_ip_address: Option<&'a str>,
+    pub start_date: Option<&'a str>,
+    pub end_date: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// Posted to the Debugger webhook configured in the Console each time an
+/// alert is raised.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DebuggerEvent {
+    /// The alert's SID, which `Client::retrieve_alert` accepts.
+    pub sid: AlertSid,
+    pub account_sid: AccountSid,
+    /// Set when the alert was raised on a subaccount.
+    pub parent_account_sid: Option<AccountSid>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub timestamp: Option<Timestamp>,
+    /// `ERROR` or `WARNING`.
+    pub level: Option<String>,
+    pub payload_type: Option<String>,
+    #[serde(deserialize_with = "payload_from_str")]
+    pub payload: DebuggerPayload,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl DebuggerEvent {
+    pub fn error_code(&self) -> Option<u32> {
+        self.payload.error_code
+    }
+
+    /// The URL of the webhook request that failed, if the alert was about one.
+    pub fn request_url(&self) -> Option<&str> {
+        let request = self.payload.webhook.as_ref()?.request.as_ref()?;
+        request.url.as_deref()
+    }
+
+    /// What your server sent back to the failed webhook request.
+    pub fn response_body(&self) -> Option<&str> {
+        let response = self.payload.webhook.as_ref()?.response.as_ref()?;
+        response.body.as_deref()
+    }
+}
+
+impl FromMap for DebuggerEvent {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<DebuggerEvent>, TwilioError> {
+        from_params(m)
+    }
+}
+
+/// The JSON document sent in a `DebuggerEvent`'s `Payload` parameter.
+#[derive(Debug, Deserialize)]
+pub struct DebuggerPayload {
+    /// The SID of the call, message or other resource that triggered it.
+    pub resource_sid: Option<String>,
+    pub service_sid: Option<String>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub error_code: Option<u32>,
+    /// Error-specific details, such as `msg` and `sourceComponent`.
+    #[serde(default)]
+    pub more_info: BTreeMap<String, serde_json::Value>,
+    /// Present when the alert was raised by a webhook request to your app.
+    pub webhook: Option<DebuggerWebhook>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DebuggerWebhook {
+    pub request: Option<WebhookRequest>,
+    pub response: Option<WebhookResponse>,
+}
+
+/// The request Twilio made to your app.
+#[derive(Debug, Deserialize)]
+pub struct WebhookRequest {
+    pub url: Option<String>,
+    pub method: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub parameters: BTreeMap<String, serde_json::Value>,
+}
+
+/// What your app answered with. Missing if the request never got a response,
+/// for example because it timed out.
+#[derive(Debug, Deserialize)]
+pub struct WebhookResponse {
+    pub status_code: Option<u16>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, serde_json::Value>,
+    pub body: Option<String>,
+}
+
+/// `Payload` arrives as a JSON string inside the form-encoded webhook.
+fn payload_from_str<'de, D: Deserializer<'de>>(d: D) -> Result<DebuggerPayload, D::Error> {
+    let s = String::deserialize(d)?;
+    serde_json::from_str(&s).map_err(serde::de::Error::custom)
+}
+
+impl Client {
+    pub fn list_alerts(&self, filter: &AlertFilter<'_>) -> PageIterator<Alert> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref level) = filter.log_level {
+            opts.push(("LogLevel", level.as_str()));
+        }
+        if let Some(start) = filter.start_date {
+            opts.push(("StartDate", start));
+        }
+        if let Some(end) = filter.end_date {
+            opts.push(("EndDate", end));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+        self.list_product(&MONITOR, "Alerts", "alerts", &opts)
+    }
+
+    pub async fn retrieve_alert(&self, sid: &AlertSid) -> Result<Alert, TwilioError> {
+        self.send_product_request(GET, &MONITOR, &format!("Alerts/{sid}"), &[])
+            .await
+    }
+
+    pub fn list_events(&self, filter: &EventFilter<'_>) -> PageIterator<Event> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(actor) = filter.actor_sid {
+            opts.push(("ActorSid", actor));
+        }
+        if let Some(event_type) = filter.event_type {
+            opts.push(("EventType", event_type));
+        }
+        if let Some(resource) = filter.resource_sid {
+            opts.push(("ResourceSid", resource));
+        }
+        if let Some(ip) = filter.source_ip_address {
+            opts.push(("SourceIpAddress", ip));
+        }
+        if let Some(start) = filter.start_date {
+            opts.push(("StartDate", start));
+        }
+        if let Some(end) = filter.end_date {
+            opts.push(("EndDate", end));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+        self.list_product(&MONITOR, "Events", "events", &opts)
+    }
+
+    pub async fn retrieve_event(&self, sid: &MonitorEventSid) -> Result<Event, TwilioError> {
+        self.send_product_request(GET, &MONITOR, &format!("Events/{sid}"), &[])
+            .await
+    }
+}
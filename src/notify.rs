@@ -0,0 +1,249 @@
+//! [Notify](https://www.twilio.com/docs/notify/api), which fans a single
+//! notification out to push (APNs, FCM) and SMS bindings by identity or tag.
+
+use crate::endpoint::NOTIFY;
+use crate::{
+    AccountSid, Client, MessagingServiceSid, NotificationSid, NotifyBindingSid, NotifyServiceSid,
+    PageIterator, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: NotifyServiceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    pub apn_credential_sid: Option<String>,
+    pub fcm_credential_sid: Option<String>,
+    pub messaging_service_sid: Option<MessagingServiceSid>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Settings applied when creating a service. Push credentials are the `CR`
+/// sids of credentials uploaded to your account.
+#[derive(Default)]
+pub struct ServiceConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub apn_credential_sid: Option<&'a str>,
+    pub fcm_credential_sid: Option<&'a str>,
+    /// Required for SMS bindings.
+    pub messaging_service_sid: Option<&'a MessagingServiceSid>,
+}
+
+pub enum BindingType {
+    Apn,
+    Fcm,
+    Sms,
+}
+
+impl BindingType {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            BindingType::Apn => "apn",
+            BindingType::Fcm => "fcm",
+            BindingType::Sms => "sms",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Binding {
+    pub sid: NotifyBindingSid,
+    pub account_sid: AccountSid,
+    pub service_sid: NotifyServiceSid,
+    pub identity: String,
+    pub binding_type: String,
+    pub address: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub credential_sid: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Registers `address` (a device token, or a phone number for SMS) as a way
+/// of reaching `identity`.
+pub struct NewBinding<'a> {
+    pub identity: &'a str,
+    pub binding_type: BindingType,
+    pub address: &'a str,
+    pub tags: Vec<&'a str>,
+    pub credential_sid: Option<&'a str>,
+}
+
+impl<'a> NewBinding<'a> {
+    pub fn new(identity: &'a str, binding_type: BindingType, address: &'a str) -> NewBinding<'a> {
+        NewBinding {
+            identity,
+            binding_type,
+            address,
+            tags: Vec::new(),
+            credential_sid: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Notification {
+    pub sid: NotificationSid,
+    pub account_sid: AccountSid,
+    pub service_sid: NotifyServiceSid,
+    #[serde(default)]
+    pub identities: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub body: Option<String>,
+    pub title: Option<String>,
+    pub priority: Option<String>,
+    pub ttl: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+}
+
+/// A notification for every binding of `identities`, plus every binding
+/// carrying one of `tags`. The tag `all` reaches every binding.
+#[derive(Default)]
+pub struct NewNotification<'a> {
+    pub identities: Vec<&'a str>,
+    pub tags: Vec<&'a str>,
+    pub body: Option<&'a str>,
+    pub title: Option<&'a str>,
+    pub sound: Option<&'a str>,
+    /// A custom payload delivered to push bindings.
+    pub data: Option<&'a serde_json::Value>,
+    /// Seconds the notification stays deliverable.
+    pub ttl: Option<u32>,
+    /// Sends at low priority instead of high.
+    pub low_priority: bool,
+}
+
+impl Client {
+    pub async fn create_notify_service(
+        &self,
+        config: ServiceConfig<'_>,
+    ) -> Result<Service, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = config.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(sid) = config.apn_credential_sid {
+            opts.push(("ApnCredentialSid", sid));
+        }
+        if let Some(sid) = config.fcm_credential_sid {
+            opts.push(("FcmCredentialSid", sid));
+        }
+        if let Some(sid) = config.messaging_service_sid {
+            opts.push(("MessagingServiceSid", sid.as_str()));
+        }
+        self.send_product_request(POST, &NOTIFY, "Services", &opts)
+            .await
+    }
+
+    pub fn list_notify_services(&self) -> PageIterator<Service> {
+        self.list_product(&NOTIFY, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_notify_service(
+        &self,
+        sid: &NotifyServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &NOTIFY, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_notify_service(&self, sid: &NotifyServiceSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&NOTIFY, &format!("Services/{sid}"))
+            .await
+    }
+
+    pub async fn create_binding(
+        &self,
+        service_sid: &NotifyServiceSid,
+        binding: NewBinding<'_>,
+    ) -> Result<Binding, TwilioError> {
+        let mut opts = vec![
+            ("Identity", binding.identity),
+            ("BindingType", binding.binding_type.as_str()),
+            ("Address", binding.address),
+        ];
+        for tag in &binding.tags {
+            opts.push(("Tag", tag));
+        }
+        if let Some(sid) = binding.credential_sid {
+            opts.push(("CredentialSid", sid));
+        }
+        self.send_product_request(
+            POST,
+            &NOTIFY,
+            &format!("Services/{service_sid}/Bindings"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_bindings(&self, service_sid: &NotifyServiceSid) -> PageIterator<Binding> {
+        self.list_product(
+            &NOTIFY,
+            &format!("Services/{service_sid}/Bindings"),
+            "bindings",
+            &[],
+        )
+    }
+
+    pub async fn delete_binding(
+        &self,
+        service_sid: &NotifyServiceSid,
+        sid: &NotifyBindingSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&NOTIFY, &format!("Services/{service_sid}/Bindings/{sid}"))
+            .await
+    }
+
+    pub async fn send_notification(
+        &self,
+        service_sid: &NotifyServiceSid,
+        notification: NewNotification<'_>,
+    ) -> Result<Notification, TwilioError> {
+        let data = notification.data.map(|d| d.to_string());
+        let ttl = notification.ttl.map(|t| t.to_string());
+        let mut opts = Vec::new();
+        for identity in &notification.identities {
+            opts.push(("Identity", *identity));
+        }
+        for tag in &notification.tags {
+            opts.push(("Tag", *tag));
+        }
+        if let Some(body) = notification.body {
+            opts.push(("Body", body));
+        }
+        if let Some(title) = notification.title {
+            opts.push(("Title", title));
+        }
+        if let Some(sound) = notification.sound {
+            opts.push(("Sound", sound));
+        }
+        if let Some(ref d) = data {
+            opts.push(("Data", d));
+        }
+        if let Some(ref t) = ttl {
+            opts.push(("Ttl", t));
+        }
+        if notification.low_priority {
+            opts.push(("Priority", "low"));
+        }
+        self.send_product_request(
+            POST,
+            &NOTIFY,
+            &format!("Services/{service_sid}/Notifications"),
+            &opts,
+        )
+        .await
+    }
+}
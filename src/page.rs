@@ -0,0 +1,203 @@
+use crate::endpoint::Endpoint;
+use crate::{Client, TwilioError, GET};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A single page of results from one of Twilio's list endpoints.
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub page_size: u32,
+    pub next_page_uri: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PageMeta {
+    page: u32,
+    page_size: u32,
+    next_page_url: Option<String>,
+}
+
+/// The wire format of a list response. The 2010-04-01 API puts the paging
+/// fields at the top level, newer APIs nest them under `meta`; either way the
+/// items themselves live under a resource-specific key such as `calls`.
+#[derive(Deserialize)]
+struct RawPage {
+    page: Option<u32>,
+    page_size: Option<u32>,
+    next_page_uri: Option<String>,
+    meta: Option<PageMeta>,
+    #[serde(flatten)]
+    rest: BTreeMap<String, serde_json::Value>,
+}
+
+impl RawPage {
+    fn into_page<T: DeserializeOwned>(mut self, key: &'static str) -> Result<Page<T>, TwilioError> {
+        let items = match self.rest.remove(key) {
+            Some(v) => serde_json::from_value(v).map_err(TwilioError::ParsingError)?,
+            None => {
+                let e = <serde_json::Error as serde::de::Error>::missing_field(key);
+                return Err(TwilioError::ParsingError(e));
+            }
+        };
+        match self.meta {
+            Some(meta) => Ok(Page {
+                items,
+                page: meta.page,
+                page_size: meta.page_size,
+                next_page_uri: meta.next_page_url,
+            }),
+            None => Ok(Page {
+                items,
+                page: self.page.unwrap_or(0),
+                page_size: self.page_size.unwrap_or(0),
+                next_page_uri: self.next_page_uri,
+            }),
+        }
+    }
+}
+
+enum PageRequest {
+    First {
+        url: String,
+        params: Vec<(String, String)>,
+    },
+    Next(String),
+}
+
+type PageFuture<T> = Pin<Box<dyn Future<Output = Result<Page<T>, TwilioError>> + Send>>;
+
+/// Lazily walks every page of a list endpoint.
+///
+/// Use it either as a `Stream` of individual items, or call `next_page` to
+/// work a page at a time. Mixing the two on one iterator skips items.
+pub struct PageIterator<T> {
+    client: Client,
+    key: &'static str,
+    next: Option<PageRequest>,
+    items: std::vec::IntoIter<T>,
+    pending: Option<PageFuture<T>>,
+}
+
+impl<T> Unpin for PageIterator<T> {}
+
+async fn fetch_page<T: DeserializeOwned>(
+    client: Client,
+    key: &'static str,
+    req: PageRequest,
+) -> Result<Page<T>, TwilioError> {
+    let raw: RawPage = match req {
+        PageRequest::First { url, params } => {
+            let params: Vec<(&str, &str)> = params
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect();
+            client.send_request_to_url(GET, url, &params).await?
+        }
+        PageRequest::Next(uri) => client.send_request_to_uri(&uri).await?,
+    };
+    raw.into_page(key)
+}
+
+impl<T: DeserializeOwned + Send + 'static> PageIterator<T> {
+    fn start(&mut self) -> Option<PageFuture<T>> {
+        if let Some(p) = self.pending.take() {
+            return Some(p);
+        }
+        let req = self.next.take()?;
+        Some(Box::pin(fetch_page(self.client.clone(), self.key, req)))
+    }
+
+    fn finish(&mut self, page: &Page<T>) {
+        self.next = page.next_page_uri.clone().map(PageRequest::Next);
+    }
+
+    /// Fetches the next page, or returns `None` once the last page has been seen.
+    pub async fn next_page(&mut self) -> Result<Option<Page<T>>, TwilioError> {
+        let fut = match self.start() {
+            Some(f) => f,
+            None => return Ok(None),
+        };
+        let page = fut.await?;
+        self.finish(&page);
+        Ok(Some(page))
+    }
+}
+
+impl<T: DeserializeOwned + Send + 'static> Stream for PageIterator<T> {
+    type Item = Result<T, TwilioError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            let mut fut = match self.start() {
+                Some(f) => f,
+                None => return Poll::Ready(None),
+            };
+            match fut.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => {
+                    self.finish(&page);
+                    self.items = page.items.into_iter();
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => {
+                    self.pending = Some(fut);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Builds a `PageIterator` over `endpoint`, whose items are found under `key`
+    /// in each response. No request is made until the iterator is polled.
+    pub(crate) fn list<T>(
+        &self,
+        endpoint: &str,
+        key: &'static str,
+        params: &[(&str, &str)],
+    ) -> PageIterator<T> {
+        self.list_url(self.account_url(endpoint, "json"), key, params)
+    }
+
+    /// Like `list`, for product APIs hosted outside of api.twilio.com.
+    pub(crate) fn list_product<T>(
+        &self,
+        endpoint: &Endpoint,
+        path: &str,
+        key: &'static str,
+        params: &[(&str, &str)],
+    ) -> PageIterator<T> {
+        self.list_url(self.product_url(endpoint, path), key, params)
+    }
+
+    fn list_url<T>(
+        &self,
+        url: String,
+        key: &'static str,
+        params: &[(&str, &str)],
+    ) -> PageIterator<T> {
+        PageIterator {
+            client: self.clone(),
+            key,
+            next: Some(PageRequest::First {
+                url,
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            }),
+            items: Vec::new().into_iter(),
+            pending: None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Phone number validation, so that a malformed `To` or `From` is caught
+//! before a request is made rather than coming back as Twilio error 21211.
+
+#[cfg(any(feature = "voice", feature = "messaging"))]
+use crate::TwilioError;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Returned when a string can't be read as an E.164 phone number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidNumber {
+    pub value: String,
+}
+
+impl Display for InvalidNumber {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not an E.164 phone number such as +15551234567",
+            self.value
+        )
+    }
+}
+
+impl Error for InvalidNumber {}
+
+/// A phone number in E.164 format: `+`, a country code and the subscriber
+/// number, 15 digits at most.
+///
+/// `new` accepts the punctuation people usually write numbers with and an
+/// international `00` prefix, so `"(+44) 20-7946 0958"` and
+/// `"0044 20 7946 0958"` both become `+442079460958`. Numbers without a
+/// country code can't be normalized, since the country is unknown.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct E164(String);
+
+impl E164 {
+    pub fn new(number: &str) -> Result<E164, InvalidNumber> {
+        let mut digits: String = number
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+            .collect();
+        if let Some(rest) = digits.strip_prefix("00") {
+            digits = format!("+{rest}");
+        }
+        if is_e164(&digits) {
+            Ok(E164(digits))
+        } else {
+            Err(InvalidNumber {
+                value: number.to_string(),
+            })
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for E164 {
+    type Err = InvalidNumber;
+
+    fn from_str(s: &str) -> Result<E164, InvalidNumber> {
+        E164::new(s)
+    }
+}
+
+impl Display for E164 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for E164 {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for E164 {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<E164, D::Error> {
+        let s = String::deserialize(d)?;
+        E164::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// True for a number already in E.164 form, with no punctuation.
+pub(crate) fn is_e164(s: &str) -> bool {
+    match s.strip_prefix('+') {
+        Some(digits) => {
+            (7..=15).contains(&digits.len())
+                && !digits.starts_with('0')
+                && digits.bytes().all(|b| b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Prefixes of addresses that aren't phone numbers, such as a SIP URI or a
+/// Twilio Client identity. These are sent as given, for Twilio to check.
+#[cfg(any(feature = "voice", feature = "messaging"))]
+const PREFIXES: &[&str] = &[
+    "client:",
+    "sip:",
+    "sips:",
+    "whatsapp:",
+    "messenger:",
+    "rcs:",
+];
+
+/// The form of `address` to send as a `To` or `From`. A prefixed address is
+/// sent as given; anything else must be a phone number, and is sent in the
+/// normalized E.164 form.
+#[cfg(any(feature = "voice", feature = "messaging"))]
+pub(crate) fn normalize_address(address: &str) -> Result<String, TwilioError> {
+    if PREFIXES.iter().any(|p| address.starts_with(p)) {
+        return Ok(address.to_string());
+    }
+    E164::new(address)
+        .map(|n| n.0)
+        .map_err(TwilioError::InvalidNumber)
+}
@@ -0,0 +1,149 @@
+use crate::twiml::Method;
+use crate::{
+    AccountSid, AddressSid, Client, PageIterator, PhoneNumberSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Capabilities {
+    #[serde(alias = "MMS")]
+    pub mms: Option<bool>,
+    #[serde(alias = "SMS")]
+    pub sms: Option<bool>,
+    pub voice: Option<bool>,
+    pub fax: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncomingPhoneNumber {
+    pub sid: PhoneNumberSid,
+    pub account_sid: AccountSid,
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    pub capabilities: Option<Capabilities>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub status_callback: Option<String>,
+    pub address_sid: Option<AddressSid>,
+    pub address_requirements: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Filters accepted by `Client::list_incoming_phone_numbers`. Twilio matches
+/// `phone_number` and `friendly_name` as substrings.
+#[derive(Default)]
+pub struct IncomingPhoneNumberFilter<'a> {
+    pub phone_number: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// Settings applied when purchasing or updating a number. Fields left as
+/// `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct PhoneNumberConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub voice_url: Option<&'a str>,
+    pub voice_method: Option<Method>,
+    pub sms_url: Option<&'a str>,
+    pub sms_method: Option<Method>,
+    pub status_callback: Option<&'a str>,
+    /// The address to register the number under, where the number's country
+    /// requires one.
+    pub address_sid: Option<&'a AddressSid>,
+}
+
+impl<'a> PhoneNumberConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let method_str = |m: &Method| match m {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(url) = self.voice_url {
+            opts.push(("VoiceUrl", url));
+        }
+        if let Some(ref m) = self.voice_method {
+            opts.push(("VoiceMethod", method_str(m)));
+        }
+        if let Some(url) = self.sms_url {
+            opts.push(("SmsUrl", url));
+        }
+        if let Some(ref m) = self.sms_method {
+            opts.push(("SmsMethod", method_str(m)));
+        }
+        if let Some(url) = self.status_callback {
+            opts.push(("StatusCallback", url));
+        }
+        if let Some(sid) = self.address_sid {
+            opts.push(("AddressSid", sid.as_str()));
+        }
+        opts
+    }
+}
+
+impl Client {
+    pub fn list_incoming_phone_numbers(
+        &self,
+        filter: &IncomingPhoneNumberFilter<'_>,
+    ) -> PageIterator<IncomingPhoneNumber> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(number) = filter.phone_number {
+            opts.push(("PhoneNumber", number));
+        }
+        if let Some(name) = filter.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("IncomingPhoneNumbers", "incoming_phone_numbers", &opts)
+    }
+
+    pub async fn retrieve_number(
+        &self,
+        sid: &PhoneNumberSid,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        self.send_request(GET, &format!("IncomingPhoneNumbers/{sid}"), &[])
+            .await
+    }
+
+    /// Buys `phone_number` (in E.164 format) for this account.
+    pub async fn purchase_number(
+        &self,
+        phone_number: &str,
+        config: PhoneNumberConfig<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        let mut opts = vec![("PhoneNumber", phone_number)];
+        opts.extend(config.params());
+        self.send_request(POST, "IncomingPhoneNumbers", &opts).await
+    }
+
+    pub async fn update_number(
+        &self,
+        sid: &PhoneNumberSid,
+        config: PhoneNumberConfig<'_>,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("IncomingPhoneNumbers/{sid}"),
+            &config.params(),
+        )
+        .await
+    }
+
+    /// Releases a number from the account. This cannot be undone.
+    pub async fn release_number(&self, sid: &PhoneNumberSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("IncomingPhoneNumbers/{sid}"))
+            .await
+    }
+}
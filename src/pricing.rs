@@ -0,0 +1,188 @@
+//! The [Pricing](https://www.twilio.com/docs/usage/pricing) API, for looking
+//! up what Twilio charges for messages, calls and phone numbers in each
+//! country before sending or buying anything.
+//!
+//! Prices are decimal strings in `price_unit`, e.g. `"0.0079"` USD.
+//! `base_price` is the list price and `current_price` what the account pays
+//! after any discounts.
+
+use crate::endpoint::{self, PRICING, PRICING_V2};
+use crate::{Client, PageIterator, TwilioError, GET};
+use serde::Deserialize;
+
+/// A country in the list returned by `Client::list_messaging_countries`,
+/// `list_voice_countries` or `list_phone_number_countries`. Retrieve it by
+/// `iso_country` for its prices.
+#[derive(Debug, Deserialize)]
+pub struct PricingCountry {
+    pub country: String,
+    pub iso_country: String,
+    pub url: Option<String>,
+}
+
+/// The price of one kind of phone number, e.g. `local` or `toll free`.
+#[derive(Debug, Deserialize)]
+pub struct NumberTypePrice {
+    pub number_type: String,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+/// The outbound SMS prices of one carrier.
+#[derive(Debug, Deserialize)]
+pub struct CarrierSmsPrices {
+    pub carrier: String,
+    pub mcc: Option<String>,
+    pub mnc: Option<String>,
+    /// Per message segment, by the type of number sending it.
+    #[serde(default)]
+    pub prices: Vec<NumberTypePrice>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessagingCountry {
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_sms_prices: Vec<CarrierSmsPrices>,
+    #[serde(default)]
+    pub inbound_sms_prices: Vec<NumberTypePrice>,
+    pub price_unit: String,
+    pub url: Option<String>,
+}
+
+/// The per-minute price of calls to numbers starting with any of
+/// `destination_prefixes`.
+#[derive(Debug, Deserialize)]
+pub struct PrefixPrice {
+    pub friendly_name: Option<String>,
+    #[serde(default)]
+    pub destination_prefixes: Vec<String>,
+    /// Set when the price only applies to calls from these prefixes.
+    #[serde(default)]
+    pub origination_prefixes: Vec<String>,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VoiceCountry {
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_prefix_prices: Vec<PrefixPrice>,
+    /// Per minute, by the type of number receiving the call.
+    #[serde(default)]
+    pub inbound_call_prices: Vec<NumberTypePrice>,
+    pub price_unit: String,
+    pub url: Option<String>,
+}
+
+/// The per-minute price of an outbound call from numbers starting with any
+/// of `origination_prefixes`, or from any number when it is empty.
+#[derive(Debug, Deserialize)]
+pub struct OutboundCallPrice {
+    #[serde(default)]
+    pub origination_prefixes: Vec<String>,
+    pub base_price: Option<String>,
+    pub current_price: Option<String>,
+}
+
+/// Call prices for one destination number.
+#[derive(Debug, Deserialize)]
+pub struct VoiceNumber {
+    pub destination_number: String,
+    pub origination_number: Option<String>,
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub outbound_call_prices: Vec<OutboundCallPrice>,
+    /// Set when the destination is a Twilio number.
+    pub inbound_call_price: Option<NumberTypePrice>,
+    pub price_unit: String,
+    pub url: Option<String>,
+}
+
+/// Monthly phone number prices in one country.
+#[derive(Debug, Deserialize)]
+pub struct PhoneNumberCountry {
+    pub country: String,
+    pub iso_country: String,
+    #[serde(default)]
+    pub phone_number_prices: Vec<NumberTypePrice>,
+    pub price_unit: String,
+    pub url: Option<String>,
+}
+
+impl Client {
+    pub fn list_messaging_countries(&self) -> PageIterator<PricingCountry> {
+        self.list_product(&PRICING, "Messaging/Countries", "countries", &[])
+    }
+
+    /// Retrieves SMS prices for a country, e.g. `"GB"`.
+    pub async fn retrieve_messaging_country(
+        &self,
+        iso_country: &str,
+    ) -> Result<MessagingCountry, TwilioError> {
+        self.send_product_request(
+            GET,
+            &PRICING,
+            &format!("Messaging/Countries/{iso_country}"),
+            &[],
+        )
+        .await
+    }
+
+    pub fn list_voice_countries(&self) -> PageIterator<PricingCountry> {
+        self.list_product(&PRICING_V2, "Voice/Countries", "countries", &[])
+    }
+
+    /// Retrieves call prices for a country, e.g. `"GB"`.
+    pub async fn retrieve_voice_country(
+        &self,
+        iso_country: &str,
+    ) -> Result<VoiceCountry, TwilioError> {
+        self.send_product_request(
+            GET,
+            &PRICING_V2,
+            &format!("Voice/Countries/{iso_country}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Retrieves the price of calling `destination`, optionally from
+    /// `origination`, both in E.164 format.
+    pub async fn retrieve_voice_number(
+        &self,
+        destination: &str,
+        origination: Option<&str>,
+    ) -> Result<VoiceNumber, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(origination) = origination {
+            opts.push(("OriginationNumber", origination));
+        }
+        let number = endpoint::path_segment(destination);
+
+        self.send_product_request(GET, &PRICING_V2, &format!("Voice/Numbers/{number}"), &opts)
+            .await
+    }
+
+    pub fn list_phone_number_countries(&self) -> PageIterator<PricingCountry> {
+        self.list_product(&PRICING, "PhoneNumbers/Countries", "countries", &[])
+    }
+
+    /// Retrieves monthly phone number prices for a country, e.g. `"GB"`.
+    pub async fn retrieve_phone_number_country(
+        &self,
+        iso_country: &str,
+    ) -> Result<PhoneNumberCountry, TwilioError> {
+        self.send_product_request(
+            GET,
+            &PRICING,
+            &format!("PhoneNumbers/Countries/{iso_country}"),
+            &[],
+        )
+        .await
+    }
+}
@@ -0,0 +1,339 @@
+//! [Proxy](https://www.twilio.com/docs/proxy/api), for masked calls and
+//! texts between participants who never see each other's real numbers.
+//! Participants in a session are reached through numbers from the
+//! service's pool.
+
+use crate::endpoint::PROXY;
+use crate::{
+    AccountSid, Client, PageIterator, PhoneNumberSid, ProxyParticipantSid, ProxyServiceSid,
+    ProxySessionSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: ProxyServiceSid,
+    pub account_sid: AccountSid,
+    pub unique_name: String,
+    pub default_ttl: Option<u32>,
+    pub callback_url: Option<String>,
+    pub intercept_callback_url: Option<String>,
+    pub out_of_session_callback_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Default)]
+pub struct ServiceConfig<'a> {
+    /// Seconds a session lasts after its last interaction, unless the session
+    /// overrides it.
+    pub default_ttl: Option<u32>,
+    pub callback_url: Option<&'a str>,
+    pub intercept_callback_url: Option<&'a str>,
+    pub out_of_session_callback_url: Option<&'a str>,
+}
+
+/// A number in a service's pool.
+#[derive(Debug, Deserialize)]
+pub struct PhoneNumber {
+    pub sid: PhoneNumberSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ProxyServiceSid,
+    pub phone_number: String,
+    pub friendly_name: Option<String>,
+    /// Reserved numbers are only used when requested explicitly.
+    pub is_reserved: Option<bool>,
+    /// How many active sessions are using the number.
+    pub in_use: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+pub enum Mode {
+    MessageOnly,
+    VoiceOnly,
+    VoiceAndMessage,
+}
+
+impl Mode {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Mode::MessageOnly => "message-only",
+            Mode::VoiceOnly => "voice-only",
+            Mode::VoiceAndMessage => "voice-and-message",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionStatus {
+    Open,
+    InProgress,
+    Closed,
+    Failed,
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Session {
+    pub sid: ProxySessionSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ProxyServiceSid,
+    pub unique_name: Option<String>,
+    pub status: SessionStatus,
+    pub mode: Option<String>,
+    pub ttl: Option<u32>,
+    pub closed_reason: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_started: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_ended: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expiry: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Default)]
+pub struct SessionConfig<'a> {
+    pub unique_name: Option<&'a str>,
+    pub ttl: Option<u32>,
+    /// An ISO 8601 time after which the session closes.
+    pub date_expiry: Option<&'a str>,
+    pub mode: Option<Mode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Participant {
+    pub sid: ProxyParticipantSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ProxyServiceSid,
+    pub session_sid: ProxySessionSid,
+    pub friendly_name: Option<String>,
+    /// The participant's real phone number.
+    pub identifier: String,
+    /// The pool number the participant is reached through.
+    pub proxy_identifier: Option<String>,
+    pub proxy_identifier_sid: Option<PhoneNumberSid>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    pub async fn create_proxy_service(
+        &self,
+        unique_name: &str,
+        config: ServiceConfig<'_>,
+    ) -> Result<Service, TwilioError> {
+        let default_ttl = config.default_ttl.map(|t| t.to_string());
+        let mut opts = vec![("UniqueName", unique_name)];
+        if let Some(ref t) = default_ttl {
+            opts.push(("DefaultTtl", t));
+        }
+        if let Some(url) = config.callback_url {
+            opts.push(("CallbackUrl", url));
+        }
+        if let Some(url) = config.intercept_callback_url {
+            opts.push(("InterceptCallbackUrl", url));
+        }
+        if let Some(url) = config.out_of_session_callback_url {
+            opts.push(("OutOfSessionCallbackUrl", url));
+        }
+        self.send_product_request(POST, &PROXY, "Services", &opts)
+            .await
+    }
+
+    pub fn list_proxy_services(&self) -> PageIterator<Service> {
+        self.list_product(&PROXY, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_proxy_service(
+        &self,
+        sid: &ProxyServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &PROXY, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_proxy_service(&self, sid: &ProxyServiceSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&PROXY, &format!("Services/{sid}"))
+            .await
+    }
+
+    /// Adds one of your numbers to the service's pool.
+    pub async fn add_proxy_phone_number(
+        &self,
+        service_sid: &ProxyServiceSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<PhoneNumber, TwilioError> {
+        self.send_product_request(
+            POST,
+            &PROXY,
+            &format!("Services/{service_sid}/PhoneNumbers"),
+            &[("Sid", phone_number_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_proxy_phone_numbers(
+        &self,
+        service_sid: &ProxyServiceSid,
+    ) -> PageIterator<PhoneNumber> {
+        self.list_product(
+            &PROXY,
+            &format!("Services/{service_sid}/PhoneNumbers"),
+            "phone_numbers",
+            &[],
+        )
+    }
+
+    pub async fn remove_proxy_phone_number(
+        &self,
+        service_sid: &ProxyServiceSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &PROXY,
+            &format!("Services/{service_sid}/PhoneNumbers/{phone_number_sid}"),
+        )
+        .await
+    }
+
+    pub async fn create_proxy_session(
+        &self,
+        service_sid: &ProxyServiceSid,
+        config: SessionConfig<'_>,
+    ) -> Result<Session, TwilioError> {
+        let ttl = config.ttl.map(|t| t.to_string());
+        let mut opts = Vec::new();
+        if let Some(name) = config.unique_name {
+            opts.push(("UniqueName", name));
+        }
+        if let Some(ref t) = ttl {
+            opts.push(("Ttl", t));
+        }
+        if let Some(expiry) = config.date_expiry {
+            opts.push(("DateExpiry", expiry));
+        }
+        if let Some(ref mode) = config.mode {
+            opts.push(("Mode", mode.as_str()));
+        }
+        self.send_product_request(
+            POST,
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_proxy_sessions(&self, service_sid: &ProxyServiceSid) -> PageIterator<Session> {
+        self.list_product(
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions"),
+            "sessions",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_proxy_session(
+        &self,
+        service_sid: &ProxyServiceSid,
+        sid: &ProxySessionSid,
+    ) -> Result<Session, TwilioError> {
+        self.send_product_request(
+            GET,
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Closes a session, so its participants can no longer reach each other.
+    pub async fn close_proxy_session(
+        &self,
+        service_sid: &ProxyServiceSid,
+        sid: &ProxySessionSid,
+    ) -> Result<Session, TwilioError> {
+        self.send_product_request(
+            POST,
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions/{sid}"),
+            &[("Status", "closed")],
+        )
+        .await
+    }
+
+    pub async fn delete_proxy_session(
+        &self,
+        service_sid: &ProxyServiceSid,
+        sid: &ProxySessionSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&PROXY, &format!("Services/{service_sid}/Sessions/{sid}"))
+            .await
+    }
+
+    /// Adds the owner of `identifier` (a phone number) to a session. A pool
+    /// number is picked for them unless `proxy_identifier` is given.
+    pub async fn add_proxy_participant(
+        &self,
+        service_sid: &ProxyServiceSid,
+        session_sid: &ProxySessionSid,
+        identifier: &str,
+        friendly_name: Option<&str>,
+        proxy_identifier: Option<&str>,
+    ) -> Result<Participant, TwilioError> {
+        let mut opts = vec![("Identifier", identifier)];
+        if let Some(name) = friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(proxy) = proxy_identifier {
+            opts.push(("ProxyIdentifier", proxy));
+        }
+        self.send_product_request(
+            POST,
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions/{session_sid}/Participants"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_proxy_participants(
+        &self,
+        service_sid: &ProxyServiceSid,
+        session_sid: &ProxySessionSid,
+    ) -> PageIterator<Participant> {
+        self.list_product(
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions/{session_sid}/Participants"),
+            "participants",
+            &[],
+        )
+    }
+
+    pub async fn remove_proxy_participant(
+        &self,
+        service_sid: &ProxyServiceSid,
+        session_sid: &ProxySessionSid,
+        sid: &ProxyParticipantSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &PROXY,
+            &format!("Services/{service_sid}/Sessions/{session_sid}/Participants/{sid}"),
+        )
+        .await
+    }
+}
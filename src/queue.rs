@@ -0,0 +1,149 @@
+use crate::twiml::Method;
+use crate::{
+    AccountSid, CallSid, Client, PageIterator, QueueSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Queue {
+    pub sid: QueueSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    pub current_size: u32,
+    pub max_size: u32,
+    pub average_wait_time: u32,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+#[derive(Default)]
+pub struct QueueUpdate<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub max_size: Option<u32>,
+}
+
+/// A call waiting in a queue.
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub call_sid: CallSid,
+    pub queue_sid: QueueSid,
+    pub position: u32,
+    pub wait_time: u32,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_enqueued: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+impl Client {
+    pub async fn create_queue(
+        &self,
+        friendly_name: &str,
+        max_size: Option<u32>,
+    ) -> Result<Queue, TwilioError> {
+        let max_size = max_size.map(|m| m.to_string());
+        let mut opts = vec![("FriendlyName", friendly_name)];
+        if let Some(ref m) = max_size {
+            opts.push(("MaxSize", m));
+        }
+        self.send_request(POST, "Queues", &opts).await
+    }
+
+    pub fn list_queues(&self) -> PageIterator<Queue> {
+        self.list("Queues", "queues", &[])
+    }
+
+    pub async fn retrieve_queue(&self, sid: &QueueSid) -> Result<Queue, TwilioError> {
+        self.send_request(GET, &format!("Queues/{sid}"), &[]).await
+    }
+
+    pub async fn update_queue(
+        &self,
+        sid: &QueueSid,
+        update: QueueUpdate<'_>,
+    ) -> Result<Queue, TwilioError> {
+        let max_size = update.max_size.map(|m| m.to_string());
+        let mut opts = Vec::new();
+        if let Some(name) = update.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref m) = max_size {
+            opts.push(("MaxSize", m));
+        }
+        self.send_request(POST, &format!("Queues/{sid}"), &opts)
+            .await
+    }
+
+    pub async fn delete_queue(&self, sid: &QueueSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Queues/{sid}")).await
+    }
+
+    pub fn list_members(&self, queue_sid: &QueueSid) -> PageIterator<Member> {
+        self.list(&format!("Queues/{queue_sid}/Members"), "queue_members", &[])
+    }
+
+    pub async fn retrieve_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: &CallSid,
+    ) -> Result<Member, TwilioError> {
+        self.fetch_member(queue_sid, call_sid.as_str()).await
+    }
+
+    /// Fetches the member that has been waiting longest.
+    pub async fn retrieve_front_member(&self, queue_sid: &QueueSid) -> Result<Member, TwilioError> {
+        self.fetch_member(queue_sid, "Front").await
+    }
+
+    async fn fetch_member(
+        &self,
+        queue_sid: &QueueSid,
+        member: &str,
+    ) -> Result<Member, TwilioError> {
+        self.send_request(GET, &format!("Queues/{queue_sid}/Members/{member}"), &[])
+            .await
+    }
+
+    /// Takes a call out of the queue and redirects it to the TwiML at `url`.
+    pub async fn dequeue_member(
+        &self,
+        queue_sid: &QueueSid,
+        call_sid: &CallSid,
+        url: &str,
+        method: Option<Method>,
+    ) -> Result<Member, TwilioError> {
+        self.dequeue(queue_sid, call_sid.as_str(), url, method)
+            .await
+    }
+
+    /// Dequeues whoever is first in line, redirecting them to the TwiML at `url`.
+    pub async fn dequeue_front_member(
+        &self,
+        queue_sid: &QueueSid,
+        url: &str,
+        method: Option<Method>,
+    ) -> Result<Member, TwilioError> {
+        self.dequeue(queue_sid, "Front", url, method).await
+    }
+
+    async fn dequeue(
+        &self,
+        queue_sid: &QueueSid,
+        member: &str,
+        url: &str,
+        method: Option<Method>,
+    ) -> Result<Member, TwilioError> {
+        let mut opts = vec![("Url", url)];
+        if let Some(ref method) = method {
+            let method_str = match method {
+                Method::Get => "GET",
+                Method::Post => "POST",
+            };
+            opts.push(("Method", method_str));
+        }
+        self.send_request(POST, &format!("Queues/{queue_sid}/Members/{member}"), &opts)
+            .await
+    }
+}
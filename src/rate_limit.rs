@@ -0,0 +1,47 @@
+use crate::timer::{self, Instant};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A token bucket holding up to one second's worth of requests. Clones of a
+/// `Client` share the same bucket, so the limit applies to all of them.
+pub(crate) struct RateLimiter {
+    per_second: f64,
+    state: Mutex<Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(per_second: u32) -> RateLimiter {
+        let per_second = f64::from(per_second.max(1));
+        RateLimiter {
+            per_second,
+            state: Mutex::new(Bucket {
+                tokens: per_second,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be sent, then takes a token for it.
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.state.lock().unwrap();
+                let now = Instant::now();
+                let refill = (now - bucket.refilled).as_secs_f64() * self.per_second;
+                bucket.tokens = (bucket.tokens + refill).min(self.per_second);
+                bucket.refilled = now;
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.per_second)
+            };
+            timer::sleep(wait).await;
+        }
+    }
+}
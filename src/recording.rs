@@ -0,0 +1,139 @@
+use crate::{
+    from_params, AccountSid, CallSid, Client, ConferenceSid, FromMap, Money, PageIterator,
+    RecordingSid, Timestamp, TwilioError, GET,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecordingStatus {
+    InProgress,
+    Paused,
+    Stopped,
+    Processing,
+    Completed,
+    Absent,
+    Deleted,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Recording {
+    pub sid: RecordingSid,
+    pub account_sid: AccountSid,
+    pub call_sid: Option<CallSid>,
+    pub conference_sid: Option<ConferenceSid>,
+    pub status: Option<RecordingStatus>,
+    pub source: Option<String>,
+    pub channels: Option<u32>,
+    pub duration: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub start_time: Option<Timestamp>,
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    pub error_code: Option<u32>,
+    pub uri: Option<String>,
+    pub media_url: Option<String>,
+}
+
+/// Posted to a `<Record>` verb's `action` or `recordingStatusCallback`, or to
+/// the `RecordingStatusCallback` of a call recording. `<Record>` actions
+/// don't include a status, only the finished recording.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RecordingStatusEvent {
+    pub recording_sid: RecordingSid,
+    pub recording_url: String,
+    pub recording_status: Option<RecordingStatus>,
+    /// The recording's length in seconds.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub recording_duration: Option<u32>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub recording_channels: Option<u32>,
+    /// What started the recording, e.g. `RecordVerb` or `DialVerb`.
+    pub recording_source: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub recording_start_time: Option<Timestamp>,
+    pub account_sid: Option<AccountSid>,
+    pub call_sid: Option<CallSid>,
+    pub conference_sid: Option<ConferenceSid>,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub error_code: Option<u32>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for RecordingStatusEvent {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<RecordingStatusEvent>, TwilioError> {
+        from_params(m)
+    }
+}
+
+/// The audio formats Twilio can serve a recording's media in.
+pub enum RecordingFormat {
+    Wav,
+    Mp3,
+}
+
+/// Filters accepted by `Client::list_recordings`. Dates are passed through to
+/// Twilio as-is, e.g. `"2023-01-31"`.
+#[derive(Default)]
+pub struct RecordingFilter<'a> {
+    pub call_sid: Option<&'a CallSid>,
+    pub conference_sid: Option<&'a ConferenceSid>,
+    pub date_created_after: Option<&'a str>,
+    pub date_created_before: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+impl Client {
+    pub fn list_recordings(&self, filter: &RecordingFilter<'_>) -> PageIterator<Recording> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(call_sid) = filter.call_sid {
+            opts.push(("CallSid", call_sid.as_str()));
+        }
+        if let Some(conference_sid) = filter.conference_sid {
+            opts.push(("ConferenceSid", conference_sid.as_str()));
+        }
+        if let Some(after) = filter.date_created_after {
+            opts.push(("DateCreated>", after));
+        }
+        if let Some(before) = filter.date_created_before {
+            opts.push(("DateCreated<", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("Recordings", "recordings", &opts)
+    }
+
+    pub async fn retrieve_recording(&self, sid: &RecordingSid) -> Result<Recording, TwilioError> {
+        self.send_request(GET, &format!("Recordings/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_recording(&self, sid: &RecordingSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Recordings/{sid}")).await
+    }
+
+    /// Downloads the audio for a recording using the client's credentials.
+    pub async fn download_recording(
+        &self,
+        sid: &RecordingSid,
+        format: RecordingFormat,
+    ) -> Result<Vec<u8>, TwilioError> {
+        let extension = match format {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Mp3 => "mp3",
+        };
+        let url = self.account_url(&format!("Recordings/{sid}"), extension);
+        self.send_raw_request(GET, url, &[])
+            .await
+            .map(|b| b.to_vec())
+    }
+}
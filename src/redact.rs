@@ -0,0 +1,81 @@
+//! Masks secrets, and optionally phone numbers, in the request and response
+//! dumps written by `ClientBuilder::debug_logging`.
+
+use url::form_urlencoded;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Which fields `ClientBuilder::debug_logging` masks. Credentials, such as
+/// `AuthToken`, `SipAuthPassword` and API key secrets, are always masked.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Redaction {
+    /// Only mask credentials.
+    #[default]
+    Credentials,
+    /// Also mask phone numbers and addresses in `To`, `From`, `Caller`,
+    /// `Called` and `PhoneNumber`.
+    CredentialsAndPii,
+}
+
+impl Redaction {
+    fn masks(&self, key: &str) -> bool {
+        let key: String = key
+            .chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect();
+        let secret = key.contains("password") || key.contains("secret") || key == "authtoken";
+        let pii = matches!(
+            key.as_str(),
+            "to" | "from" | "caller" | "called" | "phonenumber"
+        );
+        secret || (pii && *self == Redaction::CredentialsAndPii)
+    }
+
+    /// Masks values in `Key=Value&...` form or query parameters.
+    pub(crate) fn form(&self, encoded: &str) -> String {
+        let mut out = form_urlencoded::Serializer::new(String::new());
+        for (k, v) in form_urlencoded::parse(encoded.as_bytes()) {
+            let v = if self.masks(&k) { REDACTED.into() } else { v };
+            out.append_pair(&k, &v);
+        }
+        out.finish()
+    }
+
+    /// Masks the query string of `url`, leaving the rest as is.
+    pub(crate) fn url(&self, url: &str) -> String {
+        match url.split_once('?') {
+            Some((base, query)) => format!("{base}?{}", self.form(query)),
+            None => url.to_string(),
+        }
+    }
+
+    /// Masks a JSON body at any depth. Bodies that aren't JSON, such as
+    /// recording audio, are summarized by their length.
+    pub(crate) fn body(&self, body: &[u8]) -> String {
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(mut value) => {
+                self.json(&mut value);
+                value.to_string()
+            }
+            Err(_) if body.is_empty() => String::new(),
+            Err(_) => format!("<{} bytes>", body.len()),
+        }
+    }
+
+    fn json(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (k, v) in map.iter_mut() {
+                    if self.masks(k) && !v.is_null() {
+                        *v = REDACTED.into();
+                    } else {
+                        self.json(v);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(|v| self.json(v)),
+            _ => {}
+        }
+    }
+}
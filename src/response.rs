@@ -0,0 +1,56 @@
+use hyper::{HeaderMap, StatusCode};
+
+/// The status and headers of a successful response, for correlating a request
+/// with Twilio support and for adapting to Twilio's concurrency limits.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    /// The `Twilio-Request-Id` (`RQ...`) to quote when contacting support.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("twilio-request-id")
+    }
+
+    /// How long Twilio took to handle the request, in seconds.
+    pub fn request_duration(&self) -> Option<f64> {
+        self.header("twilio-request-duration")?.parse().ok()
+    }
+
+    /// How many requests the account had in flight, including this one.
+    /// Twilio answers with a 429 once this exceeds the account's limit.
+    pub fn concurrent_requests(&self) -> Option<u32> {
+        self.header("twilio-concurrent-requests")?.parse().ok()
+    }
+
+    /// The request limit of the current window, for APIs that report one,
+    /// such as Verify.
+    pub fn rate_limit(&self) -> Option<u32> {
+        self.header("x-rate-limit-limit")?.parse().ok()
+    }
+
+    /// The requests left in the current window, for APIs that report it.
+    pub fn rate_limit_remaining(&self) -> Option<u32> {
+        self.header("x-rate-limit-remaining")?.parse().ok()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+}
+
+/// A deserialized response along with its `ResponseMeta`, returned by the
+/// `*_with_meta` methods.
+#[derive(Debug)]
+pub struct WithMeta<T> {
+    pub value: T,
+    pub meta: ResponseMeta,
+}
+
+impl<T> WithMeta<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
@@ -0,0 +1,83 @@
+use crate::TwilioError;
+use hyper::header::{HeaderMap, RETRY_AFTER};
+use hyper::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Controls how failed requests are retried.
+///
+/// `429 Too Many Requests`, server errors and network failures are retried
+/// for idempotent methods (`GET`, `PUT` and `DELETE`). A `POST` may have taken
+/// effect even when it failed, so it is only sent again when it carries an
+/// idempotency token, such as `OutboundMessage::idempotency_token`, or when
+/// the connection to Twilio couldn't be made at all.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Retries stop once this much time has passed since the first attempt.
+    pub max_elapsed: Duration,
+    /// The delay before the first retry, doubled for each one after it.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// The delay before retry number `retry` (starting at 1), with jitter so
+    /// that clients failing together don't retry together.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exp = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let capped = exp.min(self.max_backoff);
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+        capped / 2 + capped / 2 * jitter as u32 / 1000
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            max_elapsed: Duration::from_secs(30),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+pub(crate) fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::PUT | Method::DELETE)
+}
+
+/// `idempotent` is whether the request is safe to repeat: either its method
+/// is, or it carries an idempotency token.
+pub(crate) fn should_retry_status(idempotent: bool, status: StatusCode) -> bool {
+    idempotent && (status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+}
+
+/// A request that failed to connect never reached Twilio, so it can be sent
+/// again whatever its method.
+pub(crate) fn should_retry_error(idempotent: bool, e: &TwilioError) -> bool {
+    match *e {
+        TwilioError::NetworkError(ref e) if e.is_connect() => true,
+        _ => idempotent && e.is_network(),
+    }
+}
+
+/// Reads a `Retry-After` given in seconds. Twilio doesn't send HTTP dates here.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
@@ -0,0 +1,533 @@
+//! [Serverless](https://www.twilio.com/docs/serverless/api) services, for
+//! deploying Twilio Functions and Assets without the CLI.
+//!
+//! A deploy uploads a version of each function and asset, bundles the
+//! versions into a build, waits for the build to complete, then deploys it
+//! to an environment.
+
+use crate::endpoint::{SERVERLESS, SERVERLESS_UPLOAD};
+use crate::{
+    bool_str, AccountSid, AssetSid, AssetVersionSid, BuildSid, Client, DeploymentSid,
+    EnvironmentSid, FilePart, FunctionSid, FunctionVersionSid, PageIterator, ServerlessServiceSid,
+    Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: ServerlessServiceSid,
+    pub account_sid: AccountSid,
+    pub unique_name: Option<String>,
+    pub friendly_name: Option<String>,
+    /// Whether functions get the account's credentials in their context.
+    pub include_credentials: Option<bool>,
+    pub ui_editable: Option<bool>,
+    pub domain_base: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A deployment target, such as `dev` or `production`, with its own domain.
+#[derive(Debug, Deserialize)]
+pub struct Environment {
+    pub sid: EnvironmentSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    /// The build currently deployed, if any.
+    pub build_sid: Option<BuildSid>,
+    pub unique_name: Option<String>,
+    pub domain_suffix: Option<String>,
+    pub domain_name: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Function {
+    pub sid: FunctionSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub friendly_name: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Asset {
+    pub sid: AssetSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub friendly_name: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Who can request a function or asset once deployed.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// Anyone.
+    Public,
+    /// Only requests signed by Twilio, such as webhooks.
+    Protected,
+    /// Only other functions in the service.
+    Private,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Visibility::Public => "public",
+            Visibility::Protected => "protected",
+            Visibility::Private => "private",
+        }
+    }
+}
+
+/// One uploaded revision of a function's code. Versions are immutable.
+#[derive(Debug, Deserialize)]
+pub struct FunctionVersion {
+    pub sid: FunctionVersionSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub function_sid: FunctionSid,
+    /// The URL path it is served at, e.g. `/hello`.
+    pub path: String,
+    pub visibility: Visibility,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// One uploaded revision of an asset's content. Versions are immutable.
+#[derive(Debug, Deserialize)]
+pub struct AssetVersion {
+    pub sid: AssetVersionSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub asset_sid: AssetSid,
+    pub path: String,
+    pub visibility: Visibility,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildStatus {
+    Building,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Build {
+    pub sid: BuildSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub status: BuildStatus,
+    #[serde(default)]
+    pub function_versions: Vec<FunctionVersion>,
+    #[serde(default)]
+    pub asset_versions: Vec<AssetVersion>,
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+    /// The Node.js runtime, e.g. `node18`.
+    pub runtime: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// The versions and npm dependencies to bundle with `Client::create_build`.
+#[derive(Default)]
+pub struct NewBuild<'a> {
+    pub function_versions: Vec<&'a FunctionVersionSid>,
+    pub asset_versions: Vec<&'a AssetVersionSid>,
+    /// Package names and version ranges, e.g. `("axios", "^1.6.0")`.
+    pub dependencies: Vec<(&'a str, &'a str)>,
+    pub runtime: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Deployment {
+    pub sid: DeploymentSid,
+    pub account_sid: AccountSid,
+    pub service_sid: ServerlessServiceSid,
+    pub environment_sid: EnvironmentSid,
+    pub build_sid: BuildSid,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+impl Client {
+    /// Creates a service. `unique_name` becomes part of its domain, so it may
+    /// only contain letters, numbers and dashes.
+    pub async fn create_serverless_service(
+        &self,
+        unique_name: &str,
+        friendly_name: &str,
+        include_credentials: bool,
+    ) -> Result<Service, TwilioError> {
+        let opts = [
+            ("UniqueName", unique_name),
+            ("FriendlyName", friendly_name),
+            ("IncludeCredentials", bool_str(include_credentials)),
+        ];
+        self.send_product_request(POST, &SERVERLESS, "Services", &opts)
+            .await
+    }
+
+    pub fn list_serverless_services(&self) -> PageIterator<Service> {
+        self.list_product(&SERVERLESS, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_serverless_service(
+        &self,
+        sid: &ServerlessServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &SERVERLESS, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_serverless_service(
+        &self,
+        sid: &ServerlessServiceSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&SERVERLESS, &format!("Services/{sid}"))
+            .await
+    }
+
+    pub async fn create_environment(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        unique_name: &str,
+        domain_suffix: Option<&str>,
+    ) -> Result<Environment, TwilioError> {
+        let mut opts = vec![("UniqueName", unique_name)];
+        if let Some(suffix) = domain_suffix {
+            opts.push(("DomainSuffix", suffix));
+        }
+        self.send_product_request(
+            POST,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_environments(
+        &self,
+        service_sid: &ServerlessServiceSid,
+    ) -> PageIterator<Environment> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments"),
+            "environments",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_environment(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &EnvironmentSid,
+    ) -> Result<Environment, TwilioError> {
+        self.send_product_request(
+            GET,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn delete_environment(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &EnvironmentSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments/{sid}"),
+        )
+        .await
+    }
+
+    pub async fn create_function(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        friendly_name: &str,
+    ) -> Result<Function, TwilioError> {
+        self.send_product_request(
+            POST,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Functions"),
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub fn list_functions(&self, service_sid: &ServerlessServiceSid) -> PageIterator<Function> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Functions"),
+            "functions",
+            &[],
+        )
+    }
+
+    pub async fn delete_function(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &FunctionSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Functions/{sid}"),
+        )
+        .await
+    }
+
+    /// Uploads `code`, the function's JavaScript source, to be served at
+    /// `path` once built and deployed.
+    pub async fn create_function_version(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        function_sid: &FunctionSid,
+        path: &str,
+        visibility: Visibility,
+        code: &str,
+    ) -> Result<FunctionVersion, TwilioError> {
+        let file = FilePart {
+            name: "Content",
+            filename: file_name(path),
+            content_type: "application/javascript",
+            data: code.as_bytes(),
+        };
+        self.send_product_multipart(
+            &SERVERLESS_UPLOAD,
+            &format!("Services/{service_sid}/Functions/{function_sid}/Versions"),
+            &[("Path", path), ("Visibility", visibility.as_str())],
+            file,
+        )
+        .await
+    }
+
+    pub fn list_function_versions(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        function_sid: &FunctionSid,
+    ) -> PageIterator<FunctionVersion> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Functions/{function_sid}/Versions"),
+            "function_versions",
+            &[],
+        )
+    }
+
+    pub async fn create_asset(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        friendly_name: &str,
+    ) -> Result<Asset, TwilioError> {
+        self.send_product_request(
+            POST,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Assets"),
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub fn list_assets(&self, service_sid: &ServerlessServiceSid) -> PageIterator<Asset> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Assets"),
+            "assets",
+            &[],
+        )
+    }
+
+    pub async fn delete_asset(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &AssetSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&SERVERLESS, &format!("Services/{service_sid}/Assets/{sid}"))
+            .await
+    }
+
+    /// Uploads `content` to be served at `path` with the given MIME type once
+    /// built and deployed.
+    pub async fn create_asset_version(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        asset_sid: &AssetSid,
+        path: &str,
+        visibility: Visibility,
+        content_type: &str,
+        content: &[u8],
+    ) -> Result<AssetVersion, TwilioError> {
+        let file = FilePart {
+            name: "Content",
+            filename: file_name(path),
+            content_type,
+            data: content,
+        };
+        self.send_product_multipart(
+            &SERVERLESS_UPLOAD,
+            &format!("Services/{service_sid}/Assets/{asset_sid}/Versions"),
+            &[("Path", path), ("Visibility", visibility.as_str())],
+            file,
+        )
+        .await
+    }
+
+    pub fn list_asset_versions(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        asset_sid: &AssetSid,
+    ) -> PageIterator<AssetVersion> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Assets/{asset_sid}/Versions"),
+            "asset_versions",
+            &[],
+        )
+    }
+
+    /// Starts a build. Builds take a few seconds; poll `retrieve_build` until
+    /// the status is no longer `Building` before deploying it.
+    pub async fn create_build(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        build: &NewBuild<'_>,
+    ) -> Result<Build, TwilioError> {
+        let dependencies = if build.dependencies.is_empty() {
+            None
+        } else {
+            let deps: Vec<_> = build
+                .dependencies
+                .iter()
+                .map(|(name, version)| serde_json::json!({ "name": name, "version": version }))
+                .collect();
+            Some(serde_json::Value::Array(deps).to_string())
+        };
+        let mut opts = Vec::new();
+        for sid in &build.function_versions {
+            opts.push(("FunctionVersions", sid.as_str()));
+        }
+        for sid in &build.asset_versions {
+            opts.push(("AssetVersions", sid.as_str()));
+        }
+        if let Some(ref deps) = dependencies {
+            opts.push(("Dependencies", deps));
+        }
+        if let Some(runtime) = build.runtime {
+            opts.push(("Runtime", runtime));
+        }
+        self.send_product_request(
+            POST,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Builds"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_builds(&self, service_sid: &ServerlessServiceSid) -> PageIterator<Build> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Builds"),
+            "builds",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_build(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &BuildSid,
+    ) -> Result<Build, TwilioError> {
+        self.send_product_request(
+            GET,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Builds/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn delete_build(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        sid: &BuildSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&SERVERLESS, &format!("Services/{service_sid}/Builds/{sid}"))
+            .await
+    }
+
+    /// Makes a completed build live in an environment.
+    pub async fn create_deployment(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        environment_sid: &EnvironmentSid,
+        build_sid: &BuildSid,
+    ) -> Result<Deployment, TwilioError> {
+        self.send_product_request(
+            POST,
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments/{environment_sid}/Deployments"),
+            &[("BuildSid", build_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_deployments(
+        &self,
+        service_sid: &ServerlessServiceSid,
+        environment_sid: &EnvironmentSid,
+    ) -> PageIterator<Deployment> {
+        self.list_product(
+            &SERVERLESS,
+            &format!("Services/{service_sid}/Environments/{environment_sid}/Deployments"),
+            "deployments",
+            &[],
+        )
+    }
+}
+
+/// The last segment of a path, used as the uploaded file's name.
+fn file_name(path: &str) -> &str {
+    match path.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name,
+        _ => "index",
+    }
+}
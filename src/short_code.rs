@@ -0,0 +1,92 @@
+use crate::twiml::Method;
+use crate::{AccountSid, Client, PageIterator, ShortCodeSid, Timestamp, TwilioError, GET, POST};
+use serde::Deserialize;
+
+/// A short code provisioned on the account. Short codes are leased through
+/// an application process rather than bought, so they can only be listed
+/// and configured here.
+#[derive(Debug, Deserialize)]
+pub struct ShortCode {
+    pub sid: ShortCodeSid,
+    pub account_sid: AccountSid,
+    pub short_code: String,
+    pub friendly_name: Option<String>,
+    pub sms_url: Option<String>,
+    pub sms_method: Option<String>,
+    pub sms_fallback_url: Option<String>,
+    pub sms_fallback_method: Option<String>,
+    pub api_version: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Filters accepted by `Client::list_short_codes`. Twilio matches both as
+/// substrings.
+#[derive(Default)]
+pub struct ShortCodeFilter<'a> {
+    pub short_code: Option<&'a str>,
+    pub friendly_name: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// Fields left as `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct ShortCodeUpdate<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub sms_url: Option<&'a str>,
+    pub sms_method: Option<Method>,
+    pub sms_fallback_url: Option<&'a str>,
+    pub sms_fallback_method: Option<Method>,
+}
+
+impl Client {
+    pub fn list_short_codes(&self, filter: &ShortCodeFilter<'_>) -> PageIterator<ShortCode> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(code) = filter.short_code {
+            opts.push(("ShortCode", code));
+        }
+        if let Some(name) = filter.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list("SMS/ShortCodes", "short_codes", &opts)
+    }
+
+    pub async fn retrieve_short_code(&self, sid: &ShortCodeSid) -> Result<ShortCode, TwilioError> {
+        self.send_request(GET, &format!("SMS/ShortCodes/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_short_code(
+        &self,
+        sid: &ShortCodeSid,
+        update: ShortCodeUpdate<'_>,
+    ) -> Result<ShortCode, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = update.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(url) = update.sms_url {
+            opts.push(("SmsUrl", url));
+        }
+        if let Some(ref m) = update.sms_method {
+            opts.push(("SmsMethod", m.as_str()));
+        }
+        if let Some(url) = update.sms_fallback_url {
+            opts.push(("SmsFallbackUrl", url));
+        }
+        if let Some(ref m) = update.sms_fallback_method {
+            opts.push(("SmsFallbackMethod", m.as_str()));
+        }
+
+        self.send_request(POST, &format!("SMS/ShortCodes/{sid}"), &opts)
+            .await
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Returned when a string is not a well-formed SID of the expected type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSid {
+    pub expected: &'static [&'static str],
+    pub value: String,
+}
+
+impl Display for InvalidSid {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a SID starting with {}",
+            self.value,
+            self.expected.join(" or ")
+        )
+    }
+}
+
+impl Error for InvalidSid {}
+
+/// A SID is a two-letter type prefix followed by 32 hex digits.
+fn is_valid(prefixes: &[&str], s: &str) -> bool {
+    s.len() == 34
+        && prefixes.iter().any(|p| s.starts_with(p))
+        && s[2..].bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+macro_rules! sid {
+    ($(#[$meta:meta])* $name:ident, $($prefix:literal),+) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub const PREFIXES: &'static [&'static str] = &[$($prefix),+];
+
+            pub fn new(s: &str) -> Result<$name, InvalidSid> {
+                if is_valid(Self::PREFIXES, s) {
+                    Ok($name(s.to_string()))
+                } else {
+                    Err(InvalidSid {
+                        expected: Self::PREFIXES,
+                        value: s.to_string(),
+                    })
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidSid;
+
+            fn from_str(s: &str) -> Result<$name, InvalidSid> {
+                $name::new(s)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<$name, D::Error> {
+                let s = String::deserialize(d)?;
+                $name::new(&s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+sid!(AccountSid, "AC");
+sid!(
+    /// Identifies a TaskRouter activity, such as `Available` or `Offline`.
+    ActivitySid,
+    "WA"
+);
+sid!(AddressSid, "AD");
+sid!(
+    /// Identifies a Monitor debugger alert.
+    AlertSid,
+    "NO"
+);
+sid!(ApiKeySid, "SK");
+sid!(
+    /// Identifies a TwiML application.
+    ApplicationSid,
+    "AP"
+);
+sid!(
+    /// Identifies a Serverless asset. Assets share the `ZH` prefix with
+    /// functions.
+    AssetSid,
+    "ZH"
+);
+sid!(AssetVersionSid, "ZN");
+sid!(
+    /// Identifies an A2P 10DLC brand registration.
+    BrandRegistrationSid,
+    "BN"
+);
+sid!(
+    /// Identifies a Serverless build, a bundle of function and asset versions
+    /// that can be deployed to an environment.
+    BuildSid,
+    "ZB"
+);
+sid!(
+    /// Identifies a Trust Hub bundle: a customer profile or trust product.
+    BundleSid,
+    "BU"
+);
+sid!(CallSid, "CA");
+sid!(
+    /// Identifies a Video composition, a room's recordings mixed into one
+    /// file.
+    CompositionSid,
+    "CJ"
+);
+sid!(ConferenceSid, "CF");
+sid!(
+    /// Identifies a Content API message template.
+    ContentSid,
+    "HX"
+);
+sid!(ConversationMessageSid, "IM");
+sid!(ConversationParticipantSid, "MB");
+sid!(
+    /// Identifies a Conversations service, the container conversations and
+    /// their users live in.
+    ConversationServiceSid,
+    "IS"
+);
+sid!(ConversationSid, "CH");
+sid!(ConversationWebhookSid, "WH");
+sid!(CredentialListSid, "CL");
+sid!(DeploymentSid, "ZD");
+sid!(EndUserSid, "IT");
+sid!(EnvironmentSid, "ZE");
+sid!(
+    /// Identifies one run of a Studio Flow.
+    ExecutionSid,
+    "FN"
+);
+sid!(FaxSid, "FX");
+sid!(FeedbackSummarySid, "FS");
+sid!(FlowSid, "FW");
+sid!(FunctionSid, "ZH");
+sid!(FunctionVersionSid, "ZN");
+sid!(
+    /// Identifies a Voice Intelligence service, which sets the language and
+    /// operators used for its transcripts.
+    IntelligenceServiceSid,
+    "GA"
+);
+sid!(InteractionChannelSid, "UO");
+sid!(
+    /// Identifies a Flex interaction, one customer contact across however
+    /// many channels it spans.
+    InteractionSid,
+    "KD"
+);
+sid!(IpAccessControlListSid, "AL");
+sid!(IpAddressSid, "IP");
+sid!(MediaSid, "ME");
+sid!(
+    /// Identifies an SMS (`SM`) or MMS (`MM`) message.
+    MessageSid,
+    "SM",
+    "MM"
+);
+sid!(MessagingServiceSid, "MG");
+sid!(
+    /// Identifies a Monitor audit event, as opposed to a TaskRouter event.
+    MonitorEventSid,
+    "AE"
+);
+sid!(NotificationSid, "NT");
+sid!(NotifyBindingSid, "BS");
+sid!(NotifyServiceSid, "IS");
+sid!(OriginationUrlSid, "OU");
+sid!(PhoneNumberSid, "PN");
+sid!(ProxyParticipantSid, "KP");
+sid!(ProxyServiceSid, "KS");
+sid!(ProxySessionSid, "KC");
+sid!(QueueSid, "QU");
+sid!(RecordingSid, "RE");
+sid!(ReservationSid, "WR");
+sid!(RoomParticipantSid, "PA");
+sid!(RoomRecordingSid, "RT");
+sid!(
+    /// Identifies a Video room.
+    RoomSid,
+    "RM"
+);
+sid!(
+    /// Identifies a Serverless service, which holds Functions and Assets.
+    ServerlessServiceSid,
+    "ZS"
+);
+sid!(ShortCodeSid, "SC");
+sid!(
+    /// Identifies an Event Streams sink, where subscribed events are sent.
+    SinkSid,
+    "DG"
+);
+sid!(SipCredentialSid, "CR");
+sid!(SipDomainSid, "SD");
+sid!(
+    /// Identifies a Media Stream.
+    StreamSid,
+    "MZ"
+);
+sid!(SubscriptionSid, "DF");
+sid!(SupportingDocumentSid, "RD");
+sid!(SyncDocumentSid, "ET");
+sid!(SyncListSid, "ES");
+sid!(SyncMapSid, "MP");
+sid!(
+    /// Identifies a Sync service. These share the `IS` prefix with
+    /// Conversations services.
+    SyncServiceSid,
+    "IS"
+);
+sid!(TaskQueueSid, "WQ");
+sid!(TaskSid, "WT");
+sid!(
+    /// Identifies a Voice Intelligence transcript, as opposed to a legacy
+    /// recording transcription.
+    TranscriptSid,
+    "GT"
+);
+sid!(TranscriptionSid, "TR");
+sid!(TrunkSid, "TK");
+sid!(
+    /// Identifies an A2P 10DLC campaign on a Messaging Service.
+    UsAppToPersonSid,
+    "QE"
+);
+sid!(UsageTriggerSid, "UT");
+sid!(VerificationSid, "VE");
+sid!(VerifyServiceSid, "VA");
+sid!(WorkerSid, "WK");
+sid!(WorkflowSid, "WW");
+sid!(WorkspaceSid, "WS");
@@ -0,0 +1,408 @@
+use crate::twiml::Method;
+use crate::{
+    bool_str, AccountSid, Client, CredentialListSid, IpAccessControlListSid, IpAddressSid,
+    PageIterator, SipCredentialSid, SipDomainSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+/// A SIP domain, such as `example.sip.twilio.com`, that SIP endpoints can
+/// call into or register with.
+#[derive(Debug, Deserialize)]
+pub struct SipDomain {
+    pub sid: SipDomainSid,
+    pub account_sid: AccountSid,
+    pub domain_name: String,
+    pub friendly_name: Option<String>,
+    pub voice_url: Option<String>,
+    pub voice_method: Option<String>,
+    pub voice_fallback_url: Option<String>,
+    pub voice_status_callback_url: Option<String>,
+    pub sip_registration: Option<bool>,
+    pub secure: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// Settings applied when creating or updating a domain. Fields left as
+/// `None` are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct SipDomainConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub voice_url: Option<&'a str>,
+    pub voice_method: Option<Method>,
+    pub voice_fallback_url: Option<&'a str>,
+    pub voice_status_callback_url: Option<&'a str>,
+    /// Whether endpoints may register with the domain.
+    pub sip_registration: Option<bool>,
+    /// Whether to require TLS and SRTP.
+    pub secure: Option<bool>,
+}
+
+impl<'a> SipDomainConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(url) = self.voice_url {
+            opts.push(("VoiceUrl", url));
+        }
+        if let Some(ref method) = self.voice_method {
+            opts.push(("VoiceMethod", method.as_str()));
+        }
+        if let Some(url) = self.voice_fallback_url {
+            opts.push(("VoiceFallbackUrl", url));
+        }
+        if let Some(url) = self.voice_status_callback_url {
+            opts.push(("VoiceStatusCallbackUrl", url));
+        }
+        if let Some(registration) = self.sip_registration {
+            opts.push(("SipRegistration", bool_str(registration)));
+        }
+        if let Some(secure) = self.secure {
+            opts.push(("Secure", bool_str(secure)));
+        }
+        opts
+    }
+}
+
+/// A named set of username/password credentials.
+#[derive(Debug, Deserialize)]
+pub struct CredentialList {
+    pub sid: CredentialListSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SipCredential {
+    pub sid: SipCredentialSid,
+    pub account_sid: AccountSid,
+    pub credential_list_sid: CredentialListSid,
+    pub username: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+/// A named set of IP addresses that are allowed to send traffic.
+#[derive(Debug, Deserialize)]
+pub struct IpAccessControlList {
+    pub sid: IpAccessControlListSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IpAddress {
+    pub sid: IpAddressSid,
+    pub account_sid: AccountSid,
+    pub ip_access_control_list_sid: IpAccessControlListSid,
+    pub friendly_name: String,
+    pub ip_address: String,
+    pub cidr_prefix_length: Option<u8>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+impl Client {
+    pub async fn create_sip_domain(
+        &self,
+        domain_name: &str,
+        config: SipDomainConfig<'_>,
+    ) -> Result<SipDomain, TwilioError> {
+        let mut opts = vec![("DomainName", domain_name)];
+        opts.extend(config.params());
+        self.send_request(POST, "SIP/Domains", &opts).await
+    }
+
+    pub fn list_sip_domains(&self) -> PageIterator<SipDomain> {
+        self.list("SIP/Domains", "domains", &[])
+    }
+
+    pub async fn retrieve_sip_domain(&self, sid: &SipDomainSid) -> Result<SipDomain, TwilioError> {
+        self.send_request(GET, &format!("SIP/Domains/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_sip_domain(
+        &self,
+        sid: &SipDomainSid,
+        config: SipDomainConfig<'_>,
+    ) -> Result<SipDomain, TwilioError> {
+        self.send_request(POST, &format!("SIP/Domains/{sid}"), &config.params())
+            .await
+    }
+
+    pub async fn delete_sip_domain(&self, sid: &SipDomainSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("SIP/Domains/{sid}")).await
+    }
+
+    /// Requires callers into the domain to authenticate with a credential from
+    /// `credential_list_sid`.
+    pub async fn map_credential_list(
+        &self,
+        domain_sid: &SipDomainSid,
+        credential_list_sid: &CredentialListSid,
+    ) -> Result<CredentialList, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/Domains/{domain_sid}/CredentialListMappings"),
+            &[("CredentialListSid", credential_list_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_credential_list_mappings(
+        &self,
+        domain_sid: &SipDomainSid,
+    ) -> PageIterator<CredentialList> {
+        self.list(
+            &format!("SIP/Domains/{domain_sid}/CredentialListMappings"),
+            "credential_list_mappings",
+            &[],
+        )
+    }
+
+    pub async fn unmap_credential_list(
+        &self,
+        domain_sid: &SipDomainSid,
+        credential_list_sid: &CredentialListSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!(
+            "SIP/Domains/{domain_sid}/CredentialListMappings/{credential_list_sid}"
+        ))
+        .await
+    }
+
+    /// Only accepts calls into the domain from addresses in `list_sid`.
+    pub async fn map_ip_access_control_list(
+        &self,
+        domain_sid: &SipDomainSid,
+        list_sid: &IpAccessControlListSid,
+    ) -> Result<IpAccessControlList, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/Domains/{domain_sid}/IpAccessControlListMappings"),
+            &[("IpAccessControlListSid", list_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_ip_access_control_list_mappings(
+        &self,
+        domain_sid: &SipDomainSid,
+    ) -> PageIterator<IpAccessControlList> {
+        self.list(
+            &format!("SIP/Domains/{domain_sid}/IpAccessControlListMappings"),
+            "ip_access_control_list_mappings",
+            &[],
+        )
+    }
+
+    pub async fn unmap_ip_access_control_list(
+        &self,
+        domain_sid: &SipDomainSid,
+        list_sid: &IpAccessControlListSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!(
+            "SIP/Domains/{domain_sid}/IpAccessControlListMappings/{list_sid}"
+        ))
+        .await
+    }
+
+    pub async fn create_credential_list(
+        &self,
+        friendly_name: &str,
+    ) -> Result<CredentialList, TwilioError> {
+        self.send_request(
+            POST,
+            "SIP/CredentialLists",
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub fn list_credential_lists(&self) -> PageIterator<CredentialList> {
+        self.list("SIP/CredentialLists", "credential_lists", &[])
+    }
+
+    pub async fn retrieve_credential_list(
+        &self,
+        sid: &CredentialListSid,
+    ) -> Result<CredentialList, TwilioError> {
+        self.send_request(GET, &format!("SIP/CredentialLists/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_credential_list(
+        &self,
+        sid: &CredentialListSid,
+        friendly_name: &str,
+    ) -> Result<CredentialList, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/CredentialLists/{sid}"),
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub async fn delete_credential_list(&self, sid: &CredentialListSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("SIP/CredentialLists/{sid}"))
+            .await
+    }
+
+    pub async fn create_sip_credential(
+        &self,
+        list_sid: &CredentialListSid,
+        username: &str,
+        password: &str,
+    ) -> Result<SipCredential, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/CredentialLists/{list_sid}/Credentials"),
+            &[("Username", username), ("Password", password)],
+        )
+        .await
+    }
+
+    pub fn list_sip_credentials(
+        &self,
+        list_sid: &CredentialListSid,
+    ) -> PageIterator<SipCredential> {
+        self.list(
+            &format!("SIP/CredentialLists/{list_sid}/Credentials"),
+            "credentials",
+            &[],
+        )
+    }
+
+    /// Changes a credential's password. Usernames can't be changed.
+    pub async fn update_sip_credential(
+        &self,
+        list_sid: &CredentialListSid,
+        sid: &SipCredentialSid,
+        password: &str,
+    ) -> Result<SipCredential, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/CredentialLists/{list_sid}/Credentials/{sid}"),
+            &[("Password", password)],
+        )
+        .await
+    }
+
+    pub async fn delete_sip_credential(
+        &self,
+        list_sid: &CredentialListSid,
+        sid: &SipCredentialSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!("SIP/CredentialLists/{list_sid}/Credentials/{sid}"))
+            .await
+    }
+
+    pub async fn create_ip_access_control_list(
+        &self,
+        friendly_name: &str,
+    ) -> Result<IpAccessControlList, TwilioError> {
+        self.send_request(
+            POST,
+            "SIP/IpAccessControlLists",
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub fn list_ip_access_control_lists(&self) -> PageIterator<IpAccessControlList> {
+        self.list("SIP/IpAccessControlLists", "ip_access_control_lists", &[])
+    }
+
+    pub async fn retrieve_ip_access_control_list(
+        &self,
+        sid: &IpAccessControlListSid,
+    ) -> Result<IpAccessControlList, TwilioError> {
+        self.send_request(GET, &format!("SIP/IpAccessControlLists/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_ip_access_control_list(
+        &self,
+        sid: &IpAccessControlListSid,
+        friendly_name: &str,
+    ) -> Result<IpAccessControlList, TwilioError> {
+        self.send_request(
+            POST,
+            &format!("SIP/IpAccessControlLists/{sid}"),
+            &[("FriendlyName", friendly_name)],
+        )
+        .await
+    }
+
+    pub async fn delete_ip_access_control_list(
+        &self,
+        sid: &IpAccessControlListSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!("SIP/IpAccessControlLists/{sid}"))
+            .await
+    }
+
+    /// Allows `ip_address`, or the whole range when `cidr_prefix_length` is
+    /// given, e.g. `24` for a `/24`.
+    pub async fn create_ip_address(
+        &self,
+        list_sid: &IpAccessControlListSid,
+        friendly_name: &str,
+        ip_address: &str,
+        cidr_prefix_length: Option<u8>,
+    ) -> Result<IpAddress, TwilioError> {
+        let cidr = cidr_prefix_length.map(|c| c.to_string());
+        let mut opts = vec![("FriendlyName", friendly_name), ("IpAddress", ip_address)];
+        if let Some(ref c) = cidr {
+            opts.push(("CidrPrefixLength", c));
+        }
+        self.send_request(
+            POST,
+            &format!("SIP/IpAccessControlLists/{list_sid}/IpAddresses"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_ip_addresses(&self, list_sid: &IpAccessControlListSid) -> PageIterator<IpAddress> {
+        self.list(
+            &format!("SIP/IpAccessControlLists/{list_sid}/IpAddresses"),
+            "ip_addresses",
+            &[],
+        )
+    }
+
+    pub async fn delete_ip_address(
+        &self,
+        list_sid: &IpAccessControlListSid,
+        sid: &IpAddressSid,
+    ) -> Result<(), TwilioError> {
+        self.send_delete(&format!(
+            "SIP/IpAccessControlLists/{list_sid}/IpAddresses/{sid}"
+        ))
+        .await
+    }
+}
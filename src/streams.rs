@@ -0,0 +1,263 @@
+//! The [Media Streams](https://www.twilio.com/docs/voice/media-streams/websocket-messages)
+//! WebSocket protocol, started with `<Connect><Stream>` or `<Start><Stream>`.
+//!
+//! This module doesn't open sockets itself. Feed each text frame from your
+//! WebSocket library to `parse_event`, and wrap its sink in a `StreamSink` to
+//! play audio back on bidirectional streams:
+//!
+//! ```ignore
+//! while let Some(Ok(frame)) = ws_rx.next().await {
+//!     match streams::parse_event(&frame)? {
+//!         StreamEvent::Start { start, .. } => sink = Some(StreamSink::new(ws_tx, &start.stream_sid)),
+//!         StreamEvent::Media { media, .. } => transcriber.feed(&streams::decode_mulaw(&media.audio()?)),
+//!         StreamEvent::Stop { .. } => break,
+//!         _ => {}
+//!     }
+//! }
+//! ```
+//!
+//! Audio is 8kHz mono μ-law in both directions.
+
+use crate::{AccountSid, CallSid, StreamSid, TwilioError};
+use futures_sink::Sink;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A message sent by Twilio over the stream's WebSocket.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum StreamEvent {
+    /// The first message on every connection.
+    Connected { protocol: String, version: String },
+    Start {
+        #[serde(rename = "sequenceNumber")]
+        #[serde(default, deserialize_with = "crate::number_from_str")]
+        sequence_number: Option<u64>,
+        #[serde(rename = "streamSid")]
+        stream_sid: StreamSid,
+        start: Start,
+    },
+    Media {
+        #[serde(rename = "sequenceNumber")]
+        #[serde(default, deserialize_with = "crate::number_from_str")]
+        sequence_number: Option<u64>,
+        #[serde(rename = "streamSid")]
+        stream_sid: StreamSid,
+        media: Media,
+    },
+    /// A key the caller pressed.
+    Dtmf {
+        #[serde(rename = "sequenceNumber")]
+        #[serde(default, deserialize_with = "crate::number_from_str")]
+        sequence_number: Option<u64>,
+        #[serde(rename = "streamSid")]
+        stream_sid: StreamSid,
+        dtmf: Dtmf,
+    },
+    /// Sent once the audio queued before an `Outgoing::Mark` has finished playing.
+    Mark {
+        #[serde(rename = "sequenceNumber")]
+        #[serde(default, deserialize_with = "crate::number_from_str")]
+        sequence_number: Option<u64>,
+        #[serde(rename = "streamSid")]
+        stream_sid: StreamSid,
+        mark: Mark,
+    },
+    Stop {
+        #[serde(rename = "sequenceNumber")]
+        #[serde(default, deserialize_with = "crate::number_from_str")]
+        sequence_number: Option<u64>,
+        #[serde(rename = "streamSid")]
+        stream_sid: StreamSid,
+        stop: Stop,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Start {
+    pub account_sid: AccountSid,
+    pub call_sid: CallSid,
+    pub stream_sid: StreamSid,
+    /// `inbound`, `outbound`, or both.
+    pub tracks: Vec<String>,
+    pub media_format: MediaFormat,
+    /// The `<Parameter>`s given to the `<Stream>`.
+    #[serde(default)]
+    pub custom_parameters: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaFormat {
+    /// Always `audio/x-mulaw`.
+    pub encoding: String,
+    pub sample_rate: u32,
+    pub channels: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Media {
+    pub track: String,
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub chunk: Option<u64>,
+    /// Milliseconds since the start of the stream.
+    #[serde(default, deserialize_with = "crate::number_from_str")]
+    pub timestamp: Option<u64>,
+    /// Base64-encoded μ-law audio.
+    pub payload: String,
+}
+
+impl Media {
+    /// Decodes `payload` into raw μ-law bytes. Pass them to `decode_mulaw`
+    /// for 16-bit PCM.
+    pub fn audio(&self) -> Result<Vec<u8>, TwilioError> {
+        base64::decode(&self.payload).map_err(|_| TwilioError::BadRequest)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Dtmf {
+    pub track: String,
+    pub digit: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Mark {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stop {
+    pub account_sid: AccountSid,
+    pub call_sid: CallSid,
+}
+
+pub fn parse_event(frame: &str) -> Result<StreamEvent, TwilioError> {
+    serde_json::from_str(frame).map_err(TwilioError::ParsingError)
+}
+
+/// A message to send back to Twilio on a bidirectional stream.
+pub enum Outgoing {
+    /// μ-law audio to queue for playback.
+    Media(Vec<u8>),
+    /// Asks Twilio to send a `StreamEvent::Mark` with this name once the audio
+    /// queued before it has played.
+    Mark(String),
+    /// Discards any audio that hasn't played yet, e.g. when the caller starts
+    /// talking over a bot.
+    Clear,
+}
+
+impl Outgoing {
+    /// Renders the message as a JSON text frame for `stream_sid`.
+    pub fn to_frame(&self, stream_sid: &StreamSid) -> String {
+        let msg = match self {
+            Outgoing::Media(audio) => json!({
+                "event": "media",
+                "streamSid": stream_sid,
+                "media": { "payload": base64::encode(audio) },
+            }),
+            Outgoing::Mark(name) => json!({
+                "event": "mark",
+                "streamSid": stream_sid,
+                "mark": { "name": name },
+            }),
+            Outgoing::Clear => json!({
+                "event": "clear",
+                "streamSid": stream_sid,
+            }),
+        };
+        msg.to_string()
+    }
+}
+
+/// Adapts a sink of WebSocket text frames into a sink of `Outgoing`
+/// messages for one stream.
+pub struct StreamSink<S> {
+    inner: S,
+    stream_sid: StreamSid,
+}
+
+impl<S> StreamSink<S> {
+    pub fn new(inner: S, stream_sid: &StreamSid) -> StreamSink<S> {
+        StreamSink {
+            inner,
+            stream_sid: stream_sid.clone(),
+        }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Sink<String> + Unpin> Sink<Outgoing> for StreamSink<S> {
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Outgoing) -> Result<(), S::Error> {
+        let this = self.get_mut();
+        let frame = item.to_frame(&this.stream_sid);
+        Pin::new(&mut this.inner).start_send(frame)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+const MULAW_BIAS: i32 = 0x84;
+const MULAW_CLIP: i32 = 32635;
+
+/// Converts G.711 μ-law bytes to 16-bit linear PCM.
+pub fn decode_mulaw(audio: &[u8]) -> Vec<i16> {
+    audio
+        .iter()
+        .map(|&b| {
+            let b = !b;
+            let exponent = (b >> 4) & 0x07;
+            let mantissa = (b & 0x0F) as i32;
+            let magnitude = (((mantissa << 3) + MULAW_BIAS) << exponent) - MULAW_BIAS;
+            if b & 0x80 != 0 {
+                -magnitude as i16
+            } else {
+                magnitude as i16
+            }
+        })
+        .collect()
+}
+
+/// Converts 16-bit linear PCM to G.711 μ-law bytes.
+pub fn encode_mulaw(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .map(|&s| {
+            let mut s = s as i32;
+            let sign = if s < 0 {
+                s = -s;
+                0x80
+            } else {
+                0
+            };
+            s = s.min(MULAW_CLIP) + MULAW_BIAS;
+            let mut exponent = 7;
+            while exponent > 0 && s & (0x80 << exponent) == 0 {
+                exponent -= 1;
+            }
+            let mantissa = (s >> (exponent + 3)) & 0x0F;
+            !(sign | (exponent << 4) | mantissa) as u8
+        })
+        .collect()
+}
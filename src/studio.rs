@@ -0,0 +1,153 @@
+//! [Studio](https://www.twilio.com/docs/studio/rest-api/v2) Flow executions,
+//! for starting prebuilt IVR and messaging flows from code.
+
+use crate::endpoint::STUDIO;
+use crate::{
+    AccountSid, Client, ExecutionSid, FlowSid, PageIterator, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExecutionStatus {
+    Active,
+    Ended,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Execution {
+    pub sid: ExecutionSid,
+    pub account_sid: AccountSid,
+    pub flow_sid: FlowSid,
+    pub contact_channel_address: Option<String>,
+    pub status: ExecutionStatus,
+    pub context: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// The variables an execution has accumulated so far: the trigger's
+/// parameters under `trigger`, and each widget's output under `widgets`.
+#[derive(Debug, Deserialize)]
+pub struct ExecutionContext {
+    pub account_sid: AccountSid,
+    pub flow_sid: FlowSid,
+    pub execution_sid: ExecutionSid,
+    pub context: serde_json::Value,
+    pub url: Option<String>,
+}
+
+/// Filters accepted by `Client::list_executions`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`.
+#[derive(Default)]
+pub struct ExecutionFilter<'a> {
+    pub date_created_from: Option<&'a str>,
+    pub date_created_to: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+impl Client {
+    /// Starts an execution of `flow_sid` for the contact at `to`, reached from
+    /// your number `from`. `parameters` are available to the flow as
+    /// `{{flow.data}}`.
+    pub async fn create_execution(
+        &self,
+        flow_sid: &FlowSid,
+        to: &str,
+        from: &str,
+        parameters: Option<&serde_json::Value>,
+    ) -> Result<Execution, TwilioError> {
+        let parameters = parameters.map(|p| p.to_string());
+        let mut opts = vec![("To", to), ("From", from)];
+        if let Some(ref p) = parameters {
+            opts.push(("Parameters", p));
+        }
+        self.send_product_request(
+            POST,
+            &STUDIO,
+            &format!("Flows/{flow_sid}/Executions"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_executions(
+        &self,
+        flow_sid: &FlowSid,
+        filter: &ExecutionFilter<'_>,
+    ) -> PageIterator<Execution> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(from) = filter.date_created_from {
+            opts.push(("DateCreatedFrom", from));
+        }
+        if let Some(to) = filter.date_created_to {
+            opts.push(("DateCreatedTo", to));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list_product(
+            &STUDIO,
+            &format!("Flows/{flow_sid}/Executions"),
+            "executions",
+            &opts,
+        )
+    }
+
+    pub async fn retrieve_execution(
+        &self,
+        flow_sid: &FlowSid,
+        sid: &ExecutionSid,
+    ) -> Result<Execution, TwilioError> {
+        self.send_product_request(
+            GET,
+            &STUDIO,
+            &format!("Flows/{flow_sid}/Executions/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn retrieve_execution_context(
+        &self,
+        flow_sid: &FlowSid,
+        sid: &ExecutionSid,
+    ) -> Result<ExecutionContext, TwilioError> {
+        self.send_product_request(
+            GET,
+            &STUDIO,
+            &format!("Flows/{flow_sid}/Executions/{sid}/Context"),
+            &[],
+        )
+        .await
+    }
+
+    /// Ends an active execution. Any call or message it started is left alone.
+    pub async fn stop_execution(
+        &self,
+        flow_sid: &FlowSid,
+        sid: &ExecutionSid,
+    ) -> Result<Execution, TwilioError> {
+        self.send_product_request(
+            POST,
+            &STUDIO,
+            &format!("Flows/{flow_sid}/Executions/{sid}"),
+            &[("Status", "ended")],
+        )
+        .await
+    }
+
+    pub async fn delete_execution(
+        &self,
+        flow_sid: &FlowSid,
+        sid: &ExecutionSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&STUDIO, &format!("Flows/{flow_sid}/Executions/{sid}"))
+            .await
+    }
+}
@@ -0,0 +1,439 @@
+//! [Sync](https://www.twilio.com/docs/sync/api), for keeping state in step
+//! with front-end Sync clients. Documents, lists and maps are addressed by
+//! sid or by unique name, and carry `data` of any type that round-trips
+//! through JSON.
+
+use crate::endpoint::SYNC;
+use crate::{
+    AccountSid, Client, PageIterator, SyncDocumentSid, SyncListSid, SyncMapSid, SyncServiceSid,
+    Timestamp, TwilioError, GET, POST,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: SyncServiceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    pub unique_name: Option<String>,
+    pub webhook_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Document<T> {
+    pub sid: SyncDocumentSid,
+    pub account_sid: AccountSid,
+    pub service_sid: SyncServiceSid,
+    pub unique_name: Option<String>,
+    pub data: T,
+    pub revision: String,
+    pub created_by: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct List {
+    pub sid: SyncListSid,
+    pub account_sid: AccountSid,
+    pub service_sid: SyncServiceSid,
+    pub unique_name: Option<String>,
+    pub revision: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListItem<T> {
+    pub index: u64,
+    pub account_sid: AccountSid,
+    pub service_sid: SyncServiceSid,
+    pub list_sid: SyncListSid,
+    pub data: T,
+    pub revision: String,
+    pub created_by: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Map {
+    pub sid: SyncMapSid,
+    pub account_sid: AccountSid,
+    pub service_sid: SyncServiceSid,
+    pub unique_name: Option<String>,
+    pub revision: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MapItem<T> {
+    pub key: String,
+    pub account_sid: AccountSid,
+    pub service_sid: SyncServiceSid,
+    pub map_sid: SyncMapSid,
+    pub data: T,
+    pub revision: String,
+    pub created_by: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_expires: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+fn to_json<T: Serialize>(data: &T) -> Result<String, TwilioError> {
+    serde_json::to_string(data).map_err(TwilioError::ParsingError)
+}
+
+impl Client {
+    pub async fn create_sync_service(
+        &self,
+        friendly_name: Option<&str>,
+    ) -> Result<Service, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        self.send_product_request(POST, &SYNC, "Services", &opts)
+            .await
+    }
+
+    pub fn list_sync_services(&self) -> PageIterator<Service> {
+        self.list_product(&SYNC, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_sync_service(
+        &self,
+        sid: &SyncServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &SYNC, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_sync_service(&self, sid: &SyncServiceSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&SYNC, &format!("Services/{sid}"))
+            .await
+    }
+
+    /// Creates a document holding `data`. With `ttl`, in seconds, the document
+    /// is deleted once it expires.
+    pub async fn create_document<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        unique_name: Option<&str>,
+        data: &T,
+        ttl: Option<u32>,
+    ) -> Result<Document<T>, TwilioError> {
+        let data = to_json(data)?;
+        let ttl = ttl.map(|t| t.to_string());
+        let mut opts = vec![("Data", data.as_str())];
+        if let Some(name) = unique_name {
+            opts.push(("UniqueName", name));
+        }
+        if let Some(ref t) = ttl {
+            opts.push(("Ttl", t));
+        }
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Documents"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_documents<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+    ) -> PageIterator<Document<T>> {
+        self.list_product(
+            &SYNC,
+            &format!("Services/{service_sid}/Documents"),
+            "documents",
+            &[],
+        )
+    }
+
+    /// Fetches a document by sid or unique name.
+    pub async fn retrieve_document<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        document: &str,
+    ) -> Result<Document<T>, TwilioError> {
+        self.send_product_request(
+            GET,
+            &SYNC,
+            &format!("Services/{service_sid}/Documents/{document}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Replaces a document's data.
+    pub async fn update_document<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        document: &str,
+        data: &T,
+    ) -> Result<Document<T>, TwilioError> {
+        let data = to_json(data)?;
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Documents/{document}"),
+            &[("Data", &data)],
+        )
+        .await
+    }
+
+    pub async fn delete_document(
+        &self,
+        service_sid: &SyncServiceSid,
+        document: &str,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &SYNC,
+            &format!("Services/{service_sid}/Documents/{document}"),
+        )
+        .await
+    }
+
+    pub async fn create_sync_list(
+        &self,
+        service_sid: &SyncServiceSid,
+        unique_name: Option<&str>,
+    ) -> Result<List, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = unique_name {
+            opts.push(("UniqueName", name));
+        }
+        self.send_product_request(POST, &SYNC, &format!("Services/{service_sid}/Lists"), &opts)
+            .await
+    }
+
+    pub fn list_sync_lists(&self, service_sid: &SyncServiceSid) -> PageIterator<List> {
+        self.list_product(
+            &SYNC,
+            &format!("Services/{service_sid}/Lists"),
+            "lists",
+            &[],
+        )
+    }
+
+    pub async fn delete_sync_list(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&SYNC, &format!("Services/{service_sid}/Lists/{list}"))
+            .await
+    }
+
+    /// Appends `data` to the end of a list.
+    pub async fn add_list_item<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+        data: &T,
+    ) -> Result<ListItem<T>, TwilioError> {
+        let data = to_json(data)?;
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Lists/{list}/Items"),
+            &[("Data", &data)],
+        )
+        .await
+    }
+
+    pub fn list_list_items<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+    ) -> PageIterator<ListItem<T>> {
+        self.list_product(
+            &SYNC,
+            &format!("Services/{service_sid}/Lists/{list}/Items"),
+            "items",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_list_item<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+        index: u64,
+    ) -> Result<ListItem<T>, TwilioError> {
+        self.send_product_request(
+            GET,
+            &SYNC,
+            &format!("Services/{service_sid}/Lists/{list}/Items/{index}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn update_list_item<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+        index: u64,
+        data: &T,
+    ) -> Result<ListItem<T>, TwilioError> {
+        let data = to_json(data)?;
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Lists/{list}/Items/{index}"),
+            &[("Data", &data)],
+        )
+        .await
+    }
+
+    pub async fn remove_list_item(
+        &self,
+        service_sid: &SyncServiceSid,
+        list: &str,
+        index: u64,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &SYNC,
+            &format!("Services/{service_sid}/Lists/{list}/Items/{index}"),
+        )
+        .await
+    }
+
+    pub async fn create_sync_map(
+        &self,
+        service_sid: &SyncServiceSid,
+        unique_name: Option<&str>,
+    ) -> Result<Map, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(name) = unique_name {
+            opts.push(("UniqueName", name));
+        }
+        self.send_product_request(POST, &SYNC, &format!("Services/{service_sid}/Maps"), &opts)
+            .await
+    }
+
+    pub fn list_sync_maps(&self, service_sid: &SyncServiceSid) -> PageIterator<Map> {
+        self.list_product(&SYNC, &format!("Services/{service_sid}/Maps"), "maps", &[])
+    }
+
+    pub async fn delete_sync_map(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(&SYNC, &format!("Services/{service_sid}/Maps/{map}"))
+            .await
+    }
+
+    /// Adds `data` under `key`. Twilio rejects keys that already exist; use
+    /// `update_map_item` to change them.
+    pub async fn create_map_item<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+        key: &str,
+        data: &T,
+    ) -> Result<MapItem<T>, TwilioError> {
+        let data = to_json(data)?;
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Maps/{map}/Items"),
+            &[("Key", key), ("Data", &data)],
+        )
+        .await
+    }
+
+    pub fn list_map_items<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+    ) -> PageIterator<MapItem<T>> {
+        self.list_product(
+            &SYNC,
+            &format!("Services/{service_sid}/Maps/{map}/Items"),
+            "items",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_map_item<T: DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+        key: &str,
+    ) -> Result<MapItem<T>, TwilioError> {
+        self.send_product_request(
+            GET,
+            &SYNC,
+            &format!("Services/{service_sid}/Maps/{map}/Items/{key}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn update_map_item<T: Serialize + DeserializeOwned>(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+        key: &str,
+        data: &T,
+    ) -> Result<MapItem<T>, TwilioError> {
+        let data = to_json(data)?;
+        self.send_product_request(
+            POST,
+            &SYNC,
+            &format!("Services/{service_sid}/Maps/{map}/Items/{key}"),
+            &[("Data", &data)],
+        )
+        .await
+    }
+
+    pub async fn remove_map_item(
+        &self,
+        service_sid: &SyncServiceSid,
+        map: &str,
+        key: &str,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &SYNC,
+            &format!("Services/{service_sid}/Maps/{map}/Items/{key}"),
+        )
+        .await
+    }
+}
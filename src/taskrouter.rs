@@ -0,0 +1,529 @@
+//! [TaskRouter](https://www.twilio.com/docs/taskrouter/api), which routes
+//! tasks such as calls and chats to available workers. Everything lives
+//! under a `Workspace`.
+
+use crate::endpoint::TASKROUTER;
+use crate::{
+    from_params, AccountSid, ActivitySid, Client, FromMap, PageIterator, ReservationSid,
+    TaskQueueSid, TaskSid, Timestamp, TwilioError, WorkerSid, WorkflowSid, WorkspaceSid, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize)]
+pub struct Workspace {
+    pub sid: WorkspaceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    pub event_callback_url: Option<String>,
+    pub default_activity_sid: Option<ActivitySid>,
+    pub timeout_activity_sid: Option<ActivitySid>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A state a worker can be in, such as `Available` or `Offline`.
+#[derive(Debug, Deserialize)]
+pub struct Activity {
+    pub sid: ActivitySid,
+    pub account_sid: AccountSid,
+    pub workspace_sid: WorkspaceSid,
+    pub friendly_name: String,
+    /// Whether workers in this activity can be assigned tasks.
+    pub available: bool,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Worker {
+    pub sid: WorkerSid,
+    pub account_sid: AccountSid,
+    pub workspace_sid: WorkspaceSid,
+    pub friendly_name: String,
+    pub activity_sid: Option<ActivitySid>,
+    pub activity_name: Option<String>,
+    pub available: bool,
+    /// A JSON string matched against workflow and queue expressions.
+    pub attributes: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_status_changed: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TaskQueue {
+    pub sid: TaskQueueSid,
+    pub account_sid: AccountSid,
+    pub workspace_sid: WorkspaceSid,
+    pub friendly_name: String,
+    /// The expression a worker's attributes must match to serve this queue.
+    pub target_workers: Option<String>,
+    pub max_reserved_workers: Option<u32>,
+    pub task_order: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Workflow {
+    pub sid: WorkflowSid,
+    pub account_sid: AccountSid,
+    pub workspace_sid: WorkspaceSid,
+    pub friendly_name: String,
+    /// The routing rules, as a JSON string.
+    pub configuration: String,
+    pub assignment_callback_url: Option<String>,
+    pub fallback_assignment_callback_url: Option<String>,
+    pub task_reservation_timeout: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentStatus {
+    Pending,
+    Reserved,
+    Assigned,
+    Canceled,
+    Wrapping,
+    Completed,
+}
+
+impl AssignmentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            AssignmentStatus::Pending => "pending",
+            AssignmentStatus::Reserved => "reserved",
+            AssignmentStatus::Assigned => "assigned",
+            AssignmentStatus::Canceled => "canceled",
+            AssignmentStatus::Wrapping => "wrapping",
+            AssignmentStatus::Completed => "completed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Task {
+    pub sid: TaskSid,
+    pub account_sid: AccountSid,
+    pub workspace_sid: WorkspaceSid,
+    pub workflow_sid: Option<WorkflowSid>,
+    pub task_queue_sid: Option<TaskQueueSid>,
+    pub assignment_status: AssignmentStatus,
+    pub attributes: String,
+    pub priority: Option<u32>,
+    /// Seconds since the task was created.
+    pub age: Option<u32>,
+    pub timeout: Option<u32>,
+    pub reason: Option<String>,
+    pub task_channel_unique_name: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A task to route through `workflow_sid`. `attributes` is a JSON string,
+/// e.g. `{"type": "support"}`.
+pub struct NewTask<'a> {
+    pub workflow_sid: &'a WorkflowSid,
+    pub attributes: &'a str,
+    pub priority: Option<u32>,
+    pub timeout: Option<u32>,
+    /// The channel, e.g. `"voice"` or `"chat"`. Defaults to `"default"`.
+    pub task_channel: Option<&'a str>,
+}
+
+impl<'a> NewTask<'a> {
+    pub fn new(workflow_sid: &'a WorkflowSid, attributes: &'a str) -> NewTask<'a> {
+        NewTask {
+            workflow_sid,
+            attributes,
+            priority: None,
+            timeout: None,
+            task_channel: None,
+        }
+    }
+}
+
+/// Filters accepted by `Client::list_tasks`.
+#[derive(Default)]
+pub struct TaskFilter<'a> {
+    pub assignment_status: Option<AssignmentStatus>,
+    pub task_queue_sid: Option<&'a TaskQueueSid>,
+    pub workflow_sid: Option<&'a WorkflowSid>,
+    /// An expression over task attributes, e.g. `type == "support"`.
+    pub evaluate_task_attributes: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+/// An event posted to a workspace's `event_callback_url`. Which fields are
+/// set depends on `resource_type`; anything without a dedicated field is
+/// kept in `extra`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Event {
+    pub event_type: String,
+    pub event_description: Option<String>,
+    pub resource_type: Option<String>,
+    pub resource_sid: Option<String>,
+    pub timestamp: Option<String>,
+    pub account_sid: Option<AccountSid>,
+    pub workspace_sid: Option<WorkspaceSid>,
+    pub workspace_name: Option<String>,
+    pub task_sid: Option<TaskSid>,
+    pub task_attributes: Option<String>,
+    pub task_assignment_status: Option<AssignmentStatus>,
+    pub task_queue_sid: Option<TaskQueueSid>,
+    pub workflow_sid: Option<WorkflowSid>,
+    pub worker_sid: Option<WorkerSid>,
+    pub worker_name: Option<String>,
+    pub worker_attributes: Option<String>,
+    pub worker_activity_sid: Option<ActivitySid>,
+    pub worker_activity_name: Option<String>,
+    pub reservation_sid: Option<ReservationSid>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl FromMap for Event {
+    fn from_map(m: BTreeMap<String, String>) -> Result<Box<Event>, TwilioError> {
+        from_params(m)
+    }
+}
+
+impl Client {
+    pub async fn create_workspace(
+        &self,
+        friendly_name: &str,
+        event_callback_url: Option<&str>,
+    ) -> Result<Workspace, TwilioError> {
+        let mut opts = vec![("FriendlyName", friendly_name)];
+        if let Some(url) = event_callback_url {
+            opts.push(("EventCallbackUrl", url));
+        }
+        self.send_product_request(POST, &TASKROUTER, "Workspaces", &opts)
+            .await
+    }
+
+    pub fn list_workspaces(&self) -> PageIterator<Workspace> {
+        self.list_product(&TASKROUTER, "Workspaces", "workspaces", &[])
+    }
+
+    pub async fn retrieve_workspace(&self, sid: &WorkspaceSid) -> Result<Workspace, TwilioError> {
+        self.send_product_request(GET, &TASKROUTER, &format!("Workspaces/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_workspace(&self, sid: &WorkspaceSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&TASKROUTER, &format!("Workspaces/{sid}"))
+            .await
+    }
+
+    pub fn list_activities(&self, workspace_sid: &WorkspaceSid) -> PageIterator<Activity> {
+        self.list_product(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Activities"),
+            "activities",
+            &[],
+        )
+    }
+
+    pub async fn create_worker(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        friendly_name: &str,
+        attributes: Option<&str>,
+        activity_sid: Option<&ActivitySid>,
+    ) -> Result<Worker, TwilioError> {
+        let mut opts = vec![("FriendlyName", friendly_name)];
+        if let Some(attributes) = attributes {
+            opts.push(("Attributes", attributes));
+        }
+        if let Some(sid) = activity_sid {
+            opts.push(("ActivitySid", sid.as_str()));
+        }
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workers"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_workers(&self, workspace_sid: &WorkspaceSid) -> PageIterator<Worker> {
+        self.list_product(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workers"),
+            "workers",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_worker(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &WorkerSid,
+    ) -> Result<Worker, TwilioError> {
+        self.send_product_request(
+            GET,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workers/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Moves a worker to another activity, e.g. from `Offline` to
+    /// `Available`.
+    pub async fn update_worker_activity(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &WorkerSid,
+        activity_sid: &ActivitySid,
+    ) -> Result<Worker, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workers/{sid}"),
+            &[("ActivitySid", activity_sid.as_str())],
+        )
+        .await
+    }
+
+    pub async fn delete_worker(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &WorkerSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workers/{sid}"),
+        )
+        .await
+    }
+
+    pub async fn create_task_queue(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        friendly_name: &str,
+        target_workers: Option<&str>,
+    ) -> Result<TaskQueue, TwilioError> {
+        let mut opts = vec![("FriendlyName", friendly_name)];
+        if let Some(target) = target_workers {
+            opts.push(("TargetWorkers", target));
+        }
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/TaskQueues"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_task_queues(&self, workspace_sid: &WorkspaceSid) -> PageIterator<TaskQueue> {
+        self.list_product(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/TaskQueues"),
+            "task_queues",
+            &[],
+        )
+    }
+
+    pub async fn delete_task_queue(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &TaskQueueSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/TaskQueues/{sid}"),
+        )
+        .await
+    }
+
+    /// Creates a workflow from its JSON `configuration`. Reservations are
+    /// posted to `assignment_callback_url` when set.
+    pub async fn create_workflow(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        friendly_name: &str,
+        configuration: &str,
+        assignment_callback_url: Option<&str>,
+    ) -> Result<Workflow, TwilioError> {
+        let mut opts = vec![
+            ("FriendlyName", friendly_name),
+            ("Configuration", configuration),
+        ];
+        if let Some(url) = assignment_callback_url {
+            opts.push(("AssignmentCallbackUrl", url));
+        }
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workflows"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_workflows(&self, workspace_sid: &WorkspaceSid) -> PageIterator<Workflow> {
+        self.list_product(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workflows"),
+            "workflows",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_workflow(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &WorkflowSid,
+    ) -> Result<Workflow, TwilioError> {
+        self.send_product_request(
+            GET,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workflows/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    pub async fn delete_workflow(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &WorkflowSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Workflows/{sid}"),
+        )
+        .await
+    }
+
+    pub async fn create_task(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        task: NewTask<'_>,
+    ) -> Result<Task, TwilioError> {
+        let priority = task.priority.map(|p| p.to_string());
+        let timeout = task.timeout.map(|t| t.to_string());
+        let mut opts = vec![
+            ("WorkflowSid", task.workflow_sid.as_str()),
+            ("Attributes", task.attributes),
+        ];
+        if let Some(ref p) = priority {
+            opts.push(("Priority", p));
+        }
+        if let Some(ref t) = timeout {
+            opts.push(("Timeout", t));
+        }
+        if let Some(channel) = task.task_channel {
+            opts.push(("TaskChannel", channel));
+        }
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Tasks"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_tasks(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        filter: &TaskFilter<'_>,
+    ) -> PageIterator<Task> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref status) = filter.assignment_status {
+            opts.push(("AssignmentStatus", status.as_str()));
+        }
+        if let Some(sid) = filter.task_queue_sid {
+            opts.push(("TaskQueueSid", sid.as_str()));
+        }
+        if let Some(sid) = filter.workflow_sid {
+            opts.push(("WorkflowSid", sid.as_str()));
+        }
+        if let Some(expression) = filter.evaluate_task_attributes {
+            opts.push(("EvaluateTaskAttributes", expression));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list_product(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Tasks"),
+            "tasks",
+            &opts,
+        )
+    }
+
+    pub async fn retrieve_task(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &TaskSid,
+    ) -> Result<Task, TwilioError> {
+        self.send_product_request(
+            GET,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Tasks/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Moves a task along its lifecycle, e.g. to `Canceled` or `Completed`.
+    /// `reason` is recorded on the task.
+    pub async fn update_task_status(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &TaskSid,
+        status: AssignmentStatus,
+        reason: Option<&str>,
+    ) -> Result<Task, TwilioError> {
+        let mut opts = vec![("AssignmentStatus", status.as_str())];
+        if let Some(reason) = reason {
+            opts.push(("Reason", reason));
+        }
+        self.send_product_request(
+            POST,
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Tasks/{sid}"),
+            &opts,
+        )
+        .await
+    }
+
+    pub async fn delete_task(
+        &self,
+        workspace_sid: &WorkspaceSid,
+        sid: &TaskSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TASKROUTER,
+            &format!("Workspaces/{workspace_sid}/Tasks/{sid}"),
+        )
+        .await
+    }
+}
@@ -0,0 +1,51 @@
+//! Timers for retries and request timeouts that work on any executor: tokio's
+//! when running inside a tokio runtime, so `tokio::time::pause` still applies,
+//! and `futures-timer` otherwise. tokio is only compiled in with the backends
+//! that need it, so wasm32 builds go without.
+
+// tokio's `Instant` reads the paused clock inside a runtime and falls back to
+// `std::time::Instant` outside of one.
+#[cfg(any(feature = "native-tls", feature = "blocking"))]
+pub(crate) use tokio::time::Instant;
+#[cfg(not(any(feature = "native-tls", feature = "blocking")))]
+pub(crate) use web_time::Instant;
+// `std::time::SystemTime::now` panics on wasm32; `web_time` reads the clock
+// through JavaScript there and is `std`'s everywhere else.
+pub(crate) use web_time::{SystemTime, UNIX_EPOCH};
+
+use std::future::Future;
+use std::task::Poll;
+use std::time::Duration;
+
+#[cfg(any(feature = "native-tls", feature = "blocking"))]
+fn on_tokio() -> bool {
+    tokio::runtime::Handle::try_current().is_ok()
+}
+
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(any(feature = "native-tls", feature = "blocking"))]
+    if on_tokio() {
+        return tokio::time::sleep(duration).await;
+    }
+    futures_timer::Delay::new(duration).await
+}
+
+/// Runs `future`, giving up with `None` once `duration` has passed.
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    #[cfg(any(feature = "native-tls", feature = "blocking"))]
+    if on_tokio() {
+        return tokio::time::timeout(duration, future).await.ok();
+    }
+    let mut future = Box::pin(future);
+    let mut delay = futures_timer::Delay::new(duration);
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(out) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(out));
+        }
+        match std::pin::Pin::new(&mut delay).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+    .await
+}
@@ -0,0 +1,39 @@
+//! Timestamps in resources. The 2010-04-01 API sends RFC 2822 dates, e.g.
+//! `Mon, 16 Aug 2010 03:45:01 +0000`, while the product APIs send ISO 8601.
+//! With the `time` feature both are parsed into `time::OffsetDateTime`;
+//! otherwise they are kept as the strings Twilio sent.
+
+/// A timestamp on a resource: `time::OffsetDateTime` with the `time`
+/// feature, otherwise the string Twilio sent.
+#[cfg(feature = "time")]
+pub type Timestamp = time::OffsetDateTime;
+#[cfg(not(feature = "time"))]
+pub type Timestamp = String;
+
+/// Deserializes an optional timestamp in either format. Empty strings, which
+/// Twilio sends for e.g. a queued call's `start_time`, are treated as missing.
+pub(crate) fn option<'de, D>(d: D) -> Result<Option<Timestamp>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    match Option::<String>::deserialize(d)? {
+        Some(s) if !s.is_empty() => parse(s).map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(feature = "time")]
+fn parse<E: serde::de::Error>(s: String) -> Result<Timestamp, E> {
+    use time::format_description::well_known::{Rfc2822, Rfc3339};
+
+    time::OffsetDateTime::parse(&s, &Rfc2822)
+        .or_else(|_| time::OffsetDateTime::parse(&s, &Rfc3339))
+        .map_err(|_| E::custom(format!("invalid timestamp {s:?}")))
+}
+
+#[cfg(not(feature = "time"))]
+fn parse<E: serde::de::Error>(s: String) -> Result<Timestamp, E> {
+    Ok(s)
+}
@@ -0,0 +1,60 @@
+use crate::{
+    AccountSid, Client, Money, PageIterator, RecordingSid, Timestamp, TranscriptionSid,
+    TwilioError, GET,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TranscriptionStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Transcription {
+    pub sid: TranscriptionSid,
+    pub account_sid: AccountSid,
+    pub recording_sid: RecordingSid,
+    pub status: TranscriptionStatus,
+    pub transcription_text: Option<String>,
+    pub duration: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub uri: Option<String>,
+}
+
+impl Client {
+    pub fn list_transcriptions(&self) -> PageIterator<Transcription> {
+        self.list("Transcriptions", "transcriptions", &[])
+    }
+
+    /// Lists the transcriptions made from a single recording.
+    pub fn list_recording_transcriptions(
+        &self,
+        recording_sid: &RecordingSid,
+    ) -> PageIterator<Transcription> {
+        self.list(
+            &format!("Recordings/{recording_sid}/Transcriptions"),
+            "transcriptions",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_transcription(
+        &self,
+        sid: &TranscriptionSid,
+    ) -> Result<Transcription, TwilioError> {
+        self.send_request(GET, &format!("Transcriptions/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_transcription(&self, sid: &TranscriptionSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Transcriptions/{sid}")).await
+    }
+}
@@ -0,0 +1,182 @@
+use crate::TwilioError;
+use hyper::client::connect::Connect;
+use hyper::header::{HeaderName, HeaderValue};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use url::form_urlencoded;
+
+#[cfg(feature = "isahc")]
+mod isahc;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(feature = "isahc")]
+pub use self::isahc::IsahcTransport;
+#[cfg(feature = "reqwest")]
+pub use self::reqwest::ReqwestTransport;
+
+pub type TransportFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, TwilioError>> + Send>>;
+
+/// Sends the HTTP requests a `Client` makes. `hyper::Client` implements this,
+/// and `ClientBuilder::transport` accepts any other implementation, such as
+/// `MockTransport` in tests.
+///
+/// Retries and timeouts are handled by the `Client`, so a transport only needs
+/// to send one request. Return `TwilioError::NetworkError` or
+/// `TwilioError::Timeout` for failures that should be retried.
+pub trait Transport: Send + Sync {
+    fn send(&self, req: Request<Body>) -> TransportFuture;
+}
+
+impl<C> Transport for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send(&self, req: Request<Body>) -> TransportFuture {
+        let resp = self.request(req);
+        Box::pin(async move { resp.await.map_err(TwilioError::NetworkError) })
+    }
+}
+
+#[cfg(any(feature = "isahc", feature = "reqwest"))]
+pub(crate) fn transport_error(e: impl std::error::Error + Send + Sync + 'static) -> TwilioError {
+    TwilioError::TransportError(Box::new(e))
+}
+
+/// A request captured by `MockTransport`.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub uri: String,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// The decoded form parameters, from the body or, for a `GET`, the query.
+    pub fn params(&self) -> BTreeMap<String, String> {
+        let encoded = if self.method == Method::GET {
+            self.uri.split_once('?').map(|(_, q)| q).unwrap_or("")
+        } else {
+            &self.body
+        };
+        form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    /// The request path, without the host or query.
+    pub fn path(&self) -> &str {
+        let path = match self.uri.find("://") {
+            Some(i) => {
+                let rest = &self.uri[i + 3..];
+                rest.find('/').map(|j| &rest[j..]).unwrap_or("/")
+            }
+            None => &self.uri,
+        };
+        path.split('?').next().unwrap_or(path)
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: VecDeque<(StatusCode, HeaderMap, String)>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// A `Transport` that records every request and answers with canned
+/// responses, in the order they were queued. Clones share their state, so
+/// keep one to inspect after handing another to the client:
+///
+/// ```ignore
+/// let mock = MockTransport::new();
+/// mock.respond(StatusCode::CREATED, r#"{"sid": "SM..."}"#);
+/// let client = Client::builder(ACCOUNT_ID, AUTH_TOKEN)
+///     .transport(mock.clone())
+///     .build()?;
+/// client.send_message(msg).await?;
+/// assert_eq!(mock.requests()[0].params()["To"], "+15558675310");
+/// ```
+///
+/// Requests made once the queue is empty get a 404 with a Twilio error body.
+/// Queued 5xx and 429 responses are retried by the client's `RetryPolicy`
+/// like real ones, so use `RetryPolicy::none()` to see them directly.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    /// Queues a response with the given status and JSON body.
+    pub fn respond(&self, status: StatusCode, body: &str) -> &MockTransport {
+        self.respond_with_headers(status, &[], body)
+    }
+
+    /// Queues a response with headers, such as a redirect's `Location` or the
+    /// `Content-Type` of downloaded media.
+    pub fn respond_with_headers(
+        &self,
+        status: StatusCode,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> &MockTransport {
+        let headers = headers
+            .iter()
+            .map(|(k, v)| {
+                (
+                    HeaderName::from_bytes(k.as_bytes()).expect("invalid header name"),
+                    HeaderValue::from_str(v).unwrap(),
+                )
+            })
+            .collect();
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back((status, headers, body.to_string()));
+        self
+    }
+
+    /// Every request sent so far, oldest first.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, req: Request<Body>) -> TransportFuture {
+        let state = self.state.clone();
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let body = hyper::body::to_bytes(body)
+                .await
+                .map_err(TwilioError::NetworkError)?;
+            let mut state = state.lock().unwrap();
+            state.requests.push(RecordedRequest {
+                method: parts.method,
+                uri: parts.uri.to_string(),
+                headers: parts.headers,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            });
+            let (status, headers, body) = state.responses.pop_front().unwrap_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    HeaderMap::new(),
+                    r#"{"code": 20404, "message": "No mock response queued", "status": 404}"#
+                        .to_string(),
+                )
+            });
+            let mut resp = Response::new(Body::from(body));
+            *resp.status_mut() = status;
+            *resp.headers_mut() = headers;
+            Ok(resp)
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::transport::{transport_error, Transport, TransportFuture};
+use crate::TwilioError;
+use ::isahc::config::Configurable;
+use ::isahc::{AsyncReadResponseExt, HttpClient};
+use hyper::{Body, Request, Response};
+use std::time::Duration;
+
+/// Sends requests with [isahc](https://github.com/sagebind/isahc), which
+/// drives curl on its own thread and so works with any executor. This is the
+/// default transport when the `isahc` feature is enabled without `native-tls`.
+#[derive(Clone)]
+pub struct IsahcTransport {
+    client: HttpClient,
+}
+
+impl IsahcTransport {
+    pub fn new() -> IsahcTransport {
+        IsahcTransport::with_connect_timeout(None).expect("failed to initialize isahc")
+    }
+
+    pub(crate) fn with_connect_timeout(
+        timeout: Option<Duration>,
+    ) -> Result<IsahcTransport, isahc::Error> {
+        let mut builder = HttpClient::builder();
+        if let Some(t) = timeout {
+            builder = builder.connect_timeout(t);
+        }
+        Ok(IsahcTransport {
+            client: builder.build()?,
+        })
+    }
+}
+
+impl Default for IsahcTransport {
+    fn default() -> IsahcTransport {
+        IsahcTransport::new()
+    }
+}
+
+/// Uses an `HttpClient` you have configured yourself.
+impl From<HttpClient> for IsahcTransport {
+    fn from(client: HttpClient) -> IsahcTransport {
+        IsahcTransport { client }
+    }
+}
+
+impl Transport for IsahcTransport {
+    fn send(&self, req: Request<Body>) -> TransportFuture {
+        let client = self.client.clone();
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let body = hyper::body::to_bytes(body)
+                .await
+                .map_err(TwilioError::NetworkError)?;
+            let req = Request::from_parts(parts, body.to_vec());
+            let mut resp = client.send_async(req).await.map_err(transport_error)?;
+            let bytes = resp.bytes().await.map_err(transport_error)?;
+            let (parts, _) = resp.into_parts();
+            Ok(Response::from_parts(parts, Body::from(bytes)))
+        })
+    }
+}
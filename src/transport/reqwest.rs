@@ -0,0 +1,72 @@
+use crate::transport::{transport_error, Transport, TransportFuture};
+use crate::TwilioError;
+use hyper::{Body, Request, Response};
+use std::time::Duration;
+
+/// Sends requests with [reqwest](https://github.com/seanmonstar/reqwest). On
+/// `wasm32` this uses the browser's `fetch`, so the client works in Workers
+/// and web pages; elsewhere it connects with native-tls.
+#[derive(Clone, Default)]
+pub struct ReqwestTransport {
+    client: ::reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> ReqwestTransport {
+        ReqwestTransport::with_connect_timeout(None).expect("failed to initialize reqwest")
+    }
+
+    /// `timeout` is ignored on `wasm32`, where `fetch` manages connections.
+    pub(crate) fn with_connect_timeout(
+        timeout: Option<Duration>,
+    ) -> Result<ReqwestTransport, ::reqwest::Error> {
+        #[allow(unused_mut)]
+        let mut builder = ::reqwest::Client::builder();
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(t) = timeout {
+            builder = builder.connect_timeout(t);
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = timeout;
+        Ok(ReqwestTransport {
+            client: builder.build()?,
+        })
+    }
+}
+
+/// Uses a `reqwest::Client` you have configured yourself.
+impl From<::reqwest::Client> for ReqwestTransport {
+    fn from(client: ::reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, req: Request<Body>) -> TransportFuture {
+        let client = self.client.clone();
+        let fut = async move {
+            let (parts, body) = req.into_parts();
+            let body = hyper::body::to_bytes(body)
+                .await
+                .map_err(TwilioError::NetworkError)?;
+            let resp = client
+                .request(parts.method, parts.uri.to_string())
+                .headers(parts.headers)
+                .body(body.to_vec())
+                .send()
+                .await
+                .map_err(transport_error)?;
+            let status = resp.status();
+            let headers = resp.headers().clone();
+            let bytes = resp.bytes().await.map_err(transport_error)?;
+            let mut out = Response::new(Body::from(bytes));
+            *out.status_mut() = status;
+            *out.headers_mut() = headers;
+            Ok(out)
+        };
+        // wasm32 is single threaded, and fetch futures are not `Send`.
+        #[cfg(target_arch = "wasm32")]
+        let fut = send_wrapper::SendWrapper::new(fut);
+        Box::pin(fut)
+    }
+}
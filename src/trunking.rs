@@ -0,0 +1,302 @@
+//! [Elastic SIP Trunking](https://www.twilio.com/docs/sip-trunking/api),
+//! for connecting your own SIP infrastructure to the PSTN through Twilio.
+
+use crate::endpoint::TRUNKING;
+use crate::twiml::Method;
+use crate::{
+    bool_str, AccountSid, Client, CredentialList, CredentialListSid, IncomingPhoneNumber,
+    IpAccessControlList, IpAccessControlListSid, OriginationUrlSid, PageIterator, PhoneNumberSid,
+    Timestamp, TrunkSid, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Trunk {
+    pub sid: TrunkSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    /// The termination domain, e.g. `example.pstn.twilio.com`.
+    pub domain_name: Option<String>,
+    pub disaster_recovery_url: Option<String>,
+    pub disaster_recovery_method: Option<String>,
+    pub secure: Option<bool>,
+    pub cnam_lookup_enabled: Option<bool>,
+    pub transfer_mode: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Settings applied when creating or updating a trunk. Fields left as `None`
+/// are not sent, so they keep their current value.
+#[derive(Default)]
+pub struct TrunkConfig<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub domain_name: Option<&'a str>,
+    /// TwiML to fall back to when no origination URL can be reached.
+    pub disaster_recovery_url: Option<&'a str>,
+    pub disaster_recovery_method: Option<Method>,
+    pub secure: Option<bool>,
+    pub cnam_lookup_enabled: Option<bool>,
+}
+
+impl<'a> TrunkConfig<'a> {
+    fn params(&self) -> Vec<(&'static str, &'a str)> {
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(domain) = self.domain_name {
+            opts.push(("DomainName", domain));
+        }
+        if let Some(url) = self.disaster_recovery_url {
+            opts.push(("DisasterRecoveryUrl", url));
+        }
+        if let Some(ref method) = self.disaster_recovery_method {
+            opts.push(("DisasterRecoveryMethod", method.as_str()));
+        }
+        if let Some(secure) = self.secure {
+            opts.push(("Secure", bool_str(secure)));
+        }
+        if let Some(enabled) = self.cnam_lookup_enabled {
+            opts.push(("CnamLookupEnabled", bool_str(enabled)));
+        }
+        opts
+    }
+}
+
+/// Where Twilio sends calls arriving on the trunk. Lower `priority` values
+/// are tried first; `weight` splits traffic between URLs of equal priority.
+#[derive(Debug, Deserialize)]
+pub struct OriginationUrl {
+    pub sid: OriginationUrlSid,
+    pub account_sid: AccountSid,
+    pub trunk_sid: TrunkSid,
+    pub friendly_name: String,
+    pub sip_url: String,
+    pub priority: u32,
+    pub weight: u32,
+    pub enabled: bool,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+pub struct NewOriginationUrl<'a> {
+    pub friendly_name: &'a str,
+    /// e.g. `sip:pbx.example.com`.
+    pub sip_url: &'a str,
+    pub priority: u32,
+    pub weight: u32,
+    pub enabled: bool,
+}
+
+impl<'a> NewOriginationUrl<'a> {
+    /// An enabled URL with priority 10 and weight 10, Twilio's usual defaults.
+    pub fn new(friendly_name: &'a str, sip_url: &'a str) -> NewOriginationUrl<'a> {
+        NewOriginationUrl {
+            friendly_name,
+            sip_url,
+            priority: 10,
+            weight: 10,
+            enabled: true,
+        }
+    }
+}
+
+impl Client {
+    pub async fn create_trunk(&self, config: TrunkConfig<'_>) -> Result<Trunk, TwilioError> {
+        self.send_product_request(POST, &TRUNKING, "Trunks", &config.params())
+            .await
+    }
+
+    pub fn list_trunks(&self) -> PageIterator<Trunk> {
+        self.list_product(&TRUNKING, "Trunks", "trunks", &[])
+    }
+
+    pub async fn retrieve_trunk(&self, sid: &TrunkSid) -> Result<Trunk, TwilioError> {
+        self.send_product_request(GET, &TRUNKING, &format!("Trunks/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_trunk(
+        &self,
+        sid: &TrunkSid,
+        config: TrunkConfig<'_>,
+    ) -> Result<Trunk, TwilioError> {
+        self.send_product_request(POST, &TRUNKING, &format!("Trunks/{sid}"), &config.params())
+            .await
+    }
+
+    pub async fn delete_trunk(&self, sid: &TrunkSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&TRUNKING, &format!("Trunks/{sid}"))
+            .await
+    }
+
+    pub async fn create_origination_url(
+        &self,
+        trunk_sid: &TrunkSid,
+        url: NewOriginationUrl<'_>,
+    ) -> Result<OriginationUrl, TwilioError> {
+        let priority = url.priority.to_string();
+        let weight = url.weight.to_string();
+        let opts = [
+            ("FriendlyName", url.friendly_name),
+            ("SipUrl", url.sip_url),
+            ("Priority", &priority),
+            ("Weight", &weight),
+            ("Enabled", bool_str(url.enabled)),
+        ];
+        self.send_product_request(
+            POST,
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/OriginationUrls"),
+            &opts,
+        )
+        .await
+    }
+
+    pub fn list_origination_urls(&self, trunk_sid: &TrunkSid) -> PageIterator<OriginationUrl> {
+        self.list_product(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/OriginationUrls"),
+            "origination_urls",
+            &[],
+        )
+    }
+
+    pub async fn delete_origination_url(
+        &self,
+        trunk_sid: &TrunkSid,
+        sid: &OriginationUrlSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/OriginationUrls/{sid}"),
+        )
+        .await
+    }
+
+    /// Requires calls terminating over the trunk to authenticate with a
+    /// credential from `credential_list_sid`.
+    pub async fn add_trunk_credential_list(
+        &self,
+        trunk_sid: &TrunkSid,
+        credential_list_sid: &CredentialListSid,
+    ) -> Result<CredentialList, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/CredentialLists"),
+            &[("CredentialListSid", credential_list_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_trunk_credential_lists(
+        &self,
+        trunk_sid: &TrunkSid,
+    ) -> PageIterator<CredentialList> {
+        self.list_product(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/CredentialLists"),
+            "credential_lists",
+            &[],
+        )
+    }
+
+    pub async fn remove_trunk_credential_list(
+        &self,
+        trunk_sid: &TrunkSid,
+        credential_list_sid: &CredentialListSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/CredentialLists/{credential_list_sid}"),
+        )
+        .await
+    }
+
+    /// Only accepts terminating calls from addresses in `list_sid`.
+    pub async fn add_trunk_ip_access_control_list(
+        &self,
+        trunk_sid: &TrunkSid,
+        list_sid: &IpAccessControlListSid,
+    ) -> Result<IpAccessControlList, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/IpAccessControlLists"),
+            &[("IpAccessControlListSid", list_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_trunk_ip_access_control_lists(
+        &self,
+        trunk_sid: &TrunkSid,
+    ) -> PageIterator<IpAccessControlList> {
+        self.list_product(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/IpAccessControlLists"),
+            "ip_access_control_lists",
+            &[],
+        )
+    }
+
+    pub async fn remove_trunk_ip_access_control_list(
+        &self,
+        trunk_sid: &TrunkSid,
+        list_sid: &IpAccessControlListSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/IpAccessControlLists/{list_sid}"),
+        )
+        .await
+    }
+
+    /// Routes calls to one of your numbers over the trunk instead of its own
+    /// voice URL.
+    pub async fn add_trunk_phone_number(
+        &self,
+        trunk_sid: &TrunkSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<IncomingPhoneNumber, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/PhoneNumbers"),
+            &[("PhoneNumberSid", phone_number_sid.as_str())],
+        )
+        .await
+    }
+
+    pub fn list_trunk_phone_numbers(
+        &self,
+        trunk_sid: &TrunkSid,
+    ) -> PageIterator<IncomingPhoneNumber> {
+        self.list_product(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/PhoneNumbers"),
+            "phone_numbers",
+            &[],
+        )
+    }
+
+    pub async fn remove_trunk_phone_number(
+        &self,
+        trunk_sid: &TrunkSid,
+        phone_number_sid: &PhoneNumberSid,
+    ) -> Result<(), TwilioError> {
+        self.send_product_delete(
+            &TRUNKING,
+            &format!("Trunks/{trunk_sid}/PhoneNumbers/{phone_number_sid}"),
+        )
+        .await
+    }
+}
@@ -0,0 +1,307 @@
+//! [Trust Hub](https://www.twilio.com/docs/trust-hub/trusthub-rest-api), where
+//! a business proves who it is before registering for A2P 10DLC messaging or
+//! other regulated products.
+//!
+//! Both customer profiles and trust products are bundles: create one against
+//! a policy, attach end users and supporting documents describing the
+//! business, check it with an evaluation, then submit it for review.
+
+use crate::endpoint::TRUSTHUB;
+use crate::{
+    AccountSid, BundleSid, Client, EndUserSid, PageIterator, SupportingDocumentSid, Timestamp,
+    TwilioError, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BundleStatus {
+    Draft,
+    PendingReview,
+    InReview,
+    TwilioRejected,
+    TwilioApproved,
+}
+
+/// A customer profile or trust product.
+#[derive(Debug, Deserialize)]
+pub struct Bundle {
+    pub sid: BundleSid,
+    pub account_sid: AccountSid,
+    /// The policy the bundle is evaluated against.
+    pub policy_sid: String,
+    pub friendly_name: String,
+    pub status: BundleStatus,
+    pub email: Option<String>,
+    pub status_callback: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub valid_until: Option<Timestamp>,
+    #[serde(default)]
+    pub errors: Vec<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// A person or business described by a bundle, such as the business itself
+/// or its authorized representative.
+#[derive(Debug, Deserialize)]
+pub struct EndUser {
+    pub sid: EndUserSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    /// E.g. `customer_profile_business_information` or
+    /// `us_a2p_messaging_profile_information`.
+    #[serde(rename = "type")]
+    pub end_user_type: String,
+    #[serde(default)]
+    pub attributes: BTreeMap<String, serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// A document backing a bundle, such as the business's address.
+#[derive(Debug, Deserialize)]
+pub struct SupportingDocument {
+    pub sid: SupportingDocumentSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    #[serde(rename = "type")]
+    pub document_type: String,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub attributes: BTreeMap<String, serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Links an end user, supporting document or other bundle to a bundle.
+#[derive(Debug, Deserialize)]
+pub struct EntityAssignment {
+    pub sid: String,
+    pub account_sid: AccountSid,
+    #[serde(alias = "customer_profile_sid", alias = "trust_product_sid")]
+    pub bundle_sid: BundleSid,
+    pub object_sid: String,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// Whether a bundle has everything its policy requires. `results` lists
+/// what is missing or invalid when it doesn't.
+#[derive(Debug, Deserialize)]
+pub struct Evaluation {
+    pub sid: String,
+    pub account_sid: AccountSid,
+    pub policy_sid: String,
+    #[serde(alias = "customer_profile_sid", alias = "trust_product_sid")]
+    pub bundle_sid: BundleSid,
+    /// `compliant` or `noncompliant`.
+    pub status: String,
+    #[serde(default)]
+    pub results: Vec<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+impl Evaluation {
+    pub fn is_compliant(&self) -> bool {
+        self.status == "compliant"
+    }
+}
+
+impl Client {
+    /// Creates a customer profile, which describes a business. `policy_sid`
+    /// is the `RN` SID of the policy, e.g. the Secondary Customer Profile
+    /// policy for A2P registration.
+    pub async fn create_customer_profile(
+        &self,
+        friendly_name: &str,
+        email: &str,
+        policy_sid: &str,
+    ) -> Result<Bundle, TwilioError> {
+        self.create_bundle("CustomerProfiles", friendly_name, email, policy_sid)
+            .await
+    }
+
+    pub fn list_customer_profiles(&self) -> PageIterator<Bundle> {
+        self.list_product(&TRUSTHUB, "CustomerProfiles", "results", &[])
+    }
+
+    pub async fn retrieve_customer_profile(&self, sid: &BundleSid) -> Result<Bundle, TwilioError> {
+        self.send_product_request(GET, &TRUSTHUB, &format!("CustomerProfiles/{sid}"), &[])
+            .await
+    }
+
+    pub async fn assign_to_customer_profile(
+        &self,
+        sid: &BundleSid,
+        object_sid: &str,
+    ) -> Result<EntityAssignment, TwilioError> {
+        self.assign_to_bundle("CustomerProfiles", sid, object_sid)
+            .await
+    }
+
+    pub async fn evaluate_customer_profile(
+        &self,
+        sid: &BundleSid,
+        policy_sid: &str,
+    ) -> Result<Evaluation, TwilioError> {
+        self.evaluate_bundle("CustomerProfiles", sid, policy_sid)
+            .await
+    }
+
+    /// Submits a customer profile for Twilio's review.
+    pub async fn submit_customer_profile(&self, sid: &BundleSid) -> Result<Bundle, TwilioError> {
+        self.submit_bundle("CustomerProfiles", sid).await
+    }
+
+    /// Creates a trust product, such as the A2P messaging profile a brand
+    /// registration requires.
+    pub async fn create_trust_product(
+        &self,
+        friendly_name: &str,
+        email: &str,
+        policy_sid: &str,
+    ) -> Result<Bundle, TwilioError> {
+        self.create_bundle("TrustProducts", friendly_name, email, policy_sid)
+            .await
+    }
+
+    pub fn list_trust_products(&self) -> PageIterator<Bundle> {
+        self.list_product(&TRUSTHUB, "TrustProducts", "results", &[])
+    }
+
+    pub async fn retrieve_trust_product(&self, sid: &BundleSid) -> Result<Bundle, TwilioError> {
+        self.send_product_request(GET, &TRUSTHUB, &format!("TrustProducts/{sid}"), &[])
+            .await
+    }
+
+    pub async fn assign_to_trust_product(
+        &self,
+        sid: &BundleSid,
+        object_sid: &str,
+    ) -> Result<EntityAssignment, TwilioError> {
+        self.assign_to_bundle("TrustProducts", sid, object_sid)
+            .await
+    }
+
+    pub async fn evaluate_trust_product(
+        &self,
+        sid: &BundleSid,
+        policy_sid: &str,
+    ) -> Result<Evaluation, TwilioError> {
+        self.evaluate_bundle("TrustProducts", sid, policy_sid).await
+    }
+
+    pub async fn submit_trust_product(&self, sid: &BundleSid) -> Result<Bundle, TwilioError> {
+        self.submit_bundle("TrustProducts", sid).await
+    }
+
+    pub async fn create_end_user(
+        &self,
+        friendly_name: &str,
+        end_user_type: &str,
+        attributes: &serde_json::Value,
+    ) -> Result<EndUser, TwilioError> {
+        let attributes = attributes.to_string();
+        let opts = [
+            ("FriendlyName", friendly_name),
+            ("Type", end_user_type),
+            ("Attributes", &attributes),
+        ];
+        self.send_product_request(POST, &TRUSTHUB, "EndUsers", &opts)
+            .await
+    }
+
+    /// Creates a supporting document. For a business address, use type
+    /// `customer_profile_address` with `{"address_sids": "AD..."}`.
+    pub async fn create_supporting_document(
+        &self,
+        friendly_name: &str,
+        document_type: &str,
+        attributes: &serde_json::Value,
+    ) -> Result<SupportingDocument, TwilioError> {
+        let attributes = attributes.to_string();
+        let opts = [
+            ("FriendlyName", friendly_name),
+            ("Type", document_type),
+            ("Attributes", &attributes),
+        ];
+        self.send_product_request(POST, &TRUSTHUB, "SupportingDocuments", &opts)
+            .await
+    }
+
+    async fn create_bundle(
+        &self,
+        collection: &str,
+        friendly_name: &str,
+        email: &str,
+        policy_sid: &str,
+    ) -> Result<Bundle, TwilioError> {
+        let opts = [
+            ("FriendlyName", friendly_name),
+            ("Email", email),
+            ("PolicySid", policy_sid),
+        ];
+        self.send_product_request(POST, &TRUSTHUB, collection, &opts)
+            .await
+    }
+
+    async fn assign_to_bundle(
+        &self,
+        collection: &str,
+        sid: &BundleSid,
+        object_sid: &str,
+    ) -> Result<EntityAssignment, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUSTHUB,
+            &format!("{collection}/{sid}/EntityAssignments"),
+            &[("ObjectSid", object_sid)],
+        )
+        .await
+    }
+
+    async fn evaluate_bundle(
+        &self,
+        collection: &str,
+        sid: &BundleSid,
+        policy_sid: &str,
+    ) -> Result<Evaluation, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUSTHUB,
+            &format!("{collection}/{sid}/Evaluations"),
+            &[("PolicySid", policy_sid)],
+        )
+        .await
+    }
+
+    async fn submit_bundle(
+        &self,
+        collection: &str,
+        sid: &BundleSid,
+    ) -> Result<Bundle, TwilioError> {
+        self.send_product_request(
+            POST,
+            &TRUSTHUB,
+            &format!("{collection}/{sid}"),
+            &[("Status", "pending-review")],
+        )
+        .await
+    }
+}
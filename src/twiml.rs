@@ -1,27 +1,96 @@
+#[cfg(feature = "voice")]
+mod connect;
+// Some readers are only used by the voice verbs.
+#[cfg_attr(not(feature = "voice"), allow(dead_code))]
+mod convert;
+#[cfg(feature = "voice")]
+mod dial;
+#[cfg(feature = "voice")]
+mod enqueue;
+#[cfg(feature = "voice")]
 mod gather;
+#[cfg(feature = "voice")]
+mod hangup;
 mod message;
+mod parse;
+#[cfg(feature = "voice")]
+mod pause;
+#[cfg(feature = "voice")]
+mod pay;
+#[cfg(feature = "voice")]
 mod play;
+#[cfg(feature = "voice")]
 mod record;
 mod redirect;
+#[cfg(feature = "voice")]
+mod refer;
+#[cfg(feature = "voice")]
 mod say;
+#[cfg(feature = "voice")]
 mod sms;
+#[cfg(feature = "voice")]
+mod start;
+#[cfg(feature = "voice")]
+mod stream;
+mod validate;
 
-pub use self::gather::{Gather, Prompt};
+#[cfg(feature = "voice")]
+pub use self::connect::{Connect, ConnectNoun, VirtualAgent};
+#[cfg(feature = "voice")]
+pub use self::dial::{
+    Beep, Client, Conference, ConferenceEvent, Dial, DialNoun, Number, Queue, Sip, StatusCallback,
+};
+#[cfg(feature = "voice")]
+pub use self::enqueue::{Enqueue, Leave, Task};
+#[cfg(feature = "voice")]
+pub use self::gather::{Gather, GatherInput, Prompt, SpeechTimeout};
+#[cfg(feature = "voice")]
+pub use self::hangup::Hangup;
 pub use self::message::Message;
+pub use self::parse::{parse, Element, Node, ParseError};
+#[cfg(feature = "voice")]
+pub use self::pause::Pause;
+#[cfg(feature = "voice")]
+pub use self::pay::{Pay, PayField, PayPrompt, PaymentMethod, TokenType};
+#[cfg(feature = "voice")]
 pub use self::play::{Digits, Play, Playable};
+#[cfg(feature = "voice")]
 pub use self::record::{Record, Transcribe};
 pub use self::redirect::Redirect;
-pub use self::say::{Say, Voice};
+#[cfg(feature = "voice")]
+pub use self::refer::Refer;
+#[cfg(feature = "voice")]
+pub use self::say::{Say, Ssml, Voice};
+#[cfg(feature = "voice")]
 pub use self::sms::Sms;
+#[cfg(feature = "voice")]
+pub use self::start::{Siprec, Start, StartNoun};
+#[cfg(feature = "voice")]
+pub use self::stream::{Parameter, Stream, Track};
+pub use self::validate::InvalidTwiml;
 
 pub trait Action {
     fn as_twiml(&self) -> String;
 }
 
+/// Verbs that are valid in a `MessagingResponse`.
+pub trait MessagingAction: Action {}
+
+/// Verbs that are valid at the top level of a voice `Twiml`. Nouns such as
+/// `Number` or `Stream` only go inside their verb.
+pub trait VoiceAction: Action {}
+
+/// A `<Response>` document. Verbs are rendered in the order they are added.
 pub struct Twiml {
     body: String,
 }
 
+impl Default for Twiml {
+    fn default() -> Twiml {
+        Twiml::new()
+    }
+}
+
 impl Twiml {
     pub fn new() -> Twiml {
         Twiml {
@@ -29,9 +98,22 @@ impl Twiml {
         }
     }
 
+    /// Appends a verb. Only voice verbs are accepted, so adding a noun such
+    /// as `Number`, which belongs inside its `Dial`, or a messaging verb is
+    /// a compile error.
+    pub fn add_verb(&mut self, a: &dyn VoiceAction) -> &mut Twiml {
+        self.body.push_str(&a.as_twiml());
+        self
+    }
+
+    /// Appends any `Action`, including ones that aren't valid at the top
+    /// level of a voice response.
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `add_verb`, which only accepts voice verbs, and `validate` for anything else"
+    )]
     pub fn add(&mut self, a: &dyn Action) -> &mut Twiml {
-        let twiml = a.as_twiml();
-        self.body.push_str((&twiml as &dyn AsRef<str>).as_ref());
+        self.body.push_str(&a.as_twiml());
         self
     }
 
@@ -42,16 +124,169 @@ impl Twiml {
             b
         )
     }
+
+    /// Checks the rendered document against Twilio's nesting rules. Builders
+    /// mostly enforce these already; this catches parsed `Element`s and
+    /// custom `Action`s.
+    pub fn validate(&self) -> Result<(), InvalidTwiml> {
+        parse(&self.as_twiml())?.validate()
+    }
+}
+
+/// `Twiml` is the `<Response>` returned to voice webhooks.
+pub type VoiceResponse = Twiml;
+
+/// A `<Response>` for inbound message webhooks, which only accepts the
+/// `<Message>` and `<Redirect>` verbs.
+pub struct MessagingResponse {
+    body: String,
+}
+
+impl Default for MessagingResponse {
+    fn default() -> MessagingResponse {
+        MessagingResponse::new()
+    }
 }
 
+impl MessagingResponse {
+    pub fn new() -> MessagingResponse {
+        MessagingResponse {
+            body: "".to_string(),
+        }
+    }
+
+    pub fn add(&mut self, a: &dyn MessagingAction) -> &mut MessagingResponse {
+        self.body.push_str(&a.as_twiml());
+        self
+    }
+
+    pub fn as_twiml(&self) -> String {
+        Twiml::from(self).as_twiml()
+    }
+
+    pub fn validate(&self) -> Result<(), InvalidTwiml> {
+        Twiml::from(self).validate()
+    }
+}
+
+impl From<&MessagingResponse> for Twiml {
+    fn from(r: &MessagingResponse) -> Twiml {
+        Twiml {
+            body: r.body.clone(),
+        }
+    }
+}
+
+impl From<MessagingResponse> for Twiml {
+    fn from(r: MessagingResponse) -> Twiml {
+        Twiml { body: r.body }
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends custom headers to a SIP URI, e.g. `sip:alice@example.com?X-Ticket=42`.
+/// SIP headers are percent-encoded rather than form-encoded, so a space
+/// becomes `%20` and not `+`.
+#[cfg(feature = "voice")]
+pub(crate) fn sip_uri<'a>(
+    uri: &str,
+    headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> String {
+    let encode = |s: &str| {
+        s.bytes()
+            .map(|b| match b {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'-'
+                | b'_'
+                | b'.'
+                | b'!'
+                | b'~'
+                | b'*'
+                | b'\''
+                | b'('
+                | b')' => (b as char).to_string(),
+                _ => format!("%{b:02X}"),
+            })
+            .collect::<String>()
+    };
+    let mut uri = uri.to_string();
+    for (name, value) in headers {
+        uri.push(if uri.contains('?') { '&' } else { '?' });
+        uri.push_str(&encode(name));
+        uri.push('=');
+        uri.push_str(&encode(value));
+    }
+    uri
+}
+
+/// Splits the headers back off a SIP URI built by `sip_uri`, decoding them.
+#[cfg(feature = "voice")]
+pub(crate) fn split_sip_uri(uri: &str) -> (String, Vec<(String, String)>) {
+    let decode = |s: &str| {
+        let bytes = s.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            match (bytes[i], hex) {
+                (b'%', Some(b)) => {
+                    out.push(b);
+                    i += 3;
+                }
+                (b, _) => {
+                    out.push(b);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&out).into_owned()
+    };
+    match uri.split_once('?') {
+        Some((uri, headers)) => (
+            uri.to_string(),
+            headers
+                .split('&')
+                .filter(|h| !h.is_empty())
+                .map(|h| {
+                    let (name, value) = h.split_once('=').unwrap_or((h, ""));
+                    (decode(name), decode(value))
+                })
+                .collect(),
+        ),
+        None => (uri.to_string(), Vec::new()),
+    }
+}
+
+/// Renders `<tag attr="...">inner</tag>`. Attribute values are escaped here;
+/// `inner` is written as-is so verbs can nest, which means callers must escape
+/// any text content themselves.
 fn format_xml_string(tag: &str, attributes: &[(&str, &str)], inner: &str) -> String {
     let attribute_string = match attributes.len() {
         0 => "".to_string(),
         _ => attributes
             .iter()
-            .map(|t| format!("{}=\"{}\"", t.0, t.1))
+            .map(|t| format!("{}=\"{}\"", t.0, escape_xml(t.1)))
             .fold("".to_string(), |mut acc, v| {
-                acc.push_str(" ");
+                acc.push(' ');
                 acc.push_str(&v);
                 acc
             }),
@@ -64,3 +299,12 @@ pub enum Method {
     Get,
     Post,
 }
+
+impl Method {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match *self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use super::convert::Reader;
+use super::stream::{parameters_twiml, read_parameters};
+use super::{
+    format_xml_string, Action, Element, InvalidTwiml, Method, Parameter, Stream, VoiceAction,
+};
+use std::convert::TryFrom;
+
+/// Hands the call to a conversational AI connector, such as a Dialogflow CX
+/// agent.
+pub struct VirtualAgent {
+    pub connector_name: String,
+    pub language: Option<String>,
+    pub sentiment_analysis: Option<bool>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    pub parameters: Vec<Parameter>,
+}
+
+impl VirtualAgent {
+    pub fn new(connector_name: &str) -> VirtualAgent {
+        VirtualAgent {
+            connector_name: connector_name.to_string(),
+            language: None,
+            sentiment_analysis: None,
+            status_callback: None,
+            status_callback_method: None,
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl Action for VirtualAgent {
+    fn as_twiml(&self) -> String {
+        let mut attrs = vec![("connectorName", self.connector_name.as_str())];
+        if let Some(ref l) = self.language {
+            attrs.push(("language", l));
+        }
+        if let Some(s) = self.sentiment_analysis {
+            attrs.push(("sentimentAnalysis", crate::bool_str(s)));
+        }
+        if let Some(ref c) = self.status_callback {
+            attrs.push(("statusCallback", c));
+        }
+        if let Some(ref m) = self.status_callback_method {
+            attrs.push(("statusCallbackMethod", m.as_str()));
+        }
+        format_xml_string("VirtualAgent", &attrs, &parameters_twiml(&self.parameters))
+    }
+}
+
+pub enum ConnectNoun {
+    Stream(Stream),
+    VirtualAgent(VirtualAgent),
+}
+
+/// Connects the call to a bidirectional stream or virtual agent. TwiML after
+/// `<Connect>` only runs once it ends, and then only without an `action`.
+pub struct Connect {
+    pub action: Option<String>,
+    pub method: Method,
+    pub noun: ConnectNoun,
+}
+
+impl Connect {
+    pub fn stream(stream: Stream) -> Connect {
+        Connect {
+            action: None,
+            method: Method::Post,
+            noun: ConnectNoun::Stream(stream),
+        }
+    }
+
+    pub fn virtual_agent(agent: VirtualAgent) -> Connect {
+        Connect {
+            action: None,
+            method: Method::Post,
+            noun: ConnectNoun::VirtualAgent(agent),
+        }
+    }
+}
+
+impl Action for Connect {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a.as_str()));
+            attrs.push(("method", self.method.as_str()));
+        }
+        let inner = match self.noun {
+            ConnectNoun::Stream(ref s) => s.as_twiml(),
+            ConnectNoun::VirtualAgent(ref v) => v.as_twiml(),
+        };
+        format_xml_string("Connect", &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Connect {}
+
+impl TryFrom<&Element> for VirtualAgent {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<VirtualAgent, InvalidTwiml> {
+        let mut r = Reader::new(e, "VirtualAgent")?;
+        let agent = VirtualAgent {
+            connector_name: r.required("connectorName")?,
+            language: r.string("language"),
+            sentiment_analysis: r.bool("sentimentAnalysis")?,
+            status_callback: r.string("statusCallback"),
+            status_callback_method: r.method("statusCallbackMethod")?,
+            parameters: read_parameters(&mut r)?,
+        };
+        r.finish()?;
+        Ok(agent)
+    }
+}
+
+/// Supports `<Stream>` and `<VirtualAgent>`; `<Room>` and `<Conversation>`
+/// are `InvalidTwiml::Unsupported`.
+impl TryFrom<&Element> for Connect {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Connect, InvalidTwiml> {
+        let mut r = Reader::new(e, "Connect")?;
+        let action = r.string("action");
+        let method = r.method("method")?.unwrap_or(Method::Post);
+        let child = r.only_child()?;
+        let noun = match child.name.as_str() {
+            "Stream" => ConnectNoun::Stream(Stream::try_from(child)?),
+            "VirtualAgent" => ConnectNoun::VirtualAgent(VirtualAgent::try_from(child)?),
+            other => return Err(r.unsupported(&format!("<{other}>"))),
+        };
+        r.finish()?;
+        Ok(Connect {
+            action,
+            method,
+            noun,
+        })
+    }
+}
@@ -0,0 +1,192 @@
+use super::{Element, InvalidTwiml, Method, Node};
+use std::str::FromStr;
+
+/// Reads a parsed element into one of the builders. Attributes are taken one
+/// at a time, so that `finish` can reject any the builder has no field for
+/// rather than silently dropping them.
+pub(super) struct Reader<'a> {
+    element: &'a Element,
+    read: Vec<&'static str>,
+    read_content: bool,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(
+        element: &'a Element,
+        name: &'static str,
+    ) -> Result<Reader<'a>, InvalidTwiml> {
+        if element.name != name {
+            return Err(InvalidTwiml::WrongElement {
+                expected: name,
+                found: element.name.clone(),
+            });
+        }
+        Ok(Reader {
+            element,
+            read: Vec::new(),
+            read_content: false,
+        })
+    }
+
+    pub(super) fn string(&mut self, name: &'static str) -> Option<String> {
+        self.read.push(name);
+        self.element.attr(name).map(String::from)
+    }
+
+    pub(super) fn required(&mut self, name: &'static str) -> Result<String, InvalidTwiml> {
+        self.string(name).ok_or_else(|| self.missing(name))
+    }
+
+    pub(super) fn missing(&self, attribute: &'static str) -> InvalidTwiml {
+        InvalidTwiml::MissingAttribute {
+            element: self.element.name.clone(),
+            attribute,
+        }
+    }
+
+    /// Converts an attribute with `f`, which returns `None` for values the
+    /// builder can't represent.
+    pub(super) fn map<T>(
+        &mut self,
+        name: &'static str,
+        f: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<Option<T>, InvalidTwiml> {
+        match self.string(name) {
+            Some(v) => match f(&v) {
+                Some(t) => Ok(Some(t)),
+                None => Err(InvalidTwiml::InvalidAttribute {
+                    element: self.element.name.clone(),
+                    attribute: name.to_string(),
+                    value: v,
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    pub(super) fn number<T: FromStr>(
+        &mut self,
+        name: &'static str,
+    ) -> Result<Option<T>, InvalidTwiml> {
+        self.map(name, |v| v.trim().parse().ok())
+    }
+
+    pub(super) fn bool(&mut self, name: &'static str) -> Result<Option<bool>, InvalidTwiml> {
+        self.map(name, |v| match v {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        })
+    }
+
+    pub(super) fn method(&mut self, name: &'static str) -> Result<Option<Method>, InvalidTwiml> {
+        self.map(name, |v| match v.to_ascii_uppercase().as_str() {
+            "GET" => Some(Method::Get),
+            "POST" => Some(Method::Post),
+            _ => None,
+        })
+    }
+
+    /// A space-separated list, such as `statusCallbackEvent`.
+    pub(super) fn list<T>(
+        &mut self,
+        name: &'static str,
+        f: impl Fn(&str) -> Option<T>,
+    ) -> Result<Vec<T>, InvalidTwiml> {
+        self.map(name, |v| v.split_whitespace().map(f).collect())
+            .map(Option::unwrap_or_default)
+    }
+
+    /// The element's text, for builders that hold no nested elements.
+    pub(super) fn text(&mut self) -> Result<String, InvalidTwiml> {
+        self.read_content = true;
+        match self.element.elements().next() {
+            Some(child) => Err(self.unsupported(&format!("<{}>", child.name))),
+            None => Ok(self.element.text()),
+        }
+    }
+
+    /// The nested elements, for the builder to convert. Text between them
+    /// other than whitespace is rejected.
+    pub(super) fn children(&mut self) -> Result<Vec<&'a Element>, InvalidTwiml> {
+        self.read_content = true;
+        let mut children = Vec::new();
+        for node in &self.element.children {
+            match node {
+                Node::Element(e) => children.push(e),
+                Node::Text(t) if t.trim().is_empty() => {}
+                Node::Text(_) => return Err(self.unsupported("text")),
+            }
+        }
+        Ok(children)
+    }
+
+    /// Everything inside the element, for `<Say>` and SSML.
+    pub(super) fn content(&mut self) -> &'a [Node] {
+        self.read_content = true;
+        &self.element.children
+    }
+
+    /// The one element inside a verb like `<Connect>`, which takes a single
+    /// noun.
+    pub(super) fn only_child(&mut self) -> Result<&'a Element, InvalidTwiml> {
+        match *self.children()? {
+            [e] => Ok(e),
+            [] => Err(InvalidTwiml::MissingContent {
+                element: self.element.name.clone(),
+            }),
+            [_, e, ..] => Err(self.unsupported(&format!("a second <{}>", e.name))),
+        }
+    }
+
+    /// `item`, such as `<Room>` or `text`, is valid TwiML that the builder
+    /// has no field for.
+    pub(super) fn unsupported(&self, item: &str) -> InvalidTwiml {
+        InvalidTwiml::Unsupported {
+            element: self.element.name.clone(),
+            item: item.to_string(),
+        }
+    }
+
+    /// Rejects attributes that weren't read, and any content when none was
+    /// expected.
+    pub(super) fn finish(self) -> Result<(), InvalidTwiml> {
+        if let Some((name, _)) = self
+            .element
+            .attributes
+            .iter()
+            .find(|(name, _)| !self.read.contains(&name.as_str()))
+        {
+            return Err(self.unsupported(&format!("attribute `{name}`")));
+        }
+        if !self.read_content {
+            match self.element.children.iter().find(|n| match n {
+                Node::Element(_) => true,
+                Node::Text(t) => !t.trim().is_empty(),
+            }) {
+                Some(Node::Element(e)) => return Err(self.unsupported(&format!("<{}>", e.name))),
+                Some(Node::Text(_)) => return Err(self.unsupported("text")),
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Picks the variant whose `as_str` is `s`.
+pub(super) fn from_str<T>(
+    variants: Vec<T>,
+    as_str: impl Fn(&T) -> &'static str,
+    s: &str,
+) -> Option<T> {
+    variants.into_iter().find(|v| as_str(v) == s)
+}
+
+/// `trim` on `<Record>` and `<Conference>`.
+pub(super) fn trim(v: &str) -> Option<bool> {
+    match v {
+        "trim-silence" => Some(true),
+        "do-not-trim" => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,690 @@
+use super::convert::{from_str, trim, Reader};
+use super::stream::parameters_twiml;
+use super::{
+    escape_xml, format_xml_string, sip_uri, split_sip_uri, Action, Element, InvalidTwiml, Method,
+    Parameter, VoiceAction,
+};
+use crate::CallEvent;
+use std::convert::TryFrom;
+use std::default::Default;
+
+pub struct Dial {
+    /// A bare number to dial. Leave empty when dialing `nouns` instead.
+    pub number: String,
+    pub nouns: Vec<DialNoun>,
+    pub action: Option<String>,
+    pub method: Method,
+    pub timeout_seconds: u32,
+    pub caller_id: Option<String>,
+    pub time_limit_seconds: Option<u32>,
+    pub hangup_on_star: bool,
+    pub record: bool,
+}
+
+impl Action for Dial {
+    fn as_twiml(&self) -> String {
+        let timeout_string = format!("{}", self.timeout_seconds);
+        let limit_string = self.time_limit_seconds.map(|l| format!("{}", l));
+        let mut attrs = Vec::new();
+        let method_str = match self.method {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        };
+        attrs.push(("method", method_str));
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a));
+        }
+        attrs.push(("timeout", timeout_string.as_ref()));
+        if let Some(ref c) = self.caller_id {
+            attrs.push(("callerId", c));
+        }
+        if let Some(ref l) = limit_string {
+            attrs.push(("timeLimit", l.as_ref()));
+        }
+        attrs.push((
+            "hangupOnStar",
+            if self.hangup_on_star { "true" } else { "false" },
+        ));
+        attrs.push((
+            "record",
+            if self.record {
+                "record-from-answer"
+            } else {
+                "do-not-record"
+            },
+        ));
+        let mut inner = escape_xml(&self.number);
+        for noun in &self.nouns {
+            inner.push_str(&noun.as_twiml());
+        }
+        format_xml_string("Dial", &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Dial {}
+
+impl Default for Dial {
+    fn default() -> Dial {
+        Dial {
+            number: "".to_string(),
+            nouns: Vec::new(),
+            action: None,
+            method: Method::Post,
+            timeout_seconds: 30,
+            caller_id: None,
+            time_limit_seconds: None,
+            hangup_on_star: false,
+            record: false,
+        }
+    }
+}
+
+/// What a `<Dial>` connects to. Several `Number`, `Sip` or `Client` nouns
+/// ring simultaneously and the first to answer wins.
+pub enum DialNoun {
+    Number(Number),
+    Sip(Sip),
+    Client(Client),
+    Conference(Conference),
+    Queue(Queue),
+}
+
+impl Action for DialNoun {
+    fn as_twiml(&self) -> String {
+        match *self {
+            DialNoun::Number(ref n) => n.as_twiml(),
+            DialNoun::Sip(ref s) => s.as_twiml(),
+            DialNoun::Client(ref c) => c.as_twiml(),
+            DialNoun::Conference(ref c) => c.as_twiml(),
+            DialNoun::Queue(ref q) => q.as_twiml(),
+        }
+    }
+}
+
+/// The call progress callback shared by `<Number>`, `<Sip>` and `<Client>`.
+#[derive(Default)]
+pub struct StatusCallback {
+    pub url: Option<String>,
+    pub method: Option<Method>,
+    pub events: Vec<CallEvent>,
+}
+
+impl StatusCallback {
+    fn read(r: &mut Reader) -> Result<StatusCallback, InvalidTwiml> {
+        Ok(StatusCallback {
+            events: r.list("statusCallbackEvent", |e| {
+                from_str(
+                    vec![
+                        CallEvent::Initiated,
+                        CallEvent::Ringing,
+                        CallEvent::Answered,
+                        CallEvent::Completed,
+                    ],
+                    CallEvent::as_str,
+                    e,
+                )
+            })?,
+            url: r.string("statusCallback"),
+            method: r.method("statusCallbackMethod")?,
+        })
+    }
+
+    fn push_attrs<'a>(&'a self, events: &'a str, attrs: &mut Vec<(&'a str, &'a str)>) {
+        if !events.is_empty() {
+            attrs.push(("statusCallbackEvent", events));
+        }
+        if let Some(ref u) = self.url {
+            attrs.push(("statusCallback", u));
+        }
+        if let Some(ref m) = self.method {
+            attrs.push(("statusCallbackMethod", m.as_str()));
+        }
+    }
+
+    fn events(&self) -> String {
+        let events: Vec<_> = self.events.iter().map(|e| e.as_str()).collect();
+        events.join(" ")
+    }
+}
+
+fn push_url<'a>(
+    url: &'a Option<String>,
+    method: &Option<Method>,
+    attrs: &mut Vec<(&'a str, &'a str)>,
+) {
+    if let Some(ref u) = *url {
+        attrs.push(("url", u));
+        if let Some(ref m) = *method {
+            attrs.push(("method", m.as_str()));
+        }
+    }
+}
+
+pub struct Number {
+    pub number: String,
+    /// DTMF tones to play once the call connects, `w` pausing half a second.
+    pub send_digits: Option<String>,
+    /// TwiML run for the callee before the calls are bridged.
+    pub url: Option<String>,
+    pub method: Option<Method>,
+    pub status_callback: StatusCallback,
+    pub byoc: Option<String>,
+}
+
+impl Number {
+    pub fn new(number: &str) -> Number {
+        Number {
+            number: number.to_string(),
+            send_digits: None,
+            url: None,
+            method: None,
+            status_callback: StatusCallback::default(),
+            byoc: None,
+        }
+    }
+}
+
+impl Action for Number {
+    fn as_twiml(&self) -> String {
+        let events = self.status_callback.events();
+        let mut attrs = Vec::new();
+        if let Some(ref d) = self.send_digits {
+            attrs.push(("sendDigits", d.as_str()));
+        }
+        push_url(&self.url, &self.method, &mut attrs);
+        self.status_callback.push_attrs(&events, &mut attrs);
+        if let Some(ref b) = self.byoc {
+            attrs.push(("byoc", b));
+        }
+        format_xml_string("Number", &attrs, &escape_xml(&self.number))
+    }
+}
+
+pub struct Sip {
+    pub uri: String,
+    /// Custom headers sent on the INVITE, conventionally prefixed `X-`.
+    pub headers: Vec<(String, String)>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub url: Option<String>,
+    pub method: Option<Method>,
+    pub status_callback: StatusCallback,
+}
+
+impl Sip {
+    pub fn new(uri: &str) -> Sip {
+        Sip {
+            uri: uri.to_string(),
+            headers: Vec::new(),
+            username: None,
+            password: None,
+            url: None,
+            method: None,
+            status_callback: StatusCallback::default(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Sip {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl Action for Sip {
+    fn as_twiml(&self) -> String {
+        let events = self.status_callback.events();
+        let mut attrs = Vec::new();
+        if let Some(ref u) = self.username {
+            attrs.push(("username", u.as_str()));
+        }
+        if let Some(ref p) = self.password {
+            attrs.push(("password", p));
+        }
+        push_url(&self.url, &self.method, &mut attrs);
+        self.status_callback.push_attrs(&events, &mut attrs);
+
+        let uri = sip_uri(
+            &self.uri,
+            self.headers.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+        );
+        format_xml_string("Sip", &attrs, &escape_xml(&uri))
+    }
+}
+
+/// A Voice SDK client, addressed by identity.
+pub struct Client {
+    pub identity: String,
+    pub url: Option<String>,
+    pub method: Option<Method>,
+    pub status_callback: StatusCallback,
+    /// Custom parameters handed to the client with the incoming call.
+    pub parameters: Vec<Parameter>,
+}
+
+impl Client {
+    pub fn new(identity: &str) -> Client {
+        Client {
+            identity: identity.to_string(),
+            url: None,
+            method: None,
+            status_callback: StatusCallback::default(),
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl Action for Client {
+    fn as_twiml(&self) -> String {
+        let events = self.status_callback.events();
+        let mut attrs = Vec::new();
+        push_url(&self.url, &self.method, &mut attrs);
+        self.status_callback.push_attrs(&events, &mut attrs);
+        let mut inner = format_xml_string("Identity", &[], &escape_xml(&self.identity));
+        inner.push_str(&parameters_twiml(&self.parameters));
+        format_xml_string("Client", &attrs, &inner)
+    }
+}
+
+pub enum Beep {
+    Always,
+    Never,
+    OnEnter,
+    OnExit,
+}
+
+impl Beep {
+    fn parse(s: &str) -> Option<Beep> {
+        from_str(
+            vec![Beep::Always, Beep::Never, Beep::OnEnter, Beep::OnExit],
+            Beep::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Beep::Always => "true",
+            Beep::Never => "false",
+            Beep::OnEnter => "onEnter",
+            Beep::OnExit => "onExit",
+        }
+    }
+}
+
+pub enum ConferenceEvent {
+    Start,
+    End,
+    Join,
+    Leave,
+    Mute,
+    Hold,
+    Modify,
+    Speaker,
+    Announcement,
+}
+
+impl ConferenceEvent {
+    fn parse(s: &str) -> Option<ConferenceEvent> {
+        from_str(
+            vec![
+                ConferenceEvent::Start,
+                ConferenceEvent::End,
+                ConferenceEvent::Join,
+                ConferenceEvent::Leave,
+                ConferenceEvent::Mute,
+                ConferenceEvent::Hold,
+                ConferenceEvent::Modify,
+                ConferenceEvent::Speaker,
+                ConferenceEvent::Announcement,
+            ],
+            ConferenceEvent::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            ConferenceEvent::Start => "start",
+            ConferenceEvent::End => "end",
+            ConferenceEvent::Join => "join",
+            ConferenceEvent::Leave => "leave",
+            ConferenceEvent::Mute => "mute",
+            ConferenceEvent::Hold => "hold",
+            ConferenceEvent::Modify => "modify",
+            ConferenceEvent::Speaker => "speaker",
+            ConferenceEvent::Announcement => "announcement",
+        }
+    }
+}
+
+/// Joins a named conference room, creating it if needed. Unset options keep
+/// Twilio's defaults.
+pub struct Conference {
+    pub name: String,
+    pub muted: Option<bool>,
+    pub beep: Option<Beep>,
+    pub start_conference_on_enter: Option<bool>,
+    pub end_conference_on_exit: Option<bool>,
+    /// Hold music TwiML played until the conference starts.
+    pub wait_url: Option<String>,
+    pub wait_method: Option<Method>,
+    pub max_participants: Option<u32>,
+    pub record: bool,
+    pub trim: Option<bool>,
+    pub region: Option<String>,
+    /// The call sid of a participant this one coaches.
+    pub coach: Option<String>,
+    pub participant_label: Option<String>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    pub status_callback_events: Vec<ConferenceEvent>,
+    pub recording_status_callback: Option<String>,
+    pub recording_status_callback_method: Option<Method>,
+}
+
+impl Conference {
+    pub fn new(name: &str) -> Conference {
+        Conference {
+            name: name.to_string(),
+            muted: None,
+            beep: None,
+            start_conference_on_enter: None,
+            end_conference_on_exit: None,
+            wait_url: None,
+            wait_method: None,
+            max_participants: None,
+            record: false,
+            trim: None,
+            region: None,
+            coach: None,
+            participant_label: None,
+            status_callback: None,
+            status_callback_method: None,
+            status_callback_events: Vec::new(),
+            recording_status_callback: None,
+            recording_status_callback_method: None,
+        }
+    }
+}
+
+impl Action for Conference {
+    fn as_twiml(&self) -> String {
+        let max = self.max_participants.map(|m| m.to_string());
+        let events: Vec<_> = self
+            .status_callback_events
+            .iter()
+            .map(|e| e.as_str())
+            .collect();
+        let events = events.join(" ");
+        let mut attrs = Vec::new();
+        if let Some(m) = self.muted {
+            attrs.push(("muted", crate::bool_str(m)));
+        }
+        if let Some(ref b) = self.beep {
+            attrs.push(("beep", b.as_str()));
+        }
+        if let Some(s) = self.start_conference_on_enter {
+            attrs.push(("startConferenceOnEnter", crate::bool_str(s)));
+        }
+        if let Some(e) = self.end_conference_on_exit {
+            attrs.push(("endConferenceOnExit", crate::bool_str(e)));
+        }
+        if let Some(ref w) = self.wait_url {
+            attrs.push(("waitUrl", w));
+        }
+        if let Some(ref m) = self.wait_method {
+            attrs.push(("waitMethod", m.as_str()));
+        }
+        if let Some(ref m) = max {
+            attrs.push(("maxParticipants", m));
+        }
+        if self.record {
+            attrs.push(("record", "record-from-start"));
+        }
+        if let Some(t) = self.trim {
+            attrs.push(("trim", if t { "trim-silence" } else { "do-not-trim" }));
+        }
+        if let Some(ref r) = self.region {
+            attrs.push(("region", r));
+        }
+        if let Some(ref c) = self.coach {
+            attrs.push(("coach", c));
+        }
+        if let Some(ref l) = self.participant_label {
+            attrs.push(("participantLabel", l));
+        }
+        if !events.is_empty() {
+            attrs.push(("statusCallbackEvent", &events));
+        }
+        if let Some(ref c) = self.status_callback {
+            attrs.push(("statusCallback", c));
+        }
+        if let Some(ref m) = self.status_callback_method {
+            attrs.push(("statusCallbackMethod", m.as_str()));
+        }
+        if let Some(ref c) = self.recording_status_callback {
+            attrs.push(("recordingStatusCallback", c));
+        }
+        if let Some(ref m) = self.recording_status_callback_method {
+            attrs.push(("recordingStatusCallbackMethod", m.as_str()));
+        }
+        format_xml_string("Conference", &attrs, &escape_xml(&self.name))
+    }
+}
+
+/// Dequeues the longest-waiting caller from a named queue.
+pub struct Queue {
+    pub name: String,
+    /// TwiML played to the dequeued caller before bridging.
+    pub url: Option<String>,
+    pub method: Option<Method>,
+    /// The TaskRouter reservation being accepted.
+    pub reservation_sid: Option<String>,
+    pub post_work_activity_sid: Option<String>,
+}
+
+impl Queue {
+    pub fn new(name: &str) -> Queue {
+        Queue {
+            name: name.to_string(),
+            url: None,
+            method: None,
+            reservation_sid: None,
+            post_work_activity_sid: None,
+        }
+    }
+}
+
+impl Action for Queue {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        push_url(&self.url, &self.method, &mut attrs);
+        if let Some(ref r) = self.reservation_sid {
+            attrs.push(("reservationSid", r.as_str()));
+        }
+        if let Some(ref p) = self.post_work_activity_sid {
+            attrs.push(("postWorkActivitySid", p));
+        }
+        format_xml_string("Queue", &attrs, &escape_xml(&self.name))
+    }
+}
+
+/// A bare number in the text becomes `number`; nested nouns become `nouns`.
+impl TryFrom<&Element> for Dial {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Dial, InvalidTwiml> {
+        let mut r = Reader::new(e, "Dial")?;
+        let d = Dial::default();
+        let mut dial = Dial {
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(d.method),
+            timeout_seconds: r.number("timeout")?.unwrap_or(d.timeout_seconds),
+            caller_id: r.string("callerId"),
+            time_limit_seconds: r.number("timeLimit")?,
+            hangup_on_star: r.bool("hangupOnStar")?.unwrap_or(d.hangup_on_star),
+            record: r
+                .map("record", |v| match v {
+                    "record-from-answer" => Some(true),
+                    "do-not-record" | "false" => Some(false),
+                    _ => None,
+                })?
+                .unwrap_or(d.record),
+            ..d
+        };
+        if e.elements().next().is_some() {
+            dial.nouns = r
+                .children()?
+                .into_iter()
+                .map(DialNoun::try_from)
+                .collect::<Result<_, _>>()?;
+        } else {
+            dial.number = r.text()?.trim().to_string();
+        }
+        r.finish()?;
+        Ok(dial)
+    }
+}
+
+impl TryFrom<&Element> for DialNoun {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<DialNoun, InvalidTwiml> {
+        match e.name.as_str() {
+            "Number" => Number::try_from(e).map(DialNoun::Number),
+            "Sip" => Sip::try_from(e).map(DialNoun::Sip),
+            "Client" => Client::try_from(e).map(DialNoun::Client),
+            "Conference" => Conference::try_from(e).map(DialNoun::Conference),
+            "Queue" => Queue::try_from(e).map(DialNoun::Queue),
+            other => Err(InvalidTwiml::Unsupported {
+                element: "Dial".to_string(),
+                item: format!("<{other}>"),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Element> for Number {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Number, InvalidTwiml> {
+        let mut r = Reader::new(e, "Number")?;
+        let number = Number {
+            send_digits: r.string("sendDigits"),
+            url: r.string("url"),
+            method: r.method("method")?,
+            status_callback: StatusCallback::read(&mut r)?,
+            byoc: r.string("byoc"),
+            number: r.text()?.trim().to_string(),
+        };
+        r.finish()?;
+        Ok(number)
+    }
+}
+
+/// Headers in the URI's query, e.g. `sip:a@example.com?X-Id=1`, become
+/// `headers`.
+impl TryFrom<&Element> for Sip {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Sip, InvalidTwiml> {
+        let mut r = Reader::new(e, "Sip")?;
+        let (uri, headers) = split_sip_uri(r.text()?.trim());
+        let sip = Sip {
+            uri,
+            headers,
+            username: r.string("username"),
+            password: r.string("password"),
+            url: r.string("url"),
+            method: r.method("method")?,
+            status_callback: StatusCallback::read(&mut r)?,
+        };
+        r.finish()?;
+        Ok(sip)
+    }
+}
+
+/// Takes the identity from either the text or an `<Identity>`.
+impl TryFrom<&Element> for Client {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Client, InvalidTwiml> {
+        let mut r = Reader::new(e, "Client")?;
+        let mut client = Client {
+            url: r.string("url"),
+            method: r.method("method")?,
+            status_callback: StatusCallback::read(&mut r)?,
+            ..Client::new("")
+        };
+        if e.elements().next().is_some() {
+            for child in r.children()? {
+                match child.name.as_str() {
+                    "Identity" => {
+                        let mut identity = Reader::new(child, "Identity")?;
+                        client.identity = identity.text()?.trim().to_string();
+                        identity.finish()?;
+                    }
+                    "Parameter" => client.parameters.push(Parameter::try_from(child)?),
+                    other => return Err(r.unsupported(&format!("<{other}>"))),
+                }
+            }
+        } else {
+            client.identity = r.text()?.trim().to_string();
+        }
+        r.finish()?;
+        Ok(client)
+    }
+}
+
+impl TryFrom<&Element> for Conference {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Conference, InvalidTwiml> {
+        let mut r = Reader::new(e, "Conference")?;
+        let conference = Conference {
+            muted: r.bool("muted")?,
+            beep: r.map("beep", Beep::parse)?,
+            start_conference_on_enter: r.bool("startConferenceOnEnter")?,
+            end_conference_on_exit: r.bool("endConferenceOnExit")?,
+            wait_url: r.string("waitUrl"),
+            wait_method: r.method("waitMethod")?,
+            max_participants: r.number("maxParticipants")?,
+            record: r
+                .map("record", |v| match v {
+                    "record-from-start" => Some(true),
+                    "do-not-record" => Some(false),
+                    _ => None,
+                })?
+                .unwrap_or(false),
+            trim: r.map("trim", trim)?,
+            region: r.string("region"),
+            coach: r.string("coach"),
+            participant_label: r.string("participantLabel"),
+            status_callback: r.string("statusCallback"),
+            status_callback_method: r.method("statusCallbackMethod")?,
+            status_callback_events: r.list("statusCallbackEvent", ConferenceEvent::parse)?,
+            recording_status_callback: r.string("recordingStatusCallback"),
+            recording_status_callback_method: r.method("recordingStatusCallbackMethod")?,
+            name: r.text()?.trim().to_string(),
+        };
+        r.finish()?;
+        Ok(conference)
+    }
+}
+
+impl TryFrom<&Element> for Queue {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Queue, InvalidTwiml> {
+        let mut r = Reader::new(e, "Queue")?;
+        let queue = Queue {
+            url: r.string("url"),
+            method: r.method("method")?,
+            reservation_sid: r.string("reservationSid"),
+            post_work_activity_sid: r.string("postWorkActivitySid"),
+            name: r.text()?.trim().to_string(),
+        };
+        r.finish()?;
+        Ok(queue)
+    }
+}
@@ -0,0 +1,178 @@
+use super::convert::Reader;
+use super::{
+    escape_xml, format_xml_string, Action, Element, InvalidTwiml, Method, Node, VoiceAction,
+};
+use std::convert::TryFrom;
+
+/// The task created when a call is enqueued into a TaskRouter workflow.
+pub struct Task {
+    /// Matched against the workflow's filters, e.g.
+    /// `{"selected_language": "es"}`.
+    pub attributes: serde_json::Value,
+    pub priority: Option<u32>,
+    pub timeout_seconds: Option<u32>,
+}
+
+impl Task {
+    pub fn new(attributes: serde_json::Value) -> Task {
+        Task {
+            attributes,
+            priority: None,
+            timeout_seconds: None,
+        }
+    }
+}
+
+impl Action for Task {
+    fn as_twiml(&self) -> String {
+        let priority_string = self.priority.map(|p| p.to_string());
+        let timeout_string = self.timeout_seconds.map(|t| t.to_string());
+        let mut attrs = Vec::new();
+        if let Some(ref p) = priority_string {
+            attrs.push(("priority", p.as_str()));
+        }
+        if let Some(ref t) = timeout_string {
+            attrs.push(("timeout", t));
+        }
+        format_xml_string("Task", &attrs, &escape_xml(&self.attributes.to_string()))
+    }
+}
+
+/// Puts the caller in a queue, where they hear `wait_url`'s TwiML until
+/// someone `<Dial>`s the queue or a `<Leave>` runs. With `workflow_sid` the
+/// call becomes a TaskRouter task instead of joining a named queue.
+pub struct Enqueue {
+    /// The queue to join, created if it doesn't exist. Leave empty with
+    /// `workflow_sid`.
+    pub name: String,
+    /// Requested once the caller leaves the queue, with `QueueResult` set to
+    /// e.g. `bridged`, `hangup` or `leave`.
+    pub action: Option<String>,
+    pub method: Method,
+    /// TwiML played in a loop while the caller waits. Only `<Play>`, `<Say>`,
+    /// `<Pause>`, `<Hangup>`, `<Redirect>`, `<Leave>` and `<Gather>` are
+    /// allowed there.
+    pub wait_url: Option<String>,
+    pub wait_url_method: Option<Method>,
+    pub workflow_sid: Option<String>,
+    pub task: Option<Task>,
+}
+
+impl Enqueue {
+    pub fn new(name: &str) -> Enqueue {
+        Enqueue {
+            name: name.to_string(),
+            action: None,
+            method: Method::Post,
+            wait_url: None,
+            wait_url_method: None,
+            workflow_sid: None,
+            task: None,
+        }
+    }
+
+    /// Creates a task in a TaskRouter workflow for the call.
+    pub fn workflow(workflow_sid: &str, task: Task) -> Enqueue {
+        Enqueue {
+            workflow_sid: Some(workflow_sid.to_string()),
+            task: Some(task),
+            ..Enqueue::new("")
+        }
+    }
+}
+
+impl Action for Enqueue {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a.as_str()));
+            attrs.push(("method", self.method.as_str()));
+        }
+        if let Some(ref w) = self.wait_url {
+            attrs.push(("waitUrl", w));
+            if let Some(ref m) = self.wait_url_method {
+                attrs.push(("waitUrlMethod", m.as_str()));
+            }
+        }
+        if let Some(ref w) = self.workflow_sid {
+            attrs.push(("workflowSid", w));
+        }
+        let mut inner = escape_xml(&self.name);
+        if let Some(ref t) = self.task {
+            inner.push_str(&t.as_twiml());
+        }
+        format_xml_string("Enqueue", &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Enqueue {}
+
+/// Takes the caller out of the queue they are waiting in, continuing with
+/// the `<Enqueue>`'s `action`. Only valid in a queue's `wait_url` TwiML.
+pub struct Leave;
+
+impl Action for Leave {
+    fn as_twiml(&self) -> String {
+        format_xml_string("Leave", &[], "")
+    }
+}
+
+impl VoiceAction for Leave {}
+
+impl TryFrom<&Element> for Task {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Task, InvalidTwiml> {
+        let mut r = Reader::new(e, "Task")?;
+        let priority = r.number("priority")?;
+        let timeout_seconds = r.number("timeout")?;
+        let text = r.text()?;
+        let attributes = serde_json::from_str(&text).map_err(|_| InvalidTwiml::InvalidText {
+            element: "Task".to_string(),
+            text,
+        })?;
+        r.finish()?;
+        Ok(Task {
+            attributes,
+            priority,
+            timeout_seconds,
+        })
+    }
+}
+
+impl TryFrom<&Element> for Enqueue {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Enqueue, InvalidTwiml> {
+        let mut r = Reader::new(e, "Enqueue")?;
+        let mut enqueue = Enqueue {
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(Method::Post),
+            wait_url: r.string("waitUrl"),
+            wait_url_method: r.method("waitUrlMethod")?,
+            workflow_sid: r.string("workflowSid"),
+            ..Enqueue::new("")
+        };
+        for node in r.content() {
+            match node {
+                Node::Text(t) => enqueue.name.push_str(t),
+                Node::Element(t) if t.name == "Task" && enqueue.task.is_none() => {
+                    enqueue.task = Some(Task::try_from(t)?);
+                }
+                Node::Element(other) => return Err(r.unsupported(&format!("<{}>", other.name))),
+            }
+        }
+        enqueue.name = enqueue.name.trim().to_string();
+        r.finish()?;
+        Ok(enqueue)
+    }
+}
+
+impl TryFrom<&Element> for Leave {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Leave, InvalidTwiml> {
+        Reader::new(e, "Leave")?.finish()?;
+        Ok(Leave)
+    }
+}
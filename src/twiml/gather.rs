@@ -1,10 +1,65 @@
-use super::{format_xml_string, Action, Method, Play, Say};
+use super::convert::{from_str, Reader};
+use super::{
+    format_xml_string, Action, Element, InvalidTwiml, Method, Pause, Play, Say, VoiceAction,
+};
+use std::convert::TryFrom;
 use std::default::Default;
 
+/// What plays while a `<Gather>` or `<Pay>` waits for input. Twilio only
+/// allows `<Say>`, `<Play>` and `<Pause>` there.
 pub enum Prompt {
     Nothing,
     Play(Play),
     Say(Say),
+    Pause(Pause),
+}
+
+impl Prompt {
+    /// Converts the `<Say>`, `<Play>` or `<Pause>` inside a `<Gather>` or
+    /// `<Prompt>`. A `Prompt` holds only one.
+    pub(super) fn read(r: &mut Reader) -> Result<Prompt, InvalidTwiml> {
+        match *r.children()? {
+            [] => Ok(Prompt::Nothing),
+            [e] => match e.name.as_str() {
+                "Say" => Say::try_from(e).map(Prompt::Say),
+                "Play" => Play::try_from(e).map(Prompt::Play),
+                "Pause" => Pause::try_from(e).map(Prompt::Pause),
+                other => Err(r.unsupported(&format!("<{other}>"))),
+            },
+            [_, e, ..] => Err(r.unsupported(&format!("a second prompt, <{}>", e.name))),
+        }
+    }
+}
+
+/// What a `<Gather>` listens for. With both, whichever the caller uses
+/// first wins.
+pub enum GatherInput {
+    Dtmf,
+    Speech,
+}
+
+impl GatherInput {
+    fn parse(s: &str) -> Option<GatherInput> {
+        from_str(
+            vec![GatherInput::Dtmf, GatherInput::Speech],
+            GatherInput::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            GatherInput::Dtmf => "dtmf",
+            GatherInput::Speech => "speech",
+        }
+    }
+}
+
+/// How long to wait after the caller stops speaking.
+pub enum SpeechTimeout {
+    /// Let Twilio decide when the caller has finished.
+    Auto,
+    Seconds(u32),
 }
 
 pub struct Gather {
@@ -14,6 +69,24 @@ pub struct Gather {
     pub finish_on_key: char,
     pub num_digits: Option<u32>,
     pub prompt: Prompt,
+    /// Twilio listens for DTMF only when empty.
+    pub input: Vec<GatherInput>,
+    /// E.g. `phone_call`, `numbers_and_commands` or
+    /// `experimental_conversations`.
+    pub speech_model: Option<String>,
+    /// Words or phrases the caller is likely to say, to improve recognition.
+    pub hints: Vec<String>,
+    /// The language spoken, e.g. `en-GB`.
+    pub language: Option<String>,
+    pub speech_timeout: Option<SpeechTimeout>,
+    pub profanity_filter: Option<bool>,
+    /// Requests `action` even when the caller says or enters nothing, instead
+    /// of moving on to the next verb.
+    pub action_on_empty_result: Option<bool>,
+    /// Uses the premium `phone_call` model.
+    pub enhanced: Option<bool>,
+    /// Receives interim speech results while the caller is still talking.
+    pub partial_result_callback: Option<String>,
 }
 
 impl Action for Gather {
@@ -21,6 +94,18 @@ impl Action for Gather {
         let timeout_string = format!("{}", self.timeout_seconds);
         let finish_string = self.finish_on_key.to_string();
         let digits_string = self.num_digits.map(|d| format!("{}", d));
+        let input_string = self
+            .input
+            .iter()
+            .map(|i| i.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let hints_string = self.hints.join(", ");
+        let speech_timeout_string = match self.speech_timeout {
+            Some(SpeechTimeout::Auto) => Some("auto".to_string()),
+            Some(SpeechTimeout::Seconds(s)) => Some(s.to_string()),
+            None => None,
+        };
         let mut attrs = Vec::new();
         let method_str = match self.method {
             Method::Get => "GET",
@@ -35,15 +120,45 @@ impl Action for Gather {
         if let Some(ref d) = digits_string {
             attrs.push(("numDigits", d.as_ref()));
         }
+        if !input_string.is_empty() {
+            attrs.push(("input", &input_string));
+        }
+        if let Some(ref m) = self.speech_model {
+            attrs.push(("speechModel", m));
+        }
+        if !hints_string.is_empty() {
+            attrs.push(("hints", &hints_string));
+        }
+        if let Some(ref l) = self.language {
+            attrs.push(("language", l));
+        }
+        if let Some(ref t) = speech_timeout_string {
+            attrs.push(("speechTimeout", t));
+        }
+        if let Some(p) = self.profanity_filter {
+            attrs.push(("profanityFilter", crate::bool_str(p)));
+        }
+        if let Some(a) = self.action_on_empty_result {
+            attrs.push(("actionOnEmptyResult", crate::bool_str(a)));
+        }
+        if let Some(e) = self.enhanced {
+            attrs.push(("enhanced", crate::bool_str(e)));
+        }
+        if let Some(ref c) = self.partial_result_callback {
+            attrs.push(("partialResultCallback", c));
+        }
         let inner = match self.prompt {
             Prompt::Nothing => "".to_string(),
             Prompt::Play(ref p) => p.as_twiml(),
             Prompt::Say(ref s) => s.as_twiml(),
+            Prompt::Pause(ref p) => p.as_twiml(),
         };
         format_xml_string("Gather", &attrs, inner.as_ref())
     }
 }
 
+impl VoiceAction for Gather {}
+
 impl Default for Gather {
     fn default() -> Gather {
         Gather {
@@ -53,6 +168,54 @@ impl Default for Gather {
             finish_on_key: '*',
             num_digits: None,
             prompt: Prompt::Nothing,
+            input: Vec::new(),
+            speech_model: None,
+            hints: Vec::new(),
+            language: None,
+            speech_timeout: None,
+            profanity_filter: None,
+            action_on_empty_result: None,
+            enhanced: None,
+            partial_result_callback: None,
         }
     }
 }
+
+impl TryFrom<&Element> for Gather {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Gather, InvalidTwiml> {
+        let mut r = Reader::new(e, "Gather")?;
+        let d = Gather::default();
+        let gather = Gather {
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(d.method),
+            timeout_seconds: r.number("timeout")?.unwrap_or(d.timeout_seconds),
+            finish_on_key: r.number("finishOnKey")?.unwrap_or(d.finish_on_key),
+            num_digits: r.number("numDigits")?,
+            input: r.list("input", GatherInput::parse)?,
+            speech_model: r.string("speechModel"),
+            hints: r
+                .string("hints")
+                .map(|h| {
+                    h.split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            language: r.string("language"),
+            speech_timeout: r.map("speechTimeout", |v| match v {
+                "auto" => Some(SpeechTimeout::Auto),
+                _ => v.parse().ok().map(SpeechTimeout::Seconds),
+            })?,
+            profanity_filter: r.bool("profanityFilter")?,
+            action_on_empty_result: r.bool("actionOnEmptyResult")?,
+            enhanced: r.bool("enhanced")?,
+            partial_result_callback: r.string("partialResultCallback"),
+            prompt: Prompt::read(&mut r)?,
+        };
+        r.finish()?;
+        Ok(gather)
+    }
+}
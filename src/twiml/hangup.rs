@@ -0,0 +1,22 @@
+use super::convert::Reader;
+use super::{format_xml_string, Action, Element, InvalidTwiml, VoiceAction};
+use std::convert::TryFrom;
+
+pub struct Hangup;
+
+impl Action for Hangup {
+    fn as_twiml(&self) -> String {
+        format_xml_string("Hangup", &[], "")
+    }
+}
+
+impl VoiceAction for Hangup {}
+
+impl TryFrom<&Element> for Hangup {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Hangup, InvalidTwiml> {
+        Reader::new(e, "Hangup")?.finish()?;
+        Ok(Hangup)
+    }
+}
@@ -1,4 +1,6 @@
-use super::{format_xml_string, Action};
+use super::convert::Reader;
+use super::{escape_xml, format_xml_string, Action, Element, InvalidTwiml, MessagingAction};
+use std::convert::TryFrom;
 
 pub struct Message {
     pub txt: String,
@@ -6,6 +8,34 @@ pub struct Message {
 
 impl Action for Message {
     fn as_twiml(&self) -> String {
-        format_xml_string("Message", &vec![], &self.txt)
+        format_xml_string("Message", &[], &escape_xml(&self.txt))
+    }
+}
+
+impl MessagingAction for Message {}
+
+/// Accepts the body as text or as a `<Body>`; `<Media>` has no field in
+/// `Message`.
+impl TryFrom<&Element> for Message {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Message, InvalidTwiml> {
+        let mut r = Reader::new(e, "Message")?;
+        let txt = if e.find("Body").is_some() {
+            let mut txt = String::new();
+            for child in r.children()? {
+                if child.name != "Body" {
+                    return Err(r.unsupported(&format!("<{}>", child.name)));
+                }
+                let mut body = Reader::new(child, "Body")?;
+                txt.push_str(&body.text()?);
+                body.finish()?;
+            }
+            txt
+        } else {
+            r.text()?
+        };
+        r.finish()?;
+        Ok(Message { txt })
     }
 }
@@ -0,0 +1,332 @@
+use super::{escape_xml, format_xml_string, Action, MessagingAction, VoiceAction};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Content of a parsed element: a nested element or text, with entities
+/// already decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+/// A verb, noun or `<Response>` read by `parse`. It renders back to TwiML
+/// through `Action`, so parsed verbs can be edited and added to a new
+/// `Twiml`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets an attribute, replacing any existing value.
+    pub fn set_attr(&mut self, name: &str, value: &str) {
+        match self.attributes.iter_mut().find(|(k, _)| k == name) {
+            Some(attr) => attr.1 = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// The child elements, skipping text.
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|c| match c {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// The first child element called `name`.
+    pub fn find(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// The text inside the element, including text nested in SSML.
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|c| match c {
+                Node::Element(e) => e.text(),
+                Node::Text(t) => t.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Action for Element {
+    fn as_twiml(&self) -> String {
+        let attrs: Vec<_> = self
+            .attributes
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let inner: String = self
+            .children
+            .iter()
+            .map(|c| match c {
+                Node::Element(e) => e.as_twiml(),
+                Node::Text(t) => escape_xml(t),
+            })
+            .collect();
+        format_xml_string(&self.name, &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Element {}
+
+impl MessagingAction for Element {}
+
+/// The SSML tags Twilio accepts inside `<Say>`.
+pub(super) const SSML: &[&str] = &[
+    "amazon:domain",
+    "amazon:effect",
+    "break",
+    "emphasis",
+    "lang",
+    "p",
+    "phoneme",
+    "prosody",
+    "s",
+    "say-as",
+    "sub",
+    "w",
+];
+
+/// How deeply elements may nest. Real TwiML rarely goes past five levels;
+/// the limit keeps a hostile document from exhausting the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Why `parse` rejected a document. `position` is a byte offset.
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid TwiML at byte {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a TwiML document, such as one stored in a TwiML Bin, returning its
+/// root element, normally `<Response>`. Comments, the XML declaration and
+/// whitespace between elements are dropped, except inside `<Say>` and its
+/// SSML, where spaces separate words. Convert the verbs into builders with
+/// `TryFrom`, e.g. `Say::try_from(&element)`.
+pub fn parse(xml: &str) -> Result<Element, ParseError> {
+    let mut parser = Parser { xml, pos: 0 };
+    parser.skip_misc()?;
+    let root = parser.element(0)?;
+    parser.skip_misc()?;
+    if parser.pos < xml.len() {
+        return Err(parser.error("content after the root element"));
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    xml: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.pos..]
+    }
+
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            message: message.to_string(),
+            position: self.pos,
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{s}`")))
+        }
+    }
+
+    /// Advances past `end`, returning what came before it.
+    fn until(&mut self, end: &str) -> Result<&'a str, ParseError> {
+        match self.rest().find(end) {
+            Some(i) => {
+                let s = &self.rest()[..i];
+                self.pos += i + end.len();
+                Ok(s)
+            }
+            None => Err(self.error(&format!("missing `{end}`"))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.xml.len() - trimmed.len();
+    }
+
+    /// Skips whitespace, comments and processing instructions such as the
+    /// XML declaration.
+    fn skip_misc(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.until("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.until("-->")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let len = self
+            .rest()
+            .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/' | '='))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        let name = &self.rest()[..len];
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn element(&mut self, depth: usize) -> Result<Element, ParseError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error(&format!("elements nested more than {MAX_DEPTH} deep")));
+        }
+        self.expect("<")?;
+        let name = self.name()?.to_string();
+        let keep_whitespace = name == "Say" || SSML.contains(&name.as_str());
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(Element {
+                    name,
+                    attributes,
+                    children: Vec::new(),
+                });
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            let key = self.name()?.to_string();
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(q @ ('"' | '\'')) => q,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.pos += 1;
+            let start = self.pos;
+            let raw = self.until(&quote.to_string())?;
+            attributes.push((key, unescape(raw, start)?));
+        }
+
+        let mut children = Vec::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                let start = self.pos;
+                self.pos += 2;
+                if self.name()? != name {
+                    self.pos = start;
+                    return Err(self.error(&format!("expected `</{name}>`")));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(Element {
+                    name,
+                    attributes,
+                    children,
+                });
+            } else if rest.starts_with("<!--") {
+                self.until("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let text = self.until("]]>")?;
+                push_text(&mut children, text.to_string());
+            } else if rest.starts_with('<') {
+                children.push(Node::Element(self.element(depth + 1)?));
+            } else if rest.is_empty() {
+                return Err(self.error(&format!("missing `</{name}>`")));
+            } else {
+                let start = self.pos;
+                let len = rest.find('<').unwrap_or(rest.len());
+                self.pos += len;
+                let raw = &rest[..len];
+                if keep_whitespace || !raw.trim().is_empty() {
+                    push_text(&mut children, unescape(raw, start)?);
+                }
+            }
+        }
+    }
+}
+
+fn push_text(children: &mut Vec<Node>, text: String) {
+    match children.last_mut() {
+        Some(Node::Text(t)) => t.push_str(&text),
+        _ => children.push(Node::Text(text)),
+    }
+}
+
+fn unescape(raw: &str, position: usize) -> Result<String, ParseError> {
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        let error = |message: &str| ParseError {
+            message: message.to_string(),
+            position: position + (raw.len() - rest.len()) + i,
+        };
+        let end = rest[i..]
+            .find(';')
+            .ok_or_else(|| error("unterminated entity"))?;
+        let entity = &rest[i + 1..i + end];
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()
+                } else if let Some(dec) = entity.strip_prefix('#') {
+                    dec.parse().ok()
+                } else {
+                    None
+                };
+                code.and_then(char::from_u32)
+                    .ok_or_else(|| error(&format!("unknown entity `&{entity};`")))?
+            }
+        };
+        out.push(c);
+        rest = &rest[i + end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
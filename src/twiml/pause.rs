@@ -0,0 +1,36 @@
+use super::convert::Reader;
+use super::{format_xml_string, Action, Element, InvalidTwiml, VoiceAction};
+use std::convert::TryFrom;
+use std::default::Default;
+
+pub struct Pause {
+    pub length_seconds: u32,
+}
+
+impl Action for Pause {
+    fn as_twiml(&self) -> String {
+        let length_string = format!("{}", self.length_seconds);
+        format_xml_string("Pause", &[("length", length_string.as_ref())], "")
+    }
+}
+
+impl VoiceAction for Pause {}
+
+impl Default for Pause {
+    fn default() -> Pause {
+        Pause { length_seconds: 1 }
+    }
+}
+
+impl TryFrom<&Element> for Pause {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Pause, InvalidTwiml> {
+        let mut r = Reader::new(e, "Pause")?;
+        let pause = Pause {
+            length_seconds: r.number("length")?.unwrap_or(1),
+        };
+        r.finish()?;
+        Ok(pause)
+    }
+}
@@ -0,0 +1,309 @@
+use super::convert::{from_str, Reader};
+use super::stream::parameters_twiml;
+use super::{
+    format_xml_string, Action, Element, InvalidTwiml, Method, Parameter, Prompt, VoiceAction,
+};
+use std::convert::TryFrom;
+use std::default::Default;
+
+pub enum PaymentMethod {
+    CreditCard,
+    AchDebit,
+}
+
+impl PaymentMethod {
+    fn parse(s: &str) -> Option<PaymentMethod> {
+        from_str(
+            vec![PaymentMethod::CreditCard, PaymentMethod::AchDebit],
+            PaymentMethod::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            PaymentMethod::CreditCard => "credit-card",
+            PaymentMethod::AchDebit => "ach-debit",
+        }
+    }
+}
+
+/// What the connector returns instead of charging, when `charge_amount` is
+/// unset or zero.
+pub enum TokenType {
+    OneTime,
+    Reusable,
+    PaymentMethod,
+}
+
+impl TokenType {
+    fn parse(s: &str) -> Option<TokenType> {
+        from_str(
+            vec![
+                TokenType::OneTime,
+                TokenType::Reusable,
+                TokenType::PaymentMethod,
+            ],
+            TokenType::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            TokenType::OneTime => "one-time",
+            TokenType::Reusable => "reusable",
+            TokenType::PaymentMethod => "payment-method",
+        }
+    }
+}
+
+/// The input a `PayPrompt` asks the caller for.
+pub enum PayField {
+    PaymentCardNumber,
+    ExpirationDate,
+    SecurityCode,
+    PostalCode,
+    BankRoutingNumber,
+    BankAccountNumber,
+    PaymentProcessing,
+}
+
+impl PayField {
+    fn parse(s: &str) -> Option<PayField> {
+        from_str(
+            vec![
+                PayField::PaymentCardNumber,
+                PayField::ExpirationDate,
+                PayField::SecurityCode,
+                PayField::PostalCode,
+                PayField::BankRoutingNumber,
+                PayField::BankAccountNumber,
+                PayField::PaymentProcessing,
+            ],
+            PayField::as_str,
+            s,
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match *self {
+            PayField::PaymentCardNumber => "payment-card-number",
+            PayField::ExpirationDate => "expiration-date",
+            PayField::SecurityCode => "security-code",
+            PayField::PostalCode => "postal-code",
+            PayField::BankRoutingNumber => "bank-routing-number",
+            PayField::BankAccountNumber => "bank-account-number",
+            PayField::PaymentProcessing => "payment-processing",
+        }
+    }
+}
+
+/// Replaces Twilio's default prompt for one field. `attempt` and
+/// `error_type`, e.g. `invalid-card-number`, narrow it to retries.
+pub struct PayPrompt {
+    pub field: PayField,
+    pub prompt: Prompt,
+    pub attempt: Option<u32>,
+    pub error_type: Option<String>,
+    pub card_type: Option<String>,
+}
+
+impl PayPrompt {
+    pub fn new(field: PayField, prompt: Prompt) -> PayPrompt {
+        PayPrompt {
+            field,
+            prompt,
+            attempt: None,
+            error_type: None,
+            card_type: None,
+        }
+    }
+}
+
+impl Action for PayPrompt {
+    fn as_twiml(&self) -> String {
+        let attempt_string = self.attempt.map(|a| a.to_string());
+        let mut attrs = vec![("for", self.field.as_str())];
+        if let Some(ref a) = attempt_string {
+            attrs.push(("attempt", a));
+        }
+        if let Some(ref e) = self.error_type {
+            attrs.push(("errorType", e));
+        }
+        if let Some(ref c) = self.card_type {
+            attrs.push(("cardType", c));
+        }
+        let inner = match self.prompt {
+            Prompt::Nothing => "".to_string(),
+            Prompt::Play(ref p) => p.as_twiml(),
+            Prompt::Say(ref s) => s.as_twiml(),
+            Prompt::Pause(ref p) => p.as_twiml(),
+        };
+        format_xml_string("Prompt", &attrs, &inner)
+    }
+}
+
+/// Collects card or bank details over DTMF and hands them to a payment
+/// connector, without the digits ever reaching your servers. The outcome is
+/// posted to `action`; parse it with `PayResult`.
+pub struct Pay {
+    /// The connector's name in the console; Twilio uses `Default` if unset.
+    pub payment_connector: Option<String>,
+    /// A decimal amount, e.g. `"10.50"`. Leave unset to tokenize instead.
+    pub charge_amount: Option<String>,
+    pub currency: Option<String>,
+    pub token_type: Option<TokenType>,
+    pub payment_method: Option<PaymentMethod>,
+    pub description: Option<String>,
+    pub action: Option<String>,
+    pub method: Method,
+    pub status_callback: Option<String>,
+    pub timeout_seconds: Option<u32>,
+    pub max_attempts: Option<u32>,
+    pub security_code: Option<bool>,
+    pub postal_code: Option<bool>,
+    /// E.g. `visa`, `mastercard` or `amex`.
+    pub valid_card_types: Vec<String>,
+    pub language: Option<String>,
+    pub prompts: Vec<PayPrompt>,
+    /// Extra parameters passed through to the connector.
+    pub parameters: Vec<Parameter>,
+}
+
+impl Action for Pay {
+    fn as_twiml(&self) -> String {
+        let timeout_string = self.timeout_seconds.map(|t| t.to_string());
+        let attempts_string = self.max_attempts.map(|a| a.to_string());
+        let card_types = self.valid_card_types.join(" ");
+        let mut attrs = Vec::new();
+        if let Some(ref c) = self.payment_connector {
+            attrs.push(("paymentConnector", c.as_str()));
+        }
+        if let Some(ref a) = self.charge_amount {
+            attrs.push(("chargeAmount", a));
+        }
+        if let Some(ref c) = self.currency {
+            attrs.push(("currency", c));
+        }
+        if let Some(ref t) = self.token_type {
+            attrs.push(("tokenType", t.as_str()));
+        }
+        if let Some(ref m) = self.payment_method {
+            attrs.push(("paymentMethod", m.as_str()));
+        }
+        if let Some(ref d) = self.description {
+            attrs.push(("description", d));
+        }
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a));
+            attrs.push(("method", self.method.as_str()));
+        }
+        if let Some(ref s) = self.status_callback {
+            attrs.push(("statusCallback", s));
+        }
+        if let Some(ref t) = timeout_string {
+            attrs.push(("timeout", t));
+        }
+        if let Some(ref a) = attempts_string {
+            attrs.push(("maxAttempts", a));
+        }
+        if let Some(s) = self.security_code {
+            attrs.push(("securityCode", crate::bool_str(s)));
+        }
+        if let Some(p) = self.postal_code {
+            attrs.push(("postalCode", crate::bool_str(p)));
+        }
+        if !card_types.is_empty() {
+            attrs.push(("validCardTypes", &card_types));
+        }
+        if let Some(ref l) = self.language {
+            attrs.push(("language", l));
+        }
+        let mut inner: String = self.prompts.iter().map(|p| p.as_twiml()).collect();
+        inner.push_str(&parameters_twiml(&self.parameters));
+        format_xml_string("Pay", &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Pay {}
+
+impl Default for Pay {
+    fn default() -> Pay {
+        Pay {
+            payment_connector: None,
+            charge_amount: None,
+            currency: None,
+            token_type: None,
+            payment_method: None,
+            description: None,
+            action: None,
+            method: Method::Post,
+            status_callback: None,
+            timeout_seconds: None,
+            max_attempts: None,
+            security_code: None,
+            postal_code: None,
+            valid_card_types: Vec::new(),
+            language: None,
+            prompts: Vec::new(),
+            parameters: Vec::new(),
+        }
+    }
+}
+
+/// Converts a `<Prompt>` inside `<Pay>`.
+impl TryFrom<&Element> for PayPrompt {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<PayPrompt, InvalidTwiml> {
+        let mut r = Reader::new(e, "Prompt")?;
+        let field = r
+            .map("for", PayField::parse)?
+            .ok_or_else(|| r.missing("for"))?;
+        let prompt = PayPrompt {
+            field,
+            attempt: r.number("attempt")?,
+            error_type: r.string("errorType"),
+            card_type: r.string("cardType"),
+            prompt: Prompt::read(&mut r)?,
+        };
+        r.finish()?;
+        Ok(prompt)
+    }
+}
+
+impl TryFrom<&Element> for Pay {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Pay, InvalidTwiml> {
+        let mut r = Reader::new(e, "Pay")?;
+        let mut pay = Pay {
+            payment_connector: r.string("paymentConnector"),
+            charge_amount: r.string("chargeAmount"),
+            currency: r.string("currency"),
+            token_type: r.map("tokenType", TokenType::parse)?,
+            payment_method: r.map("paymentMethod", PaymentMethod::parse)?,
+            description: r.string("description"),
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(Method::Post),
+            status_callback: r.string("statusCallback"),
+            timeout_seconds: r.number("timeout")?,
+            max_attempts: r.number("maxAttempts")?,
+            security_code: r.bool("securityCode")?,
+            postal_code: r.bool("postalCode")?,
+            valid_card_types: r.list("validCardTypes", |t| Some(t.to_string()))?,
+            language: r.string("language"),
+            ..Pay::default()
+        };
+        for child in r.children()? {
+            match child.name.as_str() {
+                "Prompt" => pay.prompts.push(PayPrompt::try_from(child)?),
+                "Parameter" => pay.parameters.push(Parameter::try_from(child)?),
+                other => return Err(r.unsupported(&format!("<{other}>"))),
+            }
+        }
+        r.finish()?;
+        Ok(pay)
+    }
+}
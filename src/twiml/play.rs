@@ -1,10 +1,18 @@
-use super::{format_xml_string, Action};
+use super::convert::Reader;
+use super::{escape_xml, format_xml_string, Action, Element, InvalidTwiml, VoiceAction};
 use std::char::from_digit;
+use std::convert::TryFrom;
 
 pub struct Digits {
     s: String,
 }
 
+impl Default for Digits {
+    fn default() -> Digits {
+        Digits::new()
+    }
+}
+
 impl Digits {
     pub fn new() -> Digits {
         Digits { s: "".to_string() }
@@ -20,6 +28,14 @@ impl Digits {
     fn as_str(&self) -> &str {
         self.s.as_ref()
     }
+
+    fn parse(s: &str) -> Option<Digits> {
+        if s.chars().all(|c| c.is_ascii_digit() || c == 'w') {
+            Some(Digits { s: s.to_string() })
+        } else {
+            None
+        }
+    }
 }
 
 pub enum Playable {
@@ -28,8 +44,8 @@ pub enum Playable {
 }
 
 pub struct Play {
-    playable: Playable,
-    loop_count: usize,
+    pub playable: Playable,
+    pub loop_count: usize,
 }
 
 impl Action for Play {
@@ -38,12 +54,32 @@ impl Action for Play {
         let mut atts = Vec::new();
         atts.push(("loop", &loop_string[..]));
         let inner = match self.playable {
-            Playable::Url(ref s) => s.as_ref(),
+            Playable::Url(ref s) => escape_xml(s),
             Playable::Digits(ref d) => {
                 atts.push(("digits", d.as_str()));
-                ""
+                "".to_string()
             }
         };
-        format_xml_string("Play", &atts, inner)
+        format_xml_string("Play", &atts, &inner)
+    }
+}
+
+impl VoiceAction for Play {}
+
+impl TryFrom<&Element> for Play {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Play, InvalidTwiml> {
+        let mut r = Reader::new(e, "Play")?;
+        let loop_count = r.number("loop")?.unwrap_or(1);
+        let playable = match r.map("digits", Digits::parse)? {
+            Some(d) => Playable::Digits(d),
+            None => Playable::Url(r.text()?.trim().to_string()),
+        };
+        r.finish()?;
+        Ok(Play {
+            playable,
+            loop_count,
+        })
     }
 }
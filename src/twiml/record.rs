@@ -1,4 +1,6 @@
-use super::{format_xml_string, Action, Method};
+use super::convert::{trim, Reader};
+use super::{format_xml_string, Action, Element, InvalidTwiml, Method, VoiceAction};
+use std::convert::TryFrom;
 use std::default::Default;
 
 pub enum Transcribe {
@@ -60,6 +62,8 @@ impl Action for Record {
     }
 }
 
+impl VoiceAction for Record {}
+
 impl Default for Record {
     fn default() -> Record {
         Record {
@@ -74,3 +78,28 @@ impl Default for Record {
         }
     }
 }
+
+impl TryFrom<&Element> for Record {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Record, InvalidTwiml> {
+        let mut r = Reader::new(e, "Record")?;
+        let d = Record::default();
+        let record = Record {
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(d.method),
+            timeout_seconds: r.number("timeout")?.unwrap_or(d.timeout_seconds),
+            finish_on_key: r.number("finishOnKey")?.unwrap_or(d.finish_on_key),
+            max_length_seconds: r.number("maxLength")?.unwrap_or(d.max_length_seconds),
+            play_beep: r.bool("playBeep")?.unwrap_or(d.play_beep),
+            trim: r.map("trim", trim)?.unwrap_or(d.trim),
+            transcribe: match (r.string("transcribeCallback"), r.bool("transcribe")?) {
+                (Some(url), _) => Transcribe::CallbackTranscription(url),
+                (None, Some(true)) => Transcribe::StoreTranscription,
+                (None, _) => Transcribe::DontTranscribe,
+            },
+        };
+        r.finish()?;
+        Ok(record)
+    }
+}
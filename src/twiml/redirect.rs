@@ -1,4 +1,9 @@
-use super::{format_xml_string, Action, Method};
+use super::convert::Reader;
+use super::{
+    escape_xml, format_xml_string, Action, Element, InvalidTwiml, MessagingAction, Method,
+    VoiceAction,
+};
+use std::convert::TryFrom;
 
 pub struct Redirect {
     pub url: String,
@@ -11,6 +16,28 @@ impl Action for Redirect {
             Method::Get => "GET",
             Method::Post => "POST",
         };
-        format_xml_string("Redirect", &vec![("method", method_str)], &self.url)
+        format_xml_string(
+            "Redirect",
+            &[("method", method_str)],
+            &escape_xml(&self.url),
+        )
+    }
+}
+
+impl VoiceAction for Redirect {}
+
+impl MessagingAction for Redirect {}
+
+impl TryFrom<&Element> for Redirect {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Redirect, InvalidTwiml> {
+        let mut r = Reader::new(e, "Redirect")?;
+        let redirect = Redirect {
+            method: r.method("method")?.unwrap_or(Method::Post),
+            url: r.text()?.trim().to_string(),
+        };
+        r.finish()?;
+        Ok(redirect)
     }
 }
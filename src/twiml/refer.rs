@@ -0,0 +1,73 @@
+use super::convert::Reader;
+use super::{
+    escape_xml, format_xml_string, sip_uri, split_sip_uri, Action, Element, InvalidTwiml, Method,
+    VoiceAction,
+};
+use std::convert::TryFrom;
+
+/// Hands a SIP call back to the PBX or carrier that sent it with a SIP REFER,
+/// freeing the Twilio leg. Only works on calls that arrived over SIP.
+pub struct Refer {
+    /// Where the call is transferred, e.g. `sip:alice@example.com`.
+    pub uri: String,
+    /// Custom headers added to the Refer-To URI, conventionally prefixed `X-`.
+    pub headers: Vec<(String, String)>,
+    /// Receives the outcome in `ReferCallStatus` and `ReferSipResponseCode`.
+    pub action: Option<String>,
+    pub method: Method,
+}
+
+impl Refer {
+    pub fn new(uri: &str) -> Refer {
+        Refer {
+            uri: uri.to_string(),
+            headers: Vec::new(),
+            action: None,
+            method: Method::Post,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Refer {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl Action for Refer {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a.as_str()));
+            attrs.push(("method", self.method.as_str()));
+        }
+
+        let uri = sip_uri(
+            &self.uri,
+            self.headers.iter().map(|(k, v)| (k.as_str(), v.as_str())),
+        );
+        let sip = format_xml_string("Sip", &[], &escape_xml(&uri));
+        format_xml_string("Refer", &attrs, &sip)
+    }
+}
+
+impl VoiceAction for Refer {}
+
+impl TryFrom<&Element> for Refer {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Refer, InvalidTwiml> {
+        let mut r = Reader::new(e, "Refer")?;
+        let action = r.string("action");
+        let method = r.method("method")?.unwrap_or(Method::Post);
+        let mut sip = Reader::new(r.only_child()?, "Sip")?;
+        let (uri, headers) = split_sip_uri(sip.text()?.trim());
+        sip.finish()?;
+        r.finish()?;
+        Ok(Refer {
+            uri,
+            headers,
+            action,
+            method,
+        })
+    }
+}
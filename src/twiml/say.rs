@@ -1,28 +1,286 @@
-use super::{format_xml_string, Action};
+use super::convert::Reader;
+use super::{escape_xml, format_xml_string, Action, Element, InvalidTwiml, Node, VoiceAction};
+use std::convert::TryFrom;
 
 pub enum Voice {
     Man,
     Woman,
     Alice,
+    /// An Amazon Polly voice, e.g. `Polly("Joanna-Neural")`. Polly and
+    /// Google voices are the ones that honor SSML.
+    Polly(String),
+    /// A Google text-to-speech voice, e.g. `Google("en-US-Neural2-F")`.
+    Google(String),
+}
+
+impl Voice {
+    fn parse(s: &str) -> Option<Voice> {
+        match s {
+            "man" => Some(Voice::Man),
+            "woman" => Some(Voice::Woman),
+            "alice" => Some(Voice::Alice),
+            _ => {
+                if let Some(name) = s.strip_prefix("Polly.") {
+                    Some(Voice::Polly(name.to_string()))
+                } else {
+                    s.strip_prefix("Google.")
+                        .map(|name| Voice::Google(name.to_string()))
+                }
+            }
+        }
+    }
+}
+
+/// Markup spoken inside a `<Say>`. Text is escaped when rendered.
+pub enum Ssml {
+    Text(String),
+    /// A pause, e.g. `time: Some("500ms")` or `strength: Some("strong")`.
+    Break {
+        strength: Option<String>,
+        time: Option<String>,
+    },
+    /// `level` is `strong`, `moderate` or `reduced`.
+    Emphasis {
+        level: Option<String>,
+        content: Vec<Ssml>,
+    },
+    /// E.g. `rate: Some("slow")`, `pitch: Some("+10%")`, `volume: Some("loud")`.
+    Prosody {
+        rate: Option<String>,
+        pitch: Option<String>,
+        volume: Option<String>,
+        content: Vec<Ssml>,
+    },
+    /// Pronounces `text` as `ph`, written in `alphabet`, `ipa` or `x-sampa`.
+    Phoneme {
+        alphabet: Option<String>,
+        ph: String,
+        text: String,
+    },
+    /// Reads `text` as e.g. `spell-out`, `telephone` or `date` with `format`.
+    SayAs {
+        interpret_as: String,
+        format: Option<String>,
+        text: String,
+    },
+}
+
+impl Ssml {
+    pub fn text(text: &str) -> Ssml {
+        Ssml::Text(text.to_string())
+    }
+
+    pub fn pause(time: &str) -> Ssml {
+        Ssml::Break {
+            strength: None,
+            time: Some(time.to_string()),
+        }
+    }
+
+    pub fn say_as(interpret_as: &str, text: &str) -> Ssml {
+        Ssml::SayAs {
+            interpret_as: interpret_as.to_string(),
+            format: None,
+            text: text.to_string(),
+        }
+    }
+}
+
+fn push_attr<'a>(attrs: &mut Vec<(&'a str, &'a str)>, name: &'a str, value: &'a Option<String>) {
+    if let Some(ref v) = value {
+        attrs.push((name, v));
+    }
+}
+
+fn ssml_twiml(content: &[Ssml]) -> String {
+    content.iter().map(|s| s.as_twiml()).collect()
+}
+
+impl Action for Ssml {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        match *self {
+            Ssml::Text(ref t) => escape_xml(t),
+            Ssml::Break {
+                ref strength,
+                ref time,
+            } => {
+                push_attr(&mut attrs, "strength", strength);
+                push_attr(&mut attrs, "time", time);
+                format_xml_string("break", &attrs, "")
+            }
+            Ssml::Emphasis {
+                ref level,
+                ref content,
+            } => {
+                push_attr(&mut attrs, "level", level);
+                format_xml_string("emphasis", &attrs, &ssml_twiml(content))
+            }
+            Ssml::Prosody {
+                ref rate,
+                ref pitch,
+                ref volume,
+                ref content,
+            } => {
+                push_attr(&mut attrs, "rate", rate);
+                push_attr(&mut attrs, "pitch", pitch);
+                push_attr(&mut attrs, "volume", volume);
+                format_xml_string("prosody", &attrs, &ssml_twiml(content))
+            }
+            Ssml::Phoneme {
+                ref alphabet,
+                ref ph,
+                ref text,
+            } => {
+                push_attr(&mut attrs, "alphabet", alphabet);
+                attrs.push(("ph", ph));
+                format_xml_string("phoneme", &attrs, &escape_xml(text))
+            }
+            Ssml::SayAs {
+                ref interpret_as,
+                ref format,
+                ref text,
+            } => {
+                attrs.push(("interpret-as", interpret_as));
+                push_attr(&mut attrs, "format", format);
+                format_xml_string("say-as", &attrs, &escape_xml(text))
+            }
+        }
+    }
 }
 
 pub struct Say {
     pub txt: String,
     pub voice: Voice,
     pub language: String,
+    /// Spoken after `txt`.
+    pub ssml: Vec<Ssml>,
+}
+
+impl Say {
+    pub fn new(txt: &str, voice: Voice, language: &str) -> Say {
+        Say {
+            txt: txt.to_string(),
+            voice,
+            language: language.to_string(),
+            ssml: Vec::new(),
+        }
+    }
+
+    /// A `<Say>` spoken entirely from SSML.
+    pub fn ssml(ssml: Vec<Ssml>, voice: Voice, language: &str) -> Say {
+        Say {
+            txt: String::new(),
+            voice,
+            language: language.to_string(),
+            ssml,
+        }
+    }
 }
 
 impl Action for Say {
     fn as_twiml(&self) -> String {
-        let voice_str = match self.voice {
-            Voice::Man => "man",
-            Voice::Woman => "woman",
-            Voice::Alice => "alice",
+        let voice_string = match self.voice {
+            Voice::Man => "man".to_string(),
+            Voice::Woman => "woman".to_string(),
+            Voice::Alice => "alice".to_string(),
+            Voice::Polly(ref name) => format!("Polly.{name}"),
+            Voice::Google(ref name) => format!("Google.{name}"),
         };
+        let mut inner = escape_xml(&self.txt);
+        inner.push_str(&ssml_twiml(&self.ssml));
         format_xml_string(
             "Say",
-            &vec![("voice", voice_str), ("language", &self.language)],
-            &self.txt,
+            &[("voice", &voice_string), ("language", &self.language)],
+            &inner,
         )
     }
 }
+
+impl VoiceAction for Say {}
+
+/// Supports the SSML tags `Ssml` has a variant for; others, such as `<p>` or
+/// `<sub>`, are `InvalidTwiml::Unsupported`.
+impl TryFrom<&Node> for Ssml {
+    type Error = InvalidTwiml;
+
+    fn try_from(node: &Node) -> Result<Ssml, InvalidTwiml> {
+        let e = match node {
+            Node::Text(t) => return Ok(Ssml::Text(t.clone())),
+            Node::Element(e) => e,
+        };
+        let mut r = Reader::new(e, "break")
+            .or_else(|_| Reader::new(e, "emphasis"))
+            .or_else(|_| Reader::new(e, "prosody"))
+            .or_else(|_| Reader::new(e, "phoneme"))
+            .or_else(|_| Reader::new(e, "say-as"))
+            .map_err(|_| InvalidTwiml::Unsupported {
+                element: "Say".to_string(),
+                item: format!("<{}>", e.name),
+            })?;
+        let ssml = match e.name.as_str() {
+            "break" => Ssml::Break {
+                strength: r.string("strength"),
+                time: r.string("time"),
+            },
+            "emphasis" => Ssml::Emphasis {
+                level: r.string("level"),
+                content: ssml_content(r.content())?,
+            },
+            "prosody" => Ssml::Prosody {
+                rate: r.string("rate"),
+                pitch: r.string("pitch"),
+                volume: r.string("volume"),
+                content: ssml_content(r.content())?,
+            },
+            "phoneme" => Ssml::Phoneme {
+                alphabet: r.string("alphabet"),
+                ph: r.required("ph")?,
+                text: r.text()?,
+            },
+            _ => Ssml::SayAs {
+                interpret_as: r.required("interpret-as")?,
+                format: r.string("format"),
+                text: r.text()?,
+            },
+        };
+        r.finish()?;
+        Ok(ssml)
+    }
+}
+
+fn ssml_content(nodes: &[Node]) -> Result<Vec<Ssml>, InvalidTwiml> {
+    nodes.iter().map(Ssml::try_from).collect()
+}
+
+/// Text before the first SSML tag becomes `txt`, and the rest `ssml`.
+/// Without a `voice` or `language`, Twilio's `man` and `en-US` are used.
+impl TryFrom<&Element> for Say {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Say, InvalidTwiml> {
+        let mut r = Reader::new(e, "Say")?;
+        let voice = r.map("voice", Voice::parse)?.unwrap_or(Voice::Man);
+        let language = r.string("language").unwrap_or_else(|| "en-US".to_string());
+        let content = r.content();
+        let split = content
+            .iter()
+            .position(|n| matches!(n, Node::Element(_)))
+            .unwrap_or(content.len());
+        let txt = content[..split]
+            .iter()
+            .map(|n| match n {
+                Node::Text(t) => t.as_str(),
+                Node::Element(_) => "",
+            })
+            .collect();
+        let ssml = ssml_content(&content[split..])?;
+        r.finish()?;
+        Ok(Say {
+            txt,
+            voice,
+            language,
+            ssml,
+        })
+    }
+}
@@ -1,4 +1,6 @@
-use super::{format_xml_string, Action, Method};
+use super::convert::Reader;
+use super::{escape_xml, format_xml_string, Action, Element, InvalidTwiml, Method, VoiceAction};
+use std::convert::TryFrom;
 use std::default::Default;
 
 pub struct Sms {
@@ -30,10 +32,12 @@ impl Action for Sms {
         if let Some(ref c) = self.status_callback {
             attrs.push(("statusCallback", c));
         }
-        format_xml_string("Sms", &attrs, self.txt.as_ref())
+        format_xml_string("Sms", &attrs, &escape_xml(&self.txt))
     }
 }
 
+impl VoiceAction for Sms {}
+
 impl Default for Sms {
     fn default() -> Sms {
         Sms {
@@ -46,3 +50,21 @@ impl Default for Sms {
         }
     }
 }
+
+impl TryFrom<&Element> for Sms {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Sms, InvalidTwiml> {
+        let mut r = Reader::new(e, "Sms")?;
+        let sms = Sms {
+            action: r.string("action"),
+            method: r.method("method")?.unwrap_or(Method::Post),
+            from: r.string("from"),
+            to: r.string("to"),
+            status_callback: r.string("statusCallback"),
+            txt: r.text()?,
+        };
+        r.finish()?;
+        Ok(sms)
+    }
+}
@@ -0,0 +1,138 @@
+use super::convert::Reader;
+use super::stream::{parameters_twiml, read_parameters};
+use super::{
+    format_xml_string, Action, Element, InvalidTwiml, Method, Parameter, Stream, Track, VoiceAction,
+};
+use std::convert::TryFrom;
+
+/// Forks call audio to a SIPREC recorder configured as a Twilio connector.
+pub struct Siprec {
+    pub connector_name: String,
+    pub name: Option<String>,
+    pub track: Option<Track>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    pub parameters: Vec<Parameter>,
+}
+
+impl Siprec {
+    pub fn new(connector_name: &str) -> Siprec {
+        Siprec {
+            connector_name: connector_name.to_string(),
+            name: None,
+            track: None,
+            status_callback: None,
+            status_callback_method: None,
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl Action for Siprec {
+    fn as_twiml(&self) -> String {
+        let mut attrs = vec![("connectorName", self.connector_name.as_str())];
+        if let Some(ref n) = self.name {
+            attrs.push(("name", n));
+        }
+        if let Some(ref t) = self.track {
+            attrs.push(("track", t.as_str()));
+        }
+        if let Some(ref c) = self.status_callback {
+            attrs.push(("statusCallback", c));
+        }
+        if let Some(ref m) = self.status_callback_method {
+            attrs.push(("statusCallbackMethod", m.as_str()));
+        }
+        format_xml_string("Siprec", &attrs, &parameters_twiml(&self.parameters))
+    }
+}
+
+pub enum StartNoun {
+    Stream(Stream),
+    Siprec(Siprec),
+}
+
+/// Starts forking audio in the background while the rest of the TwiML
+/// carries on.
+pub struct Start {
+    pub action: Option<String>,
+    pub method: Method,
+    pub noun: StartNoun,
+}
+
+impl Start {
+    pub fn stream(stream: Stream) -> Start {
+        Start {
+            action: None,
+            method: Method::Post,
+            noun: StartNoun::Stream(stream),
+        }
+    }
+
+    pub fn siprec(siprec: Siprec) -> Start {
+        Start {
+            action: None,
+            method: Method::Post,
+            noun: StartNoun::Siprec(siprec),
+        }
+    }
+}
+
+impl Action for Start {
+    fn as_twiml(&self) -> String {
+        let mut attrs = Vec::new();
+        if let Some(ref a) = self.action {
+            attrs.push(("action", a.as_str()));
+            attrs.push(("method", self.method.as_str()));
+        }
+        let inner = match self.noun {
+            StartNoun::Stream(ref s) => s.as_twiml(),
+            StartNoun::Siprec(ref s) => s.as_twiml(),
+        };
+        format_xml_string("Start", &attrs, &inner)
+    }
+}
+
+impl VoiceAction for Start {}
+
+impl TryFrom<&Element> for Siprec {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Siprec, InvalidTwiml> {
+        let mut r = Reader::new(e, "Siprec")?;
+        let siprec = Siprec {
+            connector_name: r.required("connectorName")?,
+            name: r.string("name"),
+            track: r.map("track", Track::parse)?,
+            status_callback: r.string("statusCallback"),
+            status_callback_method: r.method("statusCallbackMethod")?,
+            parameters: read_parameters(&mut r)?,
+        };
+        r.finish()?;
+        Ok(siprec)
+    }
+}
+
+/// Supports `<Stream>` and `<Siprec>`; `<Transcription>` is
+/// `InvalidTwiml::Unsupported`.
+impl TryFrom<&Element> for Start {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Start, InvalidTwiml> {
+        let mut r = Reader::new(e, "Start")?;
+        let action = r.string("action");
+        let method = r.method("method")?.unwrap_or(Method::Post);
+        let child = r.only_child()?;
+        let noun = match child.name.as_str() {
+            "Stream" => StartNoun::Stream(Stream::try_from(child)?),
+            "Siprec" => StartNoun::Siprec(Siprec::try_from(child)?),
+            other => return Err(r.unsupported(&format!("<{other}>"))),
+        };
+        r.finish()?;
+        Ok(Start {
+            action,
+            method,
+            noun,
+        })
+    }
+}
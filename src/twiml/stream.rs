@@ -0,0 +1,147 @@
+use super::convert::{from_str, Reader};
+use super::{format_xml_string, Action, Element, InvalidTwiml, Method};
+use std::convert::TryFrom;
+
+/// Which side of the call a `<Stream>` or `<Siprec>` carries.
+pub enum Track {
+    Inbound,
+    Outbound,
+    Both,
+}
+
+impl Track {
+    pub(super) fn parse(s: &str) -> Option<Track> {
+        from_str(
+            vec![Track::Inbound, Track::Outbound, Track::Both],
+            Track::as_str,
+            s,
+        )
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match *self {
+            Track::Inbound => "inbound_track",
+            Track::Outbound => "outbound_track",
+            Track::Both => "both_tracks",
+        }
+    }
+}
+
+/// A custom `<Parameter>` passed along to a stream, SIPREC session or
+/// virtual agent.
+pub struct Parameter {
+    pub name: String,
+    pub value: String,
+}
+
+impl Parameter {
+    pub fn new(name: &str, value: &str) -> Parameter {
+        Parameter {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Action for Parameter {
+    fn as_twiml(&self) -> String {
+        format_xml_string(
+            "Parameter",
+            &[("name", &self.name), ("value", &self.value)],
+            "",
+        )
+    }
+}
+
+pub(crate) fn parameters_twiml(parameters: &[Parameter]) -> String {
+    parameters.iter().map(|p| p.as_twiml()).collect()
+}
+
+/// Converts the `<Parameter>`s inside `r`'s element, rejecting anything else.
+pub(super) fn read_parameters(r: &mut Reader) -> Result<Vec<Parameter>, InvalidTwiml> {
+    r.children()?
+        .into_iter()
+        .map(|e| match e.name.as_str() {
+            "Parameter" => Parameter::try_from(e),
+            other => Err(r.unsupported(&format!("<{other}>"))),
+        })
+        .collect()
+}
+
+impl TryFrom<&Element> for Parameter {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Parameter, InvalidTwiml> {
+        let mut r = Reader::new(e, "Parameter")?;
+        let parameter = Parameter {
+            name: r.required("name")?,
+            value: r.required("value")?,
+        };
+        r.finish()?;
+        Ok(parameter)
+    }
+}
+
+/// Streams call audio to a WebSocket at `url`; see the `streams` module for
+/// the protocol. Under `<Connect>` the stream is bidirectional and the call
+/// waits on it, while under `<Start>` it only receives audio.
+pub struct Stream {
+    pub url: String,
+    /// Lets a later `<Stop>` refer to the stream.
+    pub name: Option<String>,
+    /// Only honored under `<Start>`; connected streams are inbound only.
+    pub track: Option<Track>,
+    pub status_callback: Option<String>,
+    pub status_callback_method: Option<Method>,
+    pub parameters: Vec<Parameter>,
+}
+
+impl Stream {
+    pub fn new(url: &str) -> Stream {
+        Stream {
+            url: url.to_string(),
+            name: None,
+            track: None,
+            status_callback: None,
+            status_callback_method: None,
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl Action for Stream {
+    fn as_twiml(&self) -> String {
+        let mut attrs = vec![("url", self.url.as_str())];
+        if let Some(ref n) = self.name {
+            attrs.push(("name", n));
+        }
+        if let Some(ref t) = self.track {
+            attrs.push(("track", t.as_str()));
+        }
+        if let Some(ref c) = self.status_callback {
+            attrs.push(("statusCallback", c));
+        }
+        if let Some(ref m) = self.status_callback_method {
+            attrs.push(("statusCallbackMethod", m.as_str()));
+        }
+        format_xml_string("Stream", &attrs, &parameters_twiml(&self.parameters))
+    }
+}
+
+impl TryFrom<&Element> for Stream {
+    type Error = InvalidTwiml;
+
+    fn try_from(e: &Element) -> Result<Stream, InvalidTwiml> {
+        let mut r = Reader::new(e, "Stream")?;
+        let stream = Stream {
+            url: r.required("url")?,
+            name: r.string("name"),
+            track: r.map("track", Track::parse)?,
+            status_callback: r.string("statusCallback"),
+            status_callback_method: r.method("statusCallbackMethod")?,
+            parameters: read_parameters(&mut r)?,
+        };
+        r.finish()?;
+        Ok(stream)
+    }
+}
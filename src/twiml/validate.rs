@@ -0,0 +1,158 @@
+use super::parse::SSML;
+use super::{Element, ParseError};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// Why `validate` rejected a document, or a parsed `Element` couldn't be
+/// converted into one of the builders.
+#[derive(Debug)]
+pub enum InvalidTwiml {
+    /// The document isn't well-formed XML.
+    Malformed(ParseError),
+    /// `element` can't appear inside `parent`, or at the root when `parent`
+    /// is `None`.
+    Misplaced {
+        element: String,
+        parent: Option<String>,
+    },
+    /// A conversion expected a different element, e.g. `Say::try_from` on a
+    /// `<Play>`.
+    WrongElement {
+        expected: &'static str,
+        found: String,
+    },
+    MissingAttribute {
+        element: String,
+        attribute: &'static str,
+    },
+    /// An attribute's value isn't one the builder accepts, such as
+    /// `timeout="soon"`.
+    InvalidAttribute {
+        element: String,
+        attribute: String,
+        value: String,
+    },
+    /// The text inside `element` isn't what its builder expects, such as a
+    /// `<Task>` that isn't JSON.
+    InvalidText { element: String, text: String },
+    /// `element` holds `item`, such as an attribute or a nested element, that
+    /// is valid TwiML but has no field in the builder. Keep the parsed
+    /// `Element`, which renders back unchanged, for TwiML like this.
+    Unsupported { element: String, item: String },
+    /// `element` needs a nested element, such as the `<Stream>` inside a
+    /// `<Connect>`, and has none.
+    MissingContent { element: String },
+}
+
+impl Display for InvalidTwiml {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            InvalidTwiml::Malformed(ref e) => e.fmt(f),
+            InvalidTwiml::Misplaced {
+                ref element,
+                parent: Some(ref parent),
+            } => write!(f, "<{element}> is not allowed inside <{parent}>"),
+            InvalidTwiml::Misplaced {
+                ref element,
+                parent: None,
+            } => write!(
+                f,
+                "<{element}> is not a TwiML document; expected <Response>"
+            ),
+            InvalidTwiml::WrongElement {
+                expected,
+                ref found,
+            } => write!(f, "expected <{expected}>, found <{found}>"),
+            InvalidTwiml::MissingAttribute {
+                ref element,
+                attribute,
+            } => write!(f, "<{element}> is missing `{attribute}`"),
+            InvalidTwiml::InvalidAttribute {
+                ref element,
+                ref attribute,
+                ref value,
+            } => write!(f, "invalid {attribute}={value:?} on <{element}>"),
+            InvalidTwiml::InvalidText {
+                ref element,
+                ref text,
+            } => write!(f, "invalid text {text:?} in <{element}>"),
+            InvalidTwiml::Unsupported {
+                ref element,
+                ref item,
+            } => write!(
+                f,
+                "<{element}> has {item}, which its builder can't represent"
+            ),
+            InvalidTwiml::MissingContent { ref element } => {
+                write!(f, "<{element}> is empty")
+            }
+        }
+    }
+}
+
+impl Error for InvalidTwiml {}
+
+impl From<ParseError> for InvalidTwiml {
+    fn from(e: ParseError) -> InvalidTwiml {
+        InvalidTwiml::Malformed(e)
+    }
+}
+
+const VOICE_VERBS: &[&str] = &[
+    "Connect", "Dial", "Enqueue", "Gather", "Hangup", "Leave", "Pause", "Pay", "Play", "Record",
+    "Redirect", "Refer", "Reject", "Say", "Sms", "Start", "Stop",
+];
+
+const MESSAGING_VERBS: &[&str] = &["Message", "Redirect"];
+
+const PROMPT: &[&str] = &["Say", "Play", "Pause"];
+
+/// The elements allowed directly inside `parent`.
+fn allowed_children(parent: &Element) -> &'static [&'static str] {
+    match parent.name.as_str() {
+        // A response is for either a call or a message, never both.
+        "Response" if parent.find("Message").is_some() => MESSAGING_VERBS,
+        "Response" => VOICE_VERBS,
+        "Gather" | "Prompt" => PROMPT,
+        "Dial" => &["Number", "Sip", "Client", "Conference", "Queue"],
+        "Connect" => &["Stream", "VirtualAgent", "Room", "Conversation"],
+        "Start" | "Stop" => &["Stream", "Siprec", "Transcription"],
+        "Stream" | "Siprec" | "VirtualAgent" | "Transcription" => &["Parameter"],
+        "Pay" => &["Prompt", "Parameter"],
+        "Enqueue" => &["Task"],
+        "Refer" => &["Sip"],
+        "Client" => &["Identity", "Parameter"],
+        "Message" => &["Body", "Media"],
+        name if name == "Say" || SSML.contains(&name) => SSML,
+        _ => &[],
+    }
+}
+
+impl Element {
+    /// Checks this `<Response>` against Twilio's nesting rules, e.g. that
+    /// `<Gather>` only holds `<Say>`, `<Play>` and `<Pause>` and that `<Dial>`
+    /// nouns only appear inside `<Dial>`.
+    pub fn validate(&self) -> Result<(), InvalidTwiml> {
+        if self.name != "Response" {
+            return Err(InvalidTwiml::Misplaced {
+                element: self.name.clone(),
+                parent: None,
+            });
+        }
+        check_children(self)
+    }
+}
+
+fn check_children(parent: &Element) -> Result<(), InvalidTwiml> {
+    let allowed = allowed_children(parent);
+    for child in parent.elements() {
+        if !allowed.contains(&child.name.as_str()) {
+            return Err(InvalidTwiml::Misplaced {
+                element: child.name.clone(),
+                parent: Some(parent.name.clone()),
+            });
+        }
+        check_children(child)?;
+    }
+    Ok(())
+}
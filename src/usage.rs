@@ -0,0 +1,253 @@
+use crate::twiml::Method;
+use crate::{
+    bool_str, AccountSid, Client, Money, PageIterator, Timestamp, TwilioError, UsageTriggerSid,
+    GET, POST,
+};
+use serde::Deserialize;
+
+/// Usage for one category over one period. `category` is one of Twilio's
+/// [usage categories](https://www.twilio.com/docs/usage/api/usage-record#usage-categories),
+/// e.g. `"sms"` or `"calls-inbound"`.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecord {
+    pub account_sid: AccountSid,
+    pub category: String,
+    pub description: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub as_of: Option<String>,
+    pub count: Option<String>,
+    pub count_unit: Option<String>,
+    pub usage: Option<String>,
+    pub usage_unit: Option<String>,
+    #[serde(flatten, deserialize_with = "crate::money::option")]
+    pub price: Option<Money>,
+    pub uri: Option<String>,
+}
+
+/// How `Client::list_usage_records` buckets usage. `All` returns one record
+/// per category for the whole filtered range.
+pub enum UsageGranularity {
+    All,
+    Daily,
+    Monthly,
+    Yearly,
+    AllTime,
+    Today,
+    Yesterday,
+    ThisMonth,
+    LastMonth,
+}
+
+impl UsageGranularity {
+    fn path(&self) -> &'static str {
+        match *self {
+            UsageGranularity::All => "Usage/Records",
+            UsageGranularity::Daily => "Usage/Records/Daily",
+            UsageGranularity::Monthly => "Usage/Records/Monthly",
+            UsageGranularity::Yearly => "Usage/Records/Yearly",
+            UsageGranularity::AllTime => "Usage/Records/AllTime",
+            UsageGranularity::Today => "Usage/Records/Today",
+            UsageGranularity::Yesterday => "Usage/Records/Yesterday",
+            UsageGranularity::ThisMonth => "Usage/Records/ThisMonth",
+            UsageGranularity::LastMonth => "Usage/Records/LastMonth",
+        }
+    }
+}
+
+/// Filters accepted by `Client::list_usage_records`. Dates are `YYYY-MM-DD`,
+/// or relative values Twilio understands such as `"-30days"`.
+#[derive(Default)]
+pub struct UsageFilter<'a> {
+    pub category: Option<&'a str>,
+    pub start_date: Option<&'a str>,
+    pub end_date: Option<&'a str>,
+    pub include_subaccounts: Option<bool>,
+    pub page_size: Option<u32>,
+}
+
+/// What a usage trigger compares against its `trigger_value`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TriggerBy {
+    Count,
+    Usage,
+    Price,
+}
+
+impl TriggerBy {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            TriggerBy::Count => "count",
+            TriggerBy::Usage => "usage",
+            TriggerBy::Price => "price",
+        }
+    }
+}
+
+/// How often a usage trigger resets. Non-recurring triggers fire only once.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TriggerRecurrence {
+    Daily,
+    Monthly,
+    Yearly,
+    Alltime,
+}
+
+impl TriggerRecurrence {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            TriggerRecurrence::Daily => "daily",
+            TriggerRecurrence::Monthly => "monthly",
+            TriggerRecurrence::Yearly => "yearly",
+            TriggerRecurrence::Alltime => "alltime",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsageTrigger {
+    pub sid: UsageTriggerSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: Option<String>,
+    pub usage_category: String,
+    pub trigger_by: Option<TriggerBy>,
+    pub trigger_value: String,
+    pub current_value: Option<String>,
+    pub recurring: Option<TriggerRecurrence>,
+    pub callback_url: String,
+    pub callback_method: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_fired: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub usage_record_uri: Option<String>,
+    pub uri: Option<String>,
+}
+
+/// A trigger that posts to `callback_url` once usage in `usage_category`
+/// reaches `trigger_value`.
+pub struct NewUsageTrigger<'a> {
+    pub usage_category: &'a str,
+    pub trigger_value: &'a str,
+    pub callback_url: &'a str,
+    pub callback_method: Option<Method>,
+    pub trigger_by: Option<TriggerBy>,
+    pub recurring: Option<TriggerRecurrence>,
+    pub friendly_name: Option<&'a str>,
+}
+
+impl<'a> NewUsageTrigger<'a> {
+    pub fn new(
+        usage_category: &'a str,
+        trigger_value: &'a str,
+        callback_url: &'a str,
+    ) -> NewUsageTrigger<'a> {
+        NewUsageTrigger {
+            usage_category,
+            trigger_value,
+            callback_url,
+            callback_method: None,
+            trigger_by: None,
+            recurring: None,
+            friendly_name: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct UsageTriggerUpdate<'a> {
+    pub callback_url: Option<&'a str>,
+    pub callback_method: Option<Method>,
+    pub friendly_name: Option<&'a str>,
+}
+
+impl Client {
+    pub fn list_usage_records(
+        &self,
+        granularity: UsageGranularity,
+        filter: &UsageFilter<'_>,
+    ) -> PageIterator<UsageRecord> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(category) = filter.category {
+            opts.push(("Category", category));
+        }
+        if let Some(start) = filter.start_date {
+            opts.push(("StartDate", start));
+        }
+        if let Some(end) = filter.end_date {
+            opts.push(("EndDate", end));
+        }
+        if let Some(include) = filter.include_subaccounts {
+            opts.push(("IncludeSubaccounts", bool_str(include)));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+
+        self.list(granularity.path(), "usage_records", &opts)
+    }
+
+    pub async fn create_usage_trigger(
+        &self,
+        trigger: NewUsageTrigger<'_>,
+    ) -> Result<UsageTrigger, TwilioError> {
+        let mut opts = vec![
+            ("UsageCategory", trigger.usage_category),
+            ("TriggerValue", trigger.trigger_value),
+            ("CallbackUrl", trigger.callback_url),
+        ];
+        if let Some(ref method) = trigger.callback_method {
+            opts.push(("CallbackMethod", method.as_str()));
+        }
+        if let Some(ref by) = trigger.trigger_by {
+            opts.push(("TriggerBy", by.as_str()));
+        }
+        if let Some(ref recurring) = trigger.recurring {
+            opts.push(("Recurring", recurring.as_str()));
+        }
+        if let Some(name) = trigger.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        self.send_request(POST, "Usage/Triggers", &opts).await
+    }
+
+    pub fn list_usage_triggers(&self) -> PageIterator<UsageTrigger> {
+        self.list("Usage/Triggers", "usage_triggers", &[])
+    }
+
+    pub async fn retrieve_usage_trigger(
+        &self,
+        sid: &UsageTriggerSid,
+    ) -> Result<UsageTrigger, TwilioError> {
+        self.send_request(GET, &format!("Usage/Triggers/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_usage_trigger(
+        &self,
+        sid: &UsageTriggerSid,
+        update: UsageTriggerUpdate<'_>,
+    ) -> Result<UsageTrigger, TwilioError> {
+        let mut opts = Vec::new();
+        if let Some(url) = update.callback_url {
+            opts.push(("CallbackUrl", url));
+        }
+        if let Some(ref method) = update.callback_method {
+            opts.push(("CallbackMethod", method.as_str()));
+        }
+        if let Some(name) = update.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        self.send_request(POST, &format!("Usage/Triggers/{sid}"), &opts)
+            .await
+    }
+
+    pub async fn delete_usage_trigger(&self, sid: &UsageTriggerSid) -> Result<(), TwilioError> {
+        self.send_delete(&format!("Usage/Triggers/{sid}")).await
+    }
+}
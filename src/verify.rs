@@ -0,0 +1,205 @@
+//! The [Verify v2](https://www.twilio.com/docs/verify/api) API, for sending
+//! and checking one-time passcodes.
+
+use crate::endpoint::VERIFY;
+use crate::{
+    bool_str, AccountSid, Client, PageIterator, Timestamp, TwilioError, VerificationSid,
+    VerifyServiceSid, GET, POST,
+};
+use serde::Deserialize;
+
+pub enum Channel {
+    Sms,
+    Call,
+    Email,
+    Whatsapp,
+}
+
+impl Channel {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Channel::Sms => "sms",
+            Channel::Call => "call",
+            Channel::Email => "email",
+            Channel::Whatsapp => "whatsapp",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStatus {
+    Pending,
+    Approved,
+    Canceled,
+    MaxAttemptsReached,
+    Deleted,
+    Failed,
+    Expired,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Verification {
+    pub sid: VerificationSid,
+    pub service_sid: VerifyServiceSid,
+    pub account_sid: AccountSid,
+    pub to: String,
+    pub channel: String,
+    pub status: VerificationStatus,
+    pub valid: bool,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VerificationCheck {
+    pub sid: VerificationSid,
+    pub service_sid: VerifyServiceSid,
+    pub account_sid: AccountSid,
+    pub to: String,
+    pub channel: String,
+    pub status: VerificationStatus,
+    pub valid: bool,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Service {
+    pub sid: VerifyServiceSid,
+    pub account_sid: AccountSid,
+    pub friendly_name: String,
+    pub code_length: u32,
+    pub lookup_enabled: Option<bool>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+#[derive(Default)]
+pub struct ServiceUpdate<'a> {
+    pub friendly_name: Option<&'a str>,
+    pub code_length: Option<u32>,
+    pub lookup_enabled: Option<bool>,
+}
+
+impl<'a> ServiceUpdate<'a> {
+    fn params(&self, code_length: &'a Option<String>) -> Vec<(&'static str, &'a str)> {
+        let mut opts = Vec::new();
+        if let Some(name) = self.friendly_name {
+            opts.push(("FriendlyName", name));
+        }
+        if let Some(ref c) = code_length {
+            opts.push(("CodeLength", c.as_str()));
+        }
+        if let Some(lookup) = self.lookup_enabled {
+            opts.push(("LookupEnabled", bool_str(lookup)));
+        }
+        opts
+    }
+}
+
+impl Client {
+    /// Sends a passcode to `to` (a phone number, or an email address for the
+    /// email channel) through the given Verify service.
+    pub async fn start_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        to: &str,
+        channel: Channel,
+    ) -> Result<Verification, TwilioError> {
+        let opts = [("To", to), ("Channel", channel.as_str())];
+        self.send_product_request(
+            POST,
+            &VERIFY,
+            &format!("Services/{service_sid}/Verifications"),
+            &opts,
+        )
+        .await
+    }
+
+    /// Checks `code` against the pending verification for `to`. A wrong code
+    /// is not an error: inspect `status` or `valid` on the result.
+    pub async fn check_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        to: &str,
+        code: &str,
+    ) -> Result<VerificationCheck, TwilioError> {
+        let opts = [("To", to), ("Code", code)];
+        self.send_product_request(
+            POST,
+            &VERIFY,
+            &format!("Services/{service_sid}/VerificationCheck"),
+            &opts,
+        )
+        .await
+    }
+
+    pub async fn cancel_verification(
+        &self,
+        service_sid: &VerifyServiceSid,
+        verification_sid: &VerificationSid,
+    ) -> Result<Verification, TwilioError> {
+        self.send_product_request(
+            POST,
+            &VERIFY,
+            &format!("Services/{service_sid}/Verifications/{verification_sid}"),
+            &[("Status", "canceled")],
+        )
+        .await
+    }
+
+    pub async fn create_verify_service(
+        &self,
+        friendly_name: &str,
+        code_length: Option<u32>,
+    ) -> Result<Service, TwilioError> {
+        let code_length = code_length.map(|c| c.to_string());
+        let update = ServiceUpdate {
+            friendly_name: Some(friendly_name),
+            ..Default::default()
+        };
+        self.send_product_request(POST, &VERIFY, "Services", &update.params(&code_length))
+            .await
+    }
+
+    pub fn list_verify_services(&self) -> PageIterator<Service> {
+        self.list_product(&VERIFY, "Services", "services", &[])
+    }
+
+    pub async fn retrieve_verify_service(
+        &self,
+        sid: &VerifyServiceSid,
+    ) -> Result<Service, TwilioError> {
+        self.send_product_request(GET, &VERIFY, &format!("Services/{sid}"), &[])
+            .await
+    }
+
+    pub async fn update_verify_service(
+        &self,
+        sid: &VerifyServiceSid,
+        update: ServiceUpdate<'_>,
+    ) -> Result<Service, TwilioError> {
+        let code_length = update.code_length.map(|c| c.to_string());
+        self.send_product_request(
+            POST,
+            &VERIFY,
+            &format!("Services/{sid}"),
+            &update.params(&code_length),
+        )
+        .await
+    }
+
+    pub async fn delete_verify_service(&self, sid: &VerifyServiceSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&VERIFY, &format!("Services/{sid}"))
+            .await
+    }
+}
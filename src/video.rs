@@ -0,0 +1,379 @@
+//! [Programmable Video](https://www.twilio.com/docs/video/api) rooms, their
+//! participants and recordings, and compositions that mix a room's
+//! recordings into a single file.
+
+use crate::endpoint::VIDEO;
+use crate::{
+    bool_str, AccountSid, Client, CompositionSid, PageIterator, RoomParticipantSid,
+    RoomRecordingSid, RoomSid, Timestamp, TwilioError, GET, POST,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoomType {
+    Go,
+    PeerToPeer,
+    Group,
+    GroupSmall,
+}
+
+impl RoomType {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            RoomType::Go => "go",
+            RoomType::PeerToPeer => "peer-to-peer",
+            RoomType::Group => "group",
+            RoomType::GroupSmall => "group-small",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoomStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+impl RoomStatus {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            RoomStatus::InProgress => "in-progress",
+            RoomStatus::Completed => "completed",
+            RoomStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Room {
+    pub sid: RoomSid,
+    pub account_sid: AccountSid,
+    pub unique_name: Option<String>,
+    pub status: RoomStatus,
+    #[serde(rename = "type")]
+    pub room_type: Option<RoomType>,
+    pub max_participants: Option<u32>,
+    /// Seconds the room was open, once completed.
+    pub duration: Option<u32>,
+    pub record_participants_on_connect: Option<bool>,
+    pub status_callback: Option<String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub end_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// Options for `Client::create_room`. Rooms are also created on the fly when
+/// a client connects to an unused name, with the account's default settings.
+#[derive(Default)]
+pub struct RoomConfig<'a> {
+    pub unique_name: Option<&'a str>,
+    pub room_type: Option<RoomType>,
+    pub max_participants: Option<u32>,
+    pub record_participants_on_connect: Option<bool>,
+    pub status_callback: Option<&'a str>,
+    /// Minutes an empty room stays open for participants to rejoin.
+    pub empty_room_timeout: Option<u32>,
+    /// Minutes a room nobody ever joined stays open.
+    pub unused_room_timeout: Option<u32>,
+}
+
+/// Filters accepted by `Client::list_rooms`. Dates are ISO 8601, e.g.
+/// `"2023-01-31T00:00:00Z"`.
+#[derive(Default)]
+pub struct RoomFilter<'a> {
+    pub status: Option<RoomStatus>,
+    pub unique_name: Option<&'a str>,
+    pub date_created_after: Option<&'a str>,
+    pub date_created_before: Option<&'a str>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ParticipantStatus {
+    Connected,
+    Disconnected,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Participant {
+    pub sid: RoomParticipantSid,
+    pub account_sid: AccountSid,
+    pub room_sid: RoomSid,
+    /// The identity in the access token the participant connected with.
+    pub identity: String,
+    pub status: ParticipantStatus,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub start_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub end_time: Option<Timestamp>,
+    pub duration: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_updated: Option<Timestamp>,
+    pub url: Option<String>,
+}
+
+/// One recorded track. Each participant's audio and video are recorded
+/// separately; use a composition to combine them.
+#[derive(Debug, Deserialize)]
+pub struct RoomRecording {
+    pub sid: RoomRecordingSid,
+    pub account_sid: AccountSid,
+    pub room_sid: Option<RoomSid>,
+    /// `processing`, `completed`, `deleted` or `failed`.
+    pub status: String,
+    /// `audio`, `video` or `data`.
+    #[serde(rename = "type")]
+    pub recording_type: Option<String>,
+    /// The SID of the recorded track.
+    pub source_sid: Option<String>,
+    pub track_name: Option<String>,
+    pub container_format: Option<String>,
+    pub codec: Option<String>,
+    pub duration: Option<u32>,
+    /// Bytes.
+    pub size: Option<u64>,
+    /// Milliseconds from the start of the room to the start of the track.
+    pub offset: Option<u64>,
+    #[serde(default)]
+    pub grouping_sids: BTreeMap<String, String>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CompositionStatus {
+    Enqueued,
+    Processing,
+    Completed,
+    Deleted,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Composition {
+    pub sid: CompositionSid,
+    pub account_sid: AccountSid,
+    pub room_sid: RoomSid,
+    pub status: CompositionStatus,
+    /// `mp4` or `webm`.
+    pub format: Option<String>,
+    pub resolution: Option<String>,
+    #[serde(default)]
+    pub audio_sources: Vec<String>,
+    pub video_layout: Option<serde_json::Value>,
+    pub duration: Option<u32>,
+    pub size: Option<u64>,
+    pub bitrate: Option<u32>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_created: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::timestamp::option")]
+    pub date_completed: Option<Timestamp>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub links: BTreeMap<String, String>,
+}
+
+/// A composition to create with `Client::create_composition`. Track names
+/// may use `*` as a wildcard, so `audio_sources: vec!["*"]` mixes everyone.
+#[derive(Default)]
+pub struct NewComposition<'a> {
+    pub audio_sources: Vec<&'a str>,
+    pub audio_sources_excluded: Vec<&'a str>,
+    /// Where each video track is drawn, as described in Twilio's
+    /// composition docs.
+    pub video_layout: Option<serde_json::Value>,
+    /// E.g. `"1280x720"`.
+    pub resolution: Option<&'a str>,
+    /// `mp4` or `webm`.
+    pub format: Option<&'a str>,
+    /// Cut out the periods when no one was sharing video or audio.
+    pub trim: Option<bool>,
+    pub status_callback: Option<&'a str>,
+}
+
+impl Client {
+    pub async fn create_room(&self, config: &RoomConfig<'_>) -> Result<Room, TwilioError> {
+        let max_participants = config.max_participants.map(|m| m.to_string());
+        let empty_timeout = config.empty_room_timeout.map(|t| t.to_string());
+        let unused_timeout = config.unused_room_timeout.map(|t| t.to_string());
+        let mut opts = Vec::new();
+        if let Some(name) = config.unique_name {
+            opts.push(("UniqueName", name));
+        }
+        if let Some(ref t) = config.room_type {
+            opts.push(("Type", t.as_str()));
+        }
+        if let Some(ref m) = max_participants {
+            opts.push(("MaxParticipants", m));
+        }
+        if let Some(r) = config.record_participants_on_connect {
+            opts.push(("RecordParticipantsOnConnect", bool_str(r)));
+        }
+        if let Some(cb) = config.status_callback {
+            opts.push(("StatusCallback", cb));
+        }
+        if let Some(ref t) = empty_timeout {
+            opts.push(("EmptyRoomTimeout", t));
+        }
+        if let Some(ref t) = unused_timeout {
+            opts.push(("UnusedRoomTimeout", t));
+        }
+        self.send_product_request(POST, &VIDEO, "Rooms", &opts)
+            .await
+    }
+
+    pub fn list_rooms(&self, filter: &RoomFilter<'_>) -> PageIterator<Room> {
+        let page_size = filter.page_size.map(|p| p.to_string());
+        let mut opts = Vec::new();
+        if let Some(ref status) = filter.status {
+            opts.push(("Status", status.as_str()));
+        }
+        if let Some(name) = filter.unique_name {
+            opts.push(("UniqueName", name));
+        }
+        if let Some(after) = filter.date_created_after {
+            opts.push(("DateCreatedAfter", after));
+        }
+        if let Some(before) = filter.date_created_before {
+            opts.push(("DateCreatedBefore", before));
+        }
+        if let Some(ref p) = page_size {
+            opts.push(("PageSize", p));
+        }
+        self.list_product(&VIDEO, "Rooms", "rooms", &opts)
+    }
+
+    pub async fn retrieve_room(&self, sid: &RoomSid) -> Result<Room, TwilioError> {
+        self.send_product_request(GET, &VIDEO, &format!("Rooms/{sid}"), &[])
+            .await
+    }
+
+    /// Ends a room, disconnecting everyone in it.
+    pub async fn complete_room(&self, sid: &RoomSid) -> Result<Room, TwilioError> {
+        self.send_product_request(
+            POST,
+            &VIDEO,
+            &format!("Rooms/{sid}"),
+            &[("Status", "completed")],
+        )
+        .await
+    }
+
+    pub fn list_room_participants(&self, room_sid: &RoomSid) -> PageIterator<Participant> {
+        self.list_product(
+            &VIDEO,
+            &format!("Rooms/{room_sid}/Participants"),
+            "participants",
+            &[],
+        )
+    }
+
+    pub async fn disconnect_room_participant(
+        &self,
+        room_sid: &RoomSid,
+        sid: &RoomParticipantSid,
+    ) -> Result<Participant, TwilioError> {
+        self.send_product_request(
+            POST,
+            &VIDEO,
+            &format!("Rooms/{room_sid}/Participants/{sid}"),
+            &[("Status", "disconnected")],
+        )
+        .await
+    }
+
+    pub fn list_room_recordings(&self, room_sid: &RoomSid) -> PageIterator<RoomRecording> {
+        self.list_product(
+            &VIDEO,
+            &format!("Rooms/{room_sid}/Recordings"),
+            "recordings",
+            &[],
+        )
+    }
+
+    pub async fn retrieve_room_recording(
+        &self,
+        room_sid: &RoomSid,
+        sid: &RoomRecordingSid,
+    ) -> Result<RoomRecording, TwilioError> {
+        self.send_product_request(
+            GET,
+            &VIDEO,
+            &format!("Rooms/{room_sid}/Recordings/{sid}"),
+            &[],
+        )
+        .await
+    }
+
+    /// Starts mixing a completed room's recordings. Poll `retrieve_composition`
+    /// or use `status_callback` to learn when it is ready.
+    pub async fn create_composition(
+        &self,
+        room_sid: &RoomSid,
+        composition: &NewComposition<'_>,
+    ) -> Result<Composition, TwilioError> {
+        let video_layout = composition.video_layout.as_ref().map(|l| l.to_string());
+        let mut opts = vec![("RoomSid", room_sid.as_str())];
+        for source in &composition.audio_sources {
+            opts.push(("AudioSources", source));
+        }
+        for source in &composition.audio_sources_excluded {
+            opts.push(("AudioSourcesExcluded", source));
+        }
+        if let Some(ref layout) = video_layout {
+            opts.push(("VideoLayout", layout));
+        }
+        if let Some(resolution) = composition.resolution {
+            opts.push(("Resolution", resolution));
+        }
+        if let Some(format) = composition.format {
+            opts.push(("Format", format));
+        }
+        if let Some(trim) = composition.trim {
+            opts.push(("Trim", bool_str(trim)));
+        }
+        if let Some(cb) = composition.status_callback {
+            opts.push(("StatusCallback", cb));
+        }
+        self.send_product_request(POST, &VIDEO, "Compositions", &opts)
+            .await
+    }
+
+    pub fn list_compositions(&self, room_sid: Option<&RoomSid>) -> PageIterator<Composition> {
+        let mut opts = Vec::new();
+        if let Some(sid) = room_sid {
+            opts.push(("RoomSid", sid.as_str()));
+        }
+        self.list_product(&VIDEO, "Compositions", "compositions", &opts)
+    }
+
+    pub async fn retrieve_composition(
+        &self,
+        sid: &CompositionSid,
+    ) -> Result<Composition, TwilioError> {
+        self.send_product_request(GET, &VIDEO, &format!("Compositions/{sid}"), &[])
+            .await
+    }
+
+    pub async fn delete_composition(&self, sid: &CompositionSid) -> Result<(), TwilioError> {
+        self.send_product_delete(&VIDEO, &format!("Compositions/{sid}"))
+            .await
+    }
+}
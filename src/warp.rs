@@ -0,0 +1,79 @@
+//! Filters and replies for handling webhooks in [warp](https://github.com/seanmonstar/warp).
+//!
+//! ```ignore
+//! let sms = warp::post()
+//!     .and(warp::path("sms"))
+//!     .and(twilio::warp::webhook::<twilio::Message>(client))
+//!     .map(|msg: twilio::Message| {
+//!         let mut t = MessagingResponse::new();
+//!         ...
+//!         t
+//!     });
+//! ```
+
+use crate::twiml::{MessagingResponse, Twiml};
+use crate::{Client, FromMap, TwilioError};
+use ::warp::http::{HeaderMap, Method};
+use ::warp::hyper::body::Bytes;
+use ::warp::path::FullPath;
+use ::warp::reject::{Reject, Rejection};
+use ::warp::reply::{Reply, Response};
+use ::warp::Filter;
+
+impl Reject for TwilioError {}
+
+/// Validates the `X-Twilio-Signature` of an incoming webhook and extracts it as `T`.
+/// Failures are rejected with the `TwilioError` that caused them.
+pub fn webhook<T>(client: Client) -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: FromMap + Send + 'static,
+{
+    ::warp::any()
+        .map(move || client.clone())
+        .and(::warp::method())
+        .and(::warp::path::full())
+        .and(
+            ::warp::query::raw()
+                .or(::warp::any().map(String::new))
+                .unify(),
+        )
+        .and(::warp::header::headers_cloned())
+        .and(::warp::body::bytes())
+        .and_then(
+            |client: Client,
+             method: Method,
+             path: FullPath,
+             query: String,
+             headers: HeaderMap,
+             body: Bytes| async move {
+                let uri = if query.is_empty() {
+                    path.as_str().to_string()
+                } else {
+                    format!("{}?{}", path.as_str(), query)
+                };
+                let mut req = hyper::Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .body(hyper::Body::from(body))
+                    .map_err(|_| ::warp::reject::custom(TwilioError::BadRequest))?;
+                *req.headers_mut() = headers;
+                client
+                    .parse_request::<T>(req)
+                    .await
+                    .map(|t| *t)
+                    .map_err(::warp::reject::custom)
+            },
+        )
+}
+
+impl Reply for Twiml {
+    fn into_response(self) -> Response {
+        ::warp::reply::with_header(self.as_twiml(), "Content-Type", "text/xml").into_response()
+    }
+}
+
+impl Reply for MessagingResponse {
+    fn into_response(self) -> Response {
+        Twiml::from(self).into_response()
+    }
+}
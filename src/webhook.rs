@@ -3,19 +3,67 @@ use headers::{HeaderMapExt, Host};
 use hmac::{Hmac, Mac};
 use hyper::{Body, Method, Request};
 use sha1::Sha1;
+use sha2::{Digest, Sha256};
 use std::collections::BTreeMap;
 
-fn get_args(path: &str) -> BTreeMap<String, String> {
-    let url_segments: Vec<&str> = path.split('?').collect();
-    if url_segments.len() != 2 {
-        return BTreeMap::new();
+fn args_from_urlencoded(enc: &[u8]) -> BTreeMap<String, String> {
+    url::form_urlencoded::parse(enc).into_owned().collect()
+}
+
+fn signature_matches(auth_token: &str, payload: &str, signature: &str) -> bool {
+    let expected = match base64::decode(signature) {
+        Ok(e) => e,
+        Err(_) => return false,
+    };
+    let mut mac = Hmac::<Sha1>::new_from_slice(auth_token.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Checks an `X-Twilio-Signature` header against a form-encoded webhook.
+///
+/// `url` is the full URL Twilio requested, including any query string, and
+/// `params` are the POST body parameters (empty for GET requests).
+pub fn validate_request(
+    auth_token: &str,
+    url: &str,
+    params: &BTreeMap<String, String>,
+    signature: &str,
+) -> bool {
+    let mut payload = url.to_string();
+    for (k, v) in params {
+        payload.push_str(k);
+        payload.push_str(v);
     }
-    let query_string = url_segments[1];
-    args_from_urlencoded(query_string.as_bytes())
+    signature_matches(auth_token, &payload, signature)
 }
 
-fn args_from_urlencoded(enc: &[u8]) -> BTreeMap<String, String> {
-    url::form_urlencoded::parse(enc).into_owned().collect()
+/// Checks an `X-Twilio-Signature` header against a webhook with a JSON (or
+/// other non-form) body. Twilio signs the URL alone and appends a
+/// `bodySHA256` query parameter holding the hex digest of the body.
+pub fn validate_request_with_body(
+    auth_token: &str,
+    url: &str,
+    body: &[u8],
+    signature: &str,
+) -> bool {
+    let expected_hash = url::Url::parse(url).ok().and_then(|u| {
+        u.query_pairs()
+            .find(|(k, _)| k == "bodySHA256")
+            .map(|(_, v)| v.into_owned())
+    });
+    let expected_hash = match expected_hash {
+        Some(h) => h,
+        None => return false,
+    };
+    let actual_hash: String = Sha256::digest(body)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    if !actual_hash.eq_ignore_ascii_case(&expected_hash) {
+        return false;
+    }
+    signature_matches(auth_token, url, signature)
 }
 
 impl Client {
@@ -23,11 +71,15 @@ impl Client {
         &self,
         req: Request<Body>,
     ) -> Result<Box<T>, TwilioError> {
-        let expected = req
+        let signature = req
             .headers()
             .get("X-Twilio-Signature")
-            .ok_or_else(|| TwilioError::AuthError)
-            .and_then(|d| base64::decode(d.as_bytes()).map_err(|_| TwilioError::BadRequest))?;
+            .ok_or(TwilioError::AuthError)
+            .and_then(|d| {
+                d.to_str()
+                    .map(|s| s.to_string())
+                    .map_err(|_| TwilioError::BadRequest)
+            })?;
 
         let (parts, body) = req.into_parts();
         let body = hyper::body::to_bytes(body)
@@ -37,29 +89,25 @@ impl Client {
             None => return Err(TwilioError::BadRequest),
             Some(h) => h.hostname().to_string(),
         };
-        let request_path = match parts.uri.path() {
-            "*" => return Err(TwilioError::BadRequest),
-            path => path,
+        let request_path = match parts.uri.path_and_query() {
+            Some(p) if p.path() != "*" => p.as_str(),
+            _ => return Err(TwilioError::BadRequest),
         };
-        let (args, post_append) = match parts.method {
-            Method::GET => (get_args(request_path), "".to_string()),
+        let effective_uri = format!("https://{}{}", host, request_path);
+        let (args, signed_args) = match parts.method {
+            Method::GET => {
+                let query = parts.uri.query().unwrap_or("");
+                (args_from_urlencoded(query.as_bytes()), BTreeMap::new())
+            }
             Method::POST => {
                 let postargs = args_from_urlencoded(&body);
-                let append = postargs
-                    .iter()
-                    .map(|(k, v)| format!("{}{}", k, v))
-                    .collect();
-                (postargs, append)
+                (postargs.clone(), postargs)
             }
             _ => return Err(TwilioError::BadRequest),
         };
 
-        let effective_uri = format!("https://{}{}{}", host, request_path, post_append);
-        let mut hasher = Hmac::<Sha1>::new_from_slice(self.auth_token.as_bytes()).unwrap();
-        hasher.update(effective_uri.as_bytes());
-
-        let result = hasher.finalize().into_bytes().to_vec();
-        if result != expected {
+        let auth_token = self.auth_token.as_ref().ok_or(TwilioError::AuthError)?;
+        if !validate_request(auth_token, &effective_uri, &signed_args, &signature) {
             return Err(TwilioError::AuthError);
         }
 
@@ -0,0 +1,39 @@
+#![cfg(all(feature = "blocking", feature = "messaging", feature = "voice"))]
+
+use hyper::StatusCode;
+use twilio::blocking::Client;
+use twilio::{MockTransport, OutboundMessage, RetryPolicy};
+
+#[test]
+fn sends_and_lists_without_a_runtime() {
+    let mock = MockTransport::new();
+    mock.respond(
+        StatusCode::CREATED,
+        r#"{"sid": "MM0123456789abcdef0123456789abcdef", "from": "+15005550006",
+            "to": "+15558675310", "body": "Hi"}"#,
+    )
+    .respond(
+        StatusCode::OK,
+        r#"{"queues": [{"sid": "QU0123456789abcdef0123456789abcdef",
+            "account_sid": "AC0123456789abcdef0123456789abcdef", "friendly_name": "support",
+            "current_size": 2, "max_size": 100, "average_wait_time": 30}],
+            "next_page_uri": null}"#,
+    );
+    let client = Client::from(
+        twilio::Client::builder("AC0123456789abcdef0123456789abcdef", "token")
+            .transport(mock.clone())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap(),
+    );
+
+    let msg = client
+        .send_message(OutboundMessage::new("+15005550006", "+15558675310", "Hi"))
+        .unwrap();
+    assert_eq!(msg.body.as_deref(), Some("Hi"));
+
+    let queues: Vec<_> = client.list_queues().collect::<Result<_, _>>().unwrap();
+    assert_eq!(queues.len(), 1);
+    assert_eq!(queues[0].friendly_name, "support");
+    assert_eq!(mock.requests().len(), 2);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "messaging")]
+
 use std::env;
 use twilio::{Client, OutboundMessage};
 
@@ -0,0 +1,31 @@
+use hyper::StatusCode;
+use twilio::{ApiError, TwilioError};
+
+#[test]
+fn exposes_twilio_error_body() {
+    let body = r#"{
+        "code": 21211,
+        "message": "The 'To' number 123 is not a valid phone number.",
+        "more_info": "https://www.twilio.com/docs/errors/21211",
+        "status": 400
+    }"#;
+    let err = TwilioError::ApiError(serde_json::from_str::<ApiError>(body).unwrap());
+
+    assert_eq!(err.code(), Some(21211));
+    assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+    assert!(err.is_validation());
+    assert!(!err.is_auth());
+    assert!(!err.is_network());
+    assert!(std::error::Error::source(&err).is_some());
+    assert_eq!(
+        err.to_string(),
+        "Twilio error 21211 (400): The 'To' number 123 is not a valid phone number."
+    );
+}
+
+#[test]
+fn classifies_auth_failures() {
+    assert!(TwilioError::HTTPError(StatusCode::UNAUTHORIZED).is_auth());
+    assert!(TwilioError::AuthError.is_auth());
+    assert!(TwilioError::Timeout.is_network());
+}
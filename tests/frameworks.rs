@@ -0,0 +1,181 @@
+#![cfg(all(
+    feature = "messaging",
+    any(feature = "axum", feature = "actix", feature = "warp")
+))]
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use twilio::{Client, MockTransport, RetryPolicy};
+
+const ACCOUNT_ID: &str = "AC0123456789abcdef0123456789abcdef";
+const AUTH_TOKEN: &str = "12345";
+const MESSAGE_SID: &str = "MM0123456789abcdef0123456789abcdef";
+const URL: &str = "https://example.com/sms";
+
+fn client() -> Client {
+    Client::builder(ACCOUNT_ID, AUTH_TOKEN)
+        .transport(MockTransport::new())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+fn params() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("Body", "Hi"),
+        ("From", "+12349013030"),
+        ("MessageSid", MESSAGE_SID),
+        ("To", "+18005551212"),
+    ]
+}
+
+fn body() -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params())
+        .finish()
+}
+
+/// The `X-Twilio-Signature` Twilio would send for `params` posted to `URL`.
+fn signature() -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(AUTH_TOKEN.as_bytes()).unwrap();
+    mac.update(URL.as_bytes());
+    for (k, v) in params() {
+        mac.update(k.as_bytes());
+        mac.update(v.as_bytes());
+    }
+    base64::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(feature = "axum")]
+fn axum_request(signature: &str) -> axum::http::Request<hyper::Body> {
+    axum::http::Request::post("/sms")
+        .header("host", "example.com")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .header("x-twilio-signature", signature)
+        .body(hyper::Body::from(body()))
+        .unwrap()
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn axum_extracts_signed_webhook() {
+    use axum::extract::FromRequest;
+    use twilio::axum::TwilioRequest;
+
+    let TwilioRequest(msg) =
+        TwilioRequest::<twilio::Message>::from_request(axum_request(&signature()), &client())
+            .await
+            .unwrap();
+    assert_eq!(msg.sid.as_str(), MESSAGE_SID);
+    assert_eq!(msg.body.as_deref(), Some("Hi"));
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn axum_rejects_bad_signature() {
+    use axum::extract::FromRequest;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use twilio::axum::TwilioRequest;
+
+    let err = TwilioRequest::<twilio::Message>::from_request(
+        axum_request("0/KCTR6DLpKmkAf8muzZqo1nDgQ="),
+        &client(),
+    )
+    .await
+    .err()
+    .unwrap();
+    assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+}
+
+#[cfg(feature = "actix")]
+async fn actix_extract(
+    req: actix_web::test::TestRequest,
+) -> Result<twilio::actix::TwilioRequest<twilio::Message>, actix_web::Error> {
+    use actix_web::FromRequest;
+
+    let (req, mut payload) = req
+        .uri("/sms")
+        .insert_header(("host", "example.com"))
+        .insert_header(("content-type", "application/x-www-form-urlencoded"))
+        .set_payload(body())
+        .to_http_parts();
+    twilio::actix::TwilioRequest::from_request(&req, &mut payload).await
+}
+
+#[cfg(feature = "actix")]
+#[tokio::test]
+async fn actix_extracts_signed_webhook() {
+    use actix_web::test::TestRequest;
+    use actix_web::web::Data;
+
+    let req = TestRequest::post()
+        .app_data(Data::new(client()))
+        .insert_header(("x-twilio-signature", signature()));
+    let msg = actix_extract(req).await.unwrap().0;
+    assert_eq!(msg.sid.as_str(), MESSAGE_SID);
+    assert_eq!(msg.body.as_deref(), Some("Hi"));
+}
+
+#[cfg(feature = "actix")]
+#[tokio::test]
+async fn actix_rejects_bad_signature() {
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+
+    let req = TestRequest::post()
+        .app_data(client())
+        .insert_header(("x-twilio-signature", "0/KCTR6DLpKmkAf8muzZqo1nDgQ="));
+    let err = actix_extract(req).await.err().unwrap();
+    assert_eq!(err.as_response_error().status_code(), StatusCode::FORBIDDEN);
+}
+
+#[cfg(feature = "actix")]
+#[tokio::test]
+async fn actix_fails_without_client_app_data() {
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+
+    let req = TestRequest::post().insert_header(("x-twilio-signature", signature()));
+    let err = actix_extract(req).await.err().unwrap();
+    assert_eq!(
+        err.as_response_error().status_code(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[cfg(feature = "warp")]
+fn warp_request(signature: &str) -> warp::test::RequestBuilder {
+    warp::test::request()
+        .method("POST")
+        .path("/sms")
+        .header("host", "example.com")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .header("x-twilio-signature", signature)
+        .body(body())
+}
+
+#[cfg(feature = "warp")]
+#[tokio::test]
+async fn warp_extracts_signed_webhook() {
+    let msg = warp_request(&signature())
+        .filter(&twilio::warp::webhook::<twilio::Message>(client()))
+        .await
+        .unwrap();
+    assert_eq!(msg.sid.as_str(), MESSAGE_SID);
+    assert_eq!(msg.body.as_deref(), Some("Hi"));
+}
+
+#[cfg(feature = "warp")]
+#[tokio::test]
+async fn warp_rejects_bad_signature() {
+    let rejection = warp_request("0/KCTR6DLpKmkAf8muzZqo1nDgQ=")
+        .filter(&twilio::warp::webhook::<twilio::Message>(client()))
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(
+        rejection.find::<twilio::TwilioError>(),
+        Some(twilio::TwilioError::AuthError)
+    ));
+}
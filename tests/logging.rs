@@ -0,0 +1,60 @@
+#![cfg(feature = "messaging")]
+
+use hyper::StatusCode;
+use std::sync::Mutex;
+use twilio::{Client, MockTransport, OutboundMessage, Redaction, RetryPolicy};
+
+const ACCOUNT_ID: &str = "AC0123456789abcdef0123456789abcdef";
+
+static LINES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl log::Log for Capture {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "twilio"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            LINES.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[tokio::test]
+async fn logs_requests_with_secrets_and_numbers_masked() {
+    log::set_logger(&Capture).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mock = MockTransport::new();
+    mock.respond(
+        StatusCode::CREATED,
+        r#"{"sid": "SM0123456789abcdef0123456789abcdef", "from": "+15005550006",
+            "to": "+15558675310", "body": "Hi", "auth_token": "abc"}"#,
+    );
+    let client = Client::builder(ACCOUNT_ID, "secret-token")
+        .transport(mock)
+        .retry_policy(RetryPolicy::none())
+        .debug_logging(Redaction::CredentialsAndPii)
+        .build()
+        .unwrap();
+    client
+        .send_message(OutboundMessage::new("+15005550006", "+15558675310", "Hi"))
+        .await
+        .unwrap();
+
+    let lines = LINES.lock().unwrap();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("POST https://api.twilio.com/"));
+    assert!(lines[0].contains("To=%5BREDACTED%5D"));
+    assert!(lines[0].contains("Body=Hi"));
+    assert!(lines[1].contains("201 Created"));
+    assert!(lines[1].contains(r#""auth_token":"[REDACTED]""#));
+    assert!(lines[1].contains(r#""body":"Hi""#));
+    assert!(lines
+        .iter()
+        .all(|l| !l.contains("+1555") && !l.contains("secret-token")));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "messaging")]
+
+use twilio::{MessageAddress, SenderKind};
+
+#[test]
+fn classifies_senders() {
+    assert_eq!(
+        SenderKind::of("+15005550006"),
+        Some(SenderKind::PhoneNumber)
+    );
+    assert_eq!(SenderKind::of("894546"), Some(SenderKind::ShortCode));
+    assert_eq!(SenderKind::of("ACME Corp"), Some(SenderKind::Alphanumeric));
+    assert_eq!(SenderKind::of("Shop24"), Some(SenderKind::Alphanumeric));
+
+    assert_eq!(SenderKind::of("+0123456789"), None);
+    assert_eq!(SenderKind::of("+1555"), None);
+    assert_eq!(SenderKind::of("12"), None);
+    assert_eq!(SenderKind::of("123456789"), None);
+    assert_eq!(SenderKind::of("ACME Corporation"), None);
+    assert_eq!(SenderKind::of("ACME-Corp"), None);
+    assert_eq!(SenderKind::of(""), None);
+}
+
+#[test]
+fn whatsapp_senders_must_be_numbers() {
+    assert_eq!(
+        MessageAddress::parse("whatsapp:+15005550006").sender_kind(),
+        Some(SenderKind::PhoneNumber)
+    );
+    assert_eq!(MessageAddress::Whatsapp("ACME").sender_kind(), None);
+}
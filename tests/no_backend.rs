@@ -0,0 +1,16 @@
+#![cfg(not(any(feature = "native-tls", feature = "isahc", feature = "reqwest")))]
+
+use twilio::{Client, MockTransport, TwilioError};
+
+const ACCOUNT_ID: &str = "AC0123456789abcdef0123456789abcdef";
+
+#[test]
+fn builds_without_a_backend_only_with_a_transport() {
+    let err = Client::builder(ACCOUNT_ID, "token").build().err().unwrap();
+    assert!(matches!(err, TwilioError::NoHttpBackend));
+
+    let client = Client::builder(ACCOUNT_ID, "token")
+        .transport(MockTransport::new())
+        .build();
+    assert!(client.is_ok());
+}
@@ -0,0 +1,29 @@
+use twilio::phone::E164;
+
+#[test]
+fn normalizes_common_formats() {
+    assert_eq!(E164::new("+15551234567").unwrap().as_str(), "+15551234567");
+    assert_eq!(
+        E164::new("+1 (555) 123-4567").unwrap().as_str(),
+        "+15551234567"
+    );
+    assert_eq!(
+        E164::new("0044 20 7946 0958").unwrap().as_str(),
+        "+442079460958"
+    );
+    assert_eq!(
+        "+44.20.7946.0958".parse::<E164>().unwrap().to_string(),
+        "+442079460958"
+    );
+}
+
+#[test]
+fn rejects_malformed_numbers() {
+    assert!(E164::new("5551234567").is_err());
+    assert!(E164::new("+0123456789").is_err());
+    assert!(E164::new("+1555").is_err());
+    assert!(E164::new("+1234567890123456").is_err());
+    assert!(E164::new("+1555CALLNOW").is_err());
+    assert!(serde_json::from_str::<E164>("\"+1 555 123 4567\"").is_ok());
+    assert!(serde_json::from_str::<E164>("\"555\"").is_err());
+}
@@ -0,0 +1,19 @@
+use twilio::{CallSid, MessageSid};
+
+#[test]
+fn validates_prefix_and_format() {
+    assert!(CallSid::new("CA0123456789abcdef0123456789abcdef").is_ok());
+    assert!(CallSid::new("SM0123456789abcdef0123456789abcdef").is_err());
+    assert!(CallSid::new("CA0123").is_err());
+    assert!(CallSid::new("CAzz23456789abcdef0123456789abcdef").is_err());
+
+    assert!(MessageSid::new("SM0123456789abcdef0123456789abcdef").is_ok());
+    assert!(MessageSid::new("MM0123456789abcdef0123456789abcdef").is_ok());
+}
+
+#[test]
+fn deserializes_with_validation() {
+    let sid: CallSid = serde_json::from_str("\"CA0123456789abcdef0123456789abcdef\"").unwrap();
+    assert_eq!(sid.to_string(), "CA0123456789abcdef0123456789abcdef");
+    assert!(serde_json::from_str::<CallSid>("\"RE0123456789abcdef0123456789abcdef\"").is_err());
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "streams")]
+
+use futures_util::SinkExt;
+use twilio::streams::{self, Outgoing, StreamEvent, StreamSink};
+
+const START: &str = r#"{
+  "event": "start",
+  "sequenceNumber": "1",
+  "start": {
+    "accountSid": "AC0123456789abcdef0123456789abcdef",
+    "streamSid": "MZ0123456789abcdef0123456789abcdef",
+    "callSid": "CA0123456789abcdef0123456789abcdef",
+    "tracks": ["inbound"],
+    "mediaFormat": { "encoding": "audio/x-mulaw", "sampleRate": 8000, "channels": 1 },
+    "customParameters": { "caller": "alice" }
+  },
+  "streamSid": "MZ0123456789abcdef0123456789abcdef"
+}"#;
+
+#[test]
+fn parses_start_and_media_frames() {
+    let start = match streams::parse_event(START).unwrap() {
+        StreamEvent::Start {
+            sequence_number,
+            start,
+            ..
+        } => {
+            assert_eq!(sequence_number, Some(1));
+            start
+        }
+        e => panic!("unexpected event {:?}", e),
+    };
+    assert_eq!(start.media_format.sample_rate, 8000);
+    assert_eq!(start.custom_parameters["caller"], "alice");
+
+    let media = r#"{"event":"media","sequenceNumber":"3","streamSid":"MZ0123456789abcdef0123456789abcdef",
+        "media":{"track":"inbound","chunk":"1","timestamp":"5","payload":"/39/"}}"#;
+    match streams::parse_event(media).unwrap() {
+        StreamEvent::Media { media, .. } => {
+            assert_eq!(media.timestamp, Some(5));
+            assert_eq!(media.audio().unwrap(), vec![0xFF, 0x7F, 0x7F]);
+        }
+        e => panic!("unexpected event {:?}", e),
+    }
+}
+
+#[test]
+fn converts_mulaw() {
+    assert_eq!(
+        streams::decode_mulaw(&[0xFF, 0x00, 0x80]),
+        vec![0, -32124, 32124]
+    );
+    let samples = [0i16, 1000, -1000, 12345, -32768];
+    let decoded = streams::decode_mulaw(&streams::encode_mulaw(&samples));
+    for (s, d) in samples.iter().zip(decoded) {
+        assert!((*s as i32 - d as i32).abs() <= (*s as i32).abs() / 16 + 8);
+    }
+}
+
+#[tokio::test]
+async fn sink_writes_outgoing_frames() {
+    let sid = "MZ0123456789abcdef0123456789abcdef".parse().unwrap();
+    let mut sink = StreamSink::new(Vec::<String>::new(), &sid);
+    sink.send(Outgoing::Media(vec![0xFF])).await.unwrap();
+    sink.send(Outgoing::Clear).await.unwrap();
+
+    let frames: Vec<serde_json::Value> = sink
+        .into_inner()
+        .iter()
+        .map(|f| serde_json::from_str(f).unwrap())
+        .collect();
+    assert_eq!(frames[0]["event"], "media");
+    assert_eq!(frames[0]["streamSid"], "MZ0123456789abcdef0123456789abcdef");
+    assert_eq!(frames[0]["media"]["payload"], "/w==");
+    assert_eq!(frames[1]["event"], "clear");
+}
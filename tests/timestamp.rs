@@ -0,0 +1,43 @@
+#![cfg(feature = "time")]
+
+use std::collections::BTreeMap;
+use time::{Duration, OffsetDateTime};
+use twilio::events::parse_cloud_events;
+use twilio::{CallStatusEvent, FromMap};
+
+fn call_status(timestamp: &str) -> BTreeMap<String, String> {
+    [
+        ("CallSid", "CA0123456789abcdef0123456789abcdef"),
+        ("CallStatus", "completed"),
+        ("Timestamp", timestamp),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+#[test]
+fn parses_rfc2822_webhook_timestamps() {
+    let event = CallStatusEvent::from_map(call_status("Mon, 16 Aug 2010 03:45:01 +0000")).unwrap();
+    assert_eq!(
+        event.timestamp,
+        Some(OffsetDateTime::from_unix_timestamp(1281930301).unwrap())
+    );
+
+    let event = CallStatusEvent::from_map(call_status("")).unwrap();
+    assert_eq!(event.timestamp, None);
+
+    assert!(CallStatusEvent::from_map(call_status("yesterday")).is_err());
+}
+
+#[test]
+fn parses_iso8601_product_timestamps() {
+    let events = parse_cloud_events(
+        br#"{"id": "EZ0123", "type": "com.twilio.messaging.message.delivered",
+            "source": "/2010-04-01/Accounts/AC0123/Messages/SM0123.json",
+            "specversion": "1.0", "time": "2023-01-31T12:00:00.250Z", "data": {}}"#,
+    )
+    .unwrap();
+    let expected = OffsetDateTime::from_unix_timestamp(1675166400).unwrap();
+    assert_eq!(events[0].time, Some(expected + Duration::milliseconds(250)));
+}
@@ -0,0 +1,531 @@
+#![cfg(feature = "voice")]
+
+use std::convert::TryFrom;
+use twilio::twiml::{
+    parse, Action, Beep, Client, Conference, ConferenceEvent, Connect, Dial, DialNoun, Enqueue,
+    Gather, GatherInput, Hangup, InvalidTwiml, Leave, Message, MessagingResponse, Method, Node,
+    Number, Parameter, Pause, Pay, PayField, PayPrompt, PaymentMethod, Prompt, Redirect, Refer,
+    Say, Sip, Siprec, SpeechTimeout, Ssml, Start, Stream, Task, TokenType, Track, Twiml,
+    VirtualAgent, Voice,
+};
+use twilio::CallEvent;
+
+#[test]
+fn voice_response() {
+    let mut t = Twiml::new();
+    t.add_verb(&Say {
+        txt: "Connecting you now".to_string(),
+        voice: Voice::Alice,
+        language: "en-US".to_string(),
+        ssml: Vec::new(),
+    })
+    .add_verb(&Pause { length_seconds: 2 })
+    .add_verb(&Dial {
+        number: "+15558675310".to_string(),
+        caller_id: Some("+15017122661".to_string()),
+        ..Default::default()
+    })
+    .add_verb(&Hangup);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Say voice=\"alice\" language=\"en-US\">Connecting you now</Say>\
+         <Pause length=\"2\"></Pause>\
+         <Dial method=\"POST\" timeout=\"30\" callerId=\"+15017122661\" hangupOnStar=\"false\" record=\"do-not-record\">+15558675310</Dial>\
+         <Hangup></Hangup>\
+         </Response>"
+    );
+}
+
+#[test]
+fn escapes_text_and_attributes() {
+    let mut t = Twiml::new();
+    t.add_verb(&Say {
+        txt: "Tom & Jerry <3".to_string(),
+        voice: Voice::Man,
+        language: "en\"".to_string(),
+        ssml: Vec::new(),
+    });
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Say voice=\"man\" language=\"en&quot;\">Tom &amp; Jerry &lt;3</Say>\
+         </Response>"
+    );
+}
+
+#[test]
+fn messaging_response() {
+    let mut r = MessagingResponse::new();
+    r.add(&Message {
+        txt: "Thanks!".to_string(),
+    })
+    .add(&Redirect {
+        url: "https://example.com/next".to_string(),
+        method: Method::Post,
+    });
+
+    assert_eq!(
+        r.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Message>Thanks!</Message>\
+         <Redirect method=\"POST\">https://example.com/next</Redirect>\
+         </Response>"
+    );
+}
+
+#[test]
+fn media_streams() {
+    let mut stream = Stream::new("wss://example.com/audio");
+    stream.name = Some("fork".to_string());
+    stream.track = Some(Track::Both);
+    stream.parameters.push(Parameter::new("customer", "a&b"));
+
+    let mut siprec = Siprec::new("recorder");
+    siprec.parameters.push(Parameter::new("tenant", "1"));
+
+    let mut agent = VirtualAgent::new("dialogflow");
+    agent.sentiment_analysis = Some(true);
+
+    let mut connect = Connect::virtual_agent(agent);
+    connect.action = Some("https://example.com/done".to_string());
+
+    let mut t = Twiml::new();
+    t.add_verb(&Start::stream(stream))
+        .add_verb(&Start::siprec(siprec))
+        .add_verb(&connect);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Start><Stream url=\"wss://example.com/audio\" name=\"fork\" track=\"both_tracks\">\
+         <Parameter name=\"customer\" value=\"a&amp;b\"></Parameter></Stream></Start>\
+         <Start><Siprec connectorName=\"recorder\">\
+         <Parameter name=\"tenant\" value=\"1\"></Parameter></Siprec></Start>\
+         <Connect action=\"https://example.com/done\" method=\"POST\">\
+         <VirtualAgent connectorName=\"dialogflow\" sentimentAnalysis=\"true\"></VirtualAgent>\
+         </Connect></Response>"
+    );
+}
+
+#[test]
+fn dial_nouns() {
+    let mut number = Number::new("+15558675310");
+    number.send_digits = Some("ww1928".to_string());
+    number.status_callback.url = Some("https://example.com/status".to_string());
+    number.status_callback.events = vec![CallEvent::Ringing, CallEvent::Answered];
+
+    let sip = Sip::new("sip:jack@example.com").header("X-Account", "7 & 8");
+
+    let mut client = Client::new("alice");
+    client.parameters.push(Parameter::new("ticket", "42"));
+
+    let ring = Dial {
+        nouns: vec![
+            DialNoun::Number(number),
+            DialNoun::Sip(sip),
+            DialNoun::Client(client),
+        ],
+        ..Default::default()
+    };
+
+    let mut room = Conference::new("Standup");
+    room.beep = Some(Beep::OnEnter);
+    room.end_conference_on_exit = Some(true);
+    room.record = true;
+    room.status_callback_events = vec![ConferenceEvent::Join, ConferenceEvent::Leave];
+    let mut conference = Dial::default();
+    conference.nouns.push(DialNoun::Conference(room));
+
+    let mut t = Twiml::new();
+    t.add_verb(&ring).add_verb(&conference);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Dial method=\"POST\" timeout=\"30\" hangupOnStar=\"false\" record=\"do-not-record\">\
+         <Number sendDigits=\"ww1928\" statusCallbackEvent=\"ringing answered\" \
+         statusCallback=\"https://example.com/status\">+15558675310</Number>\
+         <Sip>sip:jack@example.com?X-Account=7%20%26%208</Sip>\
+         <Client><Identity>alice</Identity><Parameter name=\"ticket\" value=\"42\"></Parameter></Client>\
+         </Dial>\
+         <Dial method=\"POST\" timeout=\"30\" hangupOnStar=\"false\" record=\"do-not-record\">\
+         <Conference beep=\"onEnter\" endConferenceOnExit=\"true\" record=\"record-from-start\" \
+         statusCallbackEvent=\"join leave\">Standup</Conference>\
+         </Dial></Response>"
+    );
+}
+
+#[test]
+fn refers_sip_call() {
+    let mut refer = Refer::new("sip:alice@example.com").header("X-Ticket", "42");
+    refer.action = Some("https://example.com/referred".to_string());
+
+    let mut t = Twiml::new();
+    t.add_verb(&refer);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Refer action=\"https://example.com/referred\" method=\"POST\">\
+         <Sip>sip:alice@example.com?X-Ticket=42</Sip></Refer></Response>"
+    );
+}
+
+#[test]
+fn collects_card_payment() {
+    let pay = Pay {
+        payment_connector: Some("Stripe_Connector".to_string()),
+        charge_amount: Some("10.50".to_string()),
+        currency: Some("usd".to_string()),
+        action: Some("https://example.com/paid".to_string()),
+        postal_code: Some(false),
+        valid_card_types: vec!["visa".to_string(), "mastercard".to_string()],
+        prompts: vec![PayPrompt::new(
+            PayField::PaymentCardNumber,
+            Prompt::Say(Say {
+                txt: "Enter your card number".to_string(),
+                voice: Voice::Woman,
+                language: "en".to_string(),
+                ssml: Vec::new(),
+            }),
+        )],
+        parameters: vec![Parameter::new("order", "1234")],
+        ..Default::default()
+    };
+
+    let mut t = Twiml::new();
+    t.add_verb(&pay);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Pay paymentConnector=\"Stripe_Connector\" chargeAmount=\"10.50\" currency=\"usd\" \
+         action=\"https://example.com/paid\" method=\"POST\" postalCode=\"false\" \
+         validCardTypes=\"visa mastercard\">\
+         <Prompt for=\"payment-card-number\"><Say voice=\"woman\" language=\"en\">Enter your card number</Say></Prompt>\
+         <Parameter name=\"order\" value=\"1234\"></Parameter></Pay></Response>"
+    );
+}
+
+#[test]
+fn tokenizes_card_without_charging() {
+    let pay = Pay {
+        token_type: Some(TokenType::Reusable),
+        payment_method: Some(PaymentMethod::CreditCard),
+        ..Default::default()
+    };
+    assert_eq!(
+        pay.as_twiml(),
+        "<Pay tokenType=\"reusable\" paymentMethod=\"credit-card\"></Pay>"
+    );
+}
+
+#[test]
+fn gathers_speech_and_dtmf() {
+    let gather = Gather {
+        action: Some("https://example.com/menu".to_string()),
+        input: vec![GatherInput::Speech, GatherInput::Dtmf],
+        speech_model: Some("phone_call".to_string()),
+        hints: vec!["sales".to_string(), "support".to_string()],
+        language: Some("en-GB".to_string()),
+        speech_timeout: Some(SpeechTimeout::Auto),
+        profanity_filter: Some(false),
+        action_on_empty_result: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(
+        gather.as_twiml(),
+        "<Gather method=\"POST\" action=\"https://example.com/menu\" timeout=\"5\" finishOnKey=\"*\" \
+         input=\"speech dtmf\" speechModel=\"phone_call\" hints=\"sales, support\" language=\"en-GB\" \
+         speechTimeout=\"auto\" profanityFilter=\"false\" actionOnEmptyResult=\"true\"></Gather>"
+    );
+}
+
+#[test]
+fn says_ssml_with_polly_voice() {
+    let say = Say::ssml(
+        vec![
+            Ssml::text("Your code is "),
+            Ssml::say_as("spell-out", "A1B"),
+            Ssml::pause("500ms"),
+            Ssml::Prosody {
+                rate: Some("slow".to_string()),
+                pitch: None,
+                volume: Some("loud".to_string()),
+                content: vec![Ssml::Emphasis {
+                    level: Some("strong".to_string()),
+                    content: vec![Ssml::text("Don't share it & stay safe")],
+                }],
+            },
+            Ssml::Phoneme {
+                alphabet: Some("ipa".to_string()),
+                ph: "pɪˈkɑːn".to_string(),
+                text: "pecan".to_string(),
+            },
+        ],
+        Voice::Polly("Joanna-Neural".to_string()),
+        "en-US",
+    );
+    assert_eq!(
+        say.as_twiml(),
+        "<Say voice=\"Polly.Joanna-Neural\" language=\"en-US\">Your code is \
+         <say-as interpret-as=\"spell-out\">A1B</say-as><break time=\"500ms\"></break>\
+         <prosody rate=\"slow\" volume=\"loud\"><emphasis level=\"strong\">\
+         Don&apos;t share it &amp; stay safe</emphasis></prosody>\
+         <phoneme alphabet=\"ipa\" ph=\"pɪˈkɑːn\">pecan</phoneme></Say>"
+    );
+}
+
+#[test]
+fn enqueues_into_workflow_and_leaves() {
+    let mut support = Enqueue::new("support");
+    support.action = Some("https://example.com/after-queue".to_string());
+    support.wait_url = Some("https://example.com/hold-music".to_string());
+    support.wait_url_method = Some(Method::Get);
+
+    let mut task = Task::new(serde_json::json!({"selected_language": "es"}));
+    task.priority = Some(5);
+    let routed = Enqueue::workflow("WW0123456789abcdef0123456789abcdef", task);
+
+    let mut t = Twiml::new();
+    t.add_verb(&support).add_verb(&routed).add_verb(&Leave);
+
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response>\
+         <Enqueue action=\"https://example.com/after-queue\" method=\"POST\" \
+         waitUrl=\"https://example.com/hold-music\" waitUrlMethod=\"GET\">support</Enqueue>\
+         <Enqueue workflowSid=\"WW0123456789abcdef0123456789abcdef\">\
+         <Task priority=\"5\">{&quot;selected_language&quot;:&quot;es&quot;}</Task></Enqueue>\
+         <Leave></Leave></Response>"
+    );
+}
+
+#[test]
+fn parses_twiml_back_into_elements() {
+    let mut t = Twiml::new();
+    t.add_verb(&Say::new("Tom & Jerry", Voice::Alice, "en-US"))
+        .add_verb(&Enqueue::new("support"));
+    let xml = t.as_twiml();
+
+    let mut response = parse(&xml).unwrap();
+    assert_eq!(response.name, "Response");
+    let say = response.find("Say").unwrap();
+    assert_eq!(say.attr("voice"), Some("alice"));
+    assert_eq!(say.text(), "Tom & Jerry");
+    assert_eq!(response.find("Enqueue").unwrap().text(), "support");
+
+    if let Node::Element(ref mut say) = response.children[0] {
+        say.set_attr("voice", "Polly.Joanna");
+    }
+    let mut edited = Twiml::new();
+    for verb in response.elements() {
+        edited.add_verb(verb);
+    }
+    assert_eq!(edited.as_twiml(), xml.replace("alice", "Polly.Joanna"));
+
+    let stored = parse(
+        "<?xml version='1.0'?>\n<!-- bin -->\n<Response>\n  <Say>Hi <break time='1s'/> there</Say>\n  <Hangup/>\n</Response>",
+    )
+    .unwrap();
+    assert_eq!(
+        stored.as_twiml(),
+        "<Response><Say>Hi <break time=\"1s\"></break> there</Say><Hangup></Hangup></Response>"
+    );
+
+    let err = parse("<Response><Say>Hi</Response>").unwrap_err();
+    assert_eq!(err.position, 17);
+    assert!(parse("<Response>&nbsp;</Response>").is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn still_adds_any_action() {
+    let mut t = Twiml::new();
+    t.add(&Say::new("Hi", Voice::Alice, "en-US"))
+        .add(&Number::new("+15558675310"));
+    assert_eq!(
+        t.as_twiml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?><Response><Say voice=\"alice\" language=\"en-US\">Hi</Say><Number>+15558675310</Number></Response>"
+    );
+    assert!(t.validate().is_err());
+}
+
+#[test]
+fn validates_nesting() {
+    let mut t = Twiml::new();
+    t.add_verb(&Gather {
+        action: Some("https://example.com/menu".to_string()),
+        prompt: Prompt::Pause(Pause { length_seconds: 1 }),
+        ..Default::default()
+    });
+    t.validate().unwrap();
+
+    let mut r = MessagingResponse::new();
+    r.add(&Message {
+        txt: "Hi".to_string(),
+    });
+    r.validate().unwrap();
+
+    let stored = parse("<Response><Gather><Dial>+15558675310</Dial></Gather></Response>").unwrap();
+    let mut t = Twiml::new();
+    for verb in stored.elements() {
+        t.add_verb(verb);
+    }
+    let err = t.validate().unwrap_err();
+    assert_eq!(err.to_string(), "<Dial> is not allowed inside <Gather>");
+
+    let mixed = parse("<Response><Message>Hi</Message><Say>Hi</Say></Response>").unwrap();
+    assert!(matches!(
+        mixed.validate(),
+        Err(InvalidTwiml::Misplaced { ref element, .. }) if element == "Say"
+    ));
+    let noun = parse("<Response><Number>+15558675310</Number></Response>").unwrap();
+    assert!(noun.validate().is_err());
+    assert!(parse("<Gather></Gather>").unwrap().validate().is_err());
+}
+
+#[test]
+fn keeps_whitespace_between_ssml_tags() {
+    let xml = "<Say>Hi <emphasis>there</emphasis> <break/>you</Say>";
+    let say = Say::try_from(&parse(xml).unwrap()).unwrap();
+    assert_eq!(say.txt, "Hi ");
+    assert_eq!(
+        say.as_twiml(),
+        "<Say voice=\"man\" language=\"en-US\">Hi <emphasis>there</emphasis> \
+         <break></break>you</Say>"
+    );
+
+    let nested = format!("{}{}", "<Gather>".repeat(65), "</Gather>".repeat(65));
+    let err = parse(&nested).unwrap_err();
+    assert_eq!(err.message, "elements nested more than 64 deep");
+    assert!(parse(&"<Response>".repeat(100_000)).is_err());
+}
+
+#[test]
+fn converts_parsed_twiml_into_builders() {
+    let mut t = Twiml::new();
+    t.add_verb(&Say::ssml(
+        vec![
+            Ssml::text("Your code is "),
+            Ssml::say_as("spell-out", "A1B"),
+        ],
+        Voice::Polly("Joanna".to_string()),
+        "en-GB",
+    ));
+    let mut sip = Sip::new("sip:jack@example.com").header("X-Account", "7 & 8");
+    sip.username = Some("jack".to_string());
+    let mut number = Number::new("+15558675310");
+    number.status_callback.events = vec![CallEvent::Answered];
+    let mut client = Client::new("alice");
+    client.parameters.push(Parameter::new("ticket", "42"));
+    t.add_verb(&Dial {
+        nouns: vec![
+            DialNoun::Number(number),
+            DialNoun::Sip(sip),
+            DialNoun::Client(client),
+        ],
+        timeout_seconds: 10,
+        ..Default::default()
+    });
+    t.add_verb(&Gather {
+        input: vec![GatherInput::Speech],
+        hints: vec!["yes".to_string(), "no".to_string()],
+        speech_timeout: Some(SpeechTimeout::Auto),
+        prompt: Prompt::Say(Say::new("Yes or no?", Voice::Alice, "en-US")),
+        ..Default::default()
+    });
+    let mut connect = Connect::stream(Stream::new("wss://example.com/audio"));
+    connect.action = Some("https://example.com/done".to_string());
+    t.add_verb(&connect);
+    t.add_verb(&Refer::new("sip:alice@example.com").header("X-Ticket", "4 2"));
+    let xml = t.as_twiml();
+
+    let response = parse(&xml).unwrap();
+    let mut converted = Twiml::new();
+    let verbs: Vec<_> = response.elements().collect();
+    converted
+        .add_verb(&Say::try_from(verbs[0]).unwrap())
+        .add_verb(&Dial::try_from(verbs[1]).unwrap())
+        .add_verb(&Gather::try_from(verbs[2]).unwrap())
+        .add_verb(&Connect::try_from(verbs[3]).unwrap())
+        .add_verb(&Refer::try_from(verbs[4]).unwrap());
+    assert_eq!(converted.as_twiml(), xml);
+
+    let dial = Dial::try_from(verbs[1]).unwrap();
+    match dial.nouns[1] {
+        DialNoun::Sip(ref sip) => {
+            assert_eq!(sip.uri, "sip:jack@example.com");
+            assert_eq!(
+                sip.headers,
+                vec![("X-Account".to_string(), "7 & 8".to_string())]
+            );
+        }
+        _ => panic!("expected <Sip>"),
+    }
+
+    let bare = parse("<Dial timeLimit=\"60\">\n  +15558675310\n</Dial>").unwrap();
+    let bare = Dial::try_from(&bare).unwrap();
+    assert_eq!(bare.number, "+15558675310");
+    assert_eq!(bare.time_limit_seconds, Some(60));
+
+    let task =
+        parse("<Enqueue workflowSid=\"WW1\"><Task priority=\"5\">{\"a\":1}</Task></Enqueue>")
+            .unwrap();
+    let enqueue = Enqueue::try_from(&task).unwrap();
+    assert_eq!(
+        enqueue.task.unwrap().attributes,
+        serde_json::json!({"a": 1})
+    );
+}
+
+#[test]
+fn rejects_twiml_the_builders_cannot_represent() {
+    let say = parse("<Play>https://example.com/a.mp3</Play>").unwrap();
+    assert!(matches!(
+        Say::try_from(&say).err().unwrap(),
+        InvalidTwiml::WrongElement { expected: "Say", ref found } if found == "Play"
+    ));
+
+    let unknown = parse("<Say rate=\"fast\">Hi</Say>").unwrap();
+    assert_eq!(
+        Say::try_from(&unknown).err().unwrap().to_string(),
+        "<Say> has attribute `rate`, which its builder can't represent"
+    );
+
+    let room = parse("<Connect><Room>standup</Room></Connect>").unwrap();
+    assert!(matches!(
+        Connect::try_from(&room).err().unwrap(),
+        InvalidTwiml::Unsupported { ref item, .. } if item == "<Room>"
+    ));
+
+    let timeout = parse("<Gather timeout=\"soon\"></Gather>").unwrap();
+    assert_eq!(
+        Gather::try_from(&timeout).err().unwrap().to_string(),
+        "invalid timeout=\"soon\" on <Gather>"
+    );
+
+    let prompt = parse("<Pay><Prompt><Say>Card number?</Say></Prompt></Pay>").unwrap();
+    assert!(matches!(
+        Pay::try_from(&prompt).err().unwrap(),
+        InvalidTwiml::MissingAttribute {
+            attribute: "for",
+            ..
+        }
+    ));
+
+    let task = parse("<Task>not json</Task>").unwrap();
+    assert!(matches!(
+        Task::try_from(&task).err().unwrap(),
+        InvalidTwiml::InvalidText { .. }
+    ));
+    assert!(matches!(
+        Start::try_from(&parse("<Start></Start>").unwrap())
+            .err()
+            .unwrap(),
+        InvalidTwiml::MissingContent { .. }
+    ));
+}
@@ -0,0 +1,452 @@
+#![cfg(feature = "all-products")]
+
+use std::collections::BTreeMap;
+use twilio::events::parse_cloud_events;
+use twilio::fax::{FaxStatus, FaxStatusEvent};
+use twilio::monitor::DebuggerEvent;
+use twilio::taskrouter::{AssignmentStatus, Event};
+use twilio::{
+    validate_request, validate_request_with_body, AnsweredBy, Call, CallDirection, CallStatus,
+    CallStatusEvent, FromMap, GatherResult, Message, MessageAddress, MessageStatus,
+    MessageStatusEvent, PayOutcome, PayResult, RecordingStatus, RecordingStatusEvent,
+};
+
+const AUTH_TOKEN: &str = "12345";
+const CALL_SID: &str = "CA0123456789abcdef0123456789abcdef";
+const MESSAGE_SID: &str = "MM0123456789abcdef0123456789abcdef";
+
+fn params() -> BTreeMap<String, String> {
+    [
+        ("CallSid", "CA1234567890ABCDE"),
+        ("Caller", "+12349013030"),
+        ("Digits", "1234"),
+        ("From", "+12349013030"),
+        ("To", "+18005551212"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+#[test]
+fn validates_form_signature() {
+    let url = "https://mycompany.com/myapp.php?foo=1&bar=2";
+    assert!(validate_request(
+        AUTH_TOKEN,
+        url,
+        &params(),
+        "0/KCTR6DLpKmkAf8muzZqo1nDgQ="
+    ));
+    assert!(!validate_request(
+        AUTH_TOKEN,
+        url,
+        &BTreeMap::new(),
+        "0/KCTR6DLpKmkAf8muzZqo1nDgQ="
+    ));
+}
+
+#[test]
+fn validates_json_signature() {
+    let body = br#"{"property": "value", "boolean": true}"#;
+    let url = "https://mycompany.com/myapp.php?foo=1&bar=2\
+               &bodySHA256=0a1ff7634d9ab3b95db5c9a2dfe9416e41502b283a80c7cf19632632f96e6620";
+    assert!(validate_request_with_body(
+        AUTH_TOKEN,
+        url,
+        body,
+        "a9nBmqA0ju/hNViExpshrM61xv4="
+    ));
+    assert!(!validate_request_with_body(
+        AUTH_TOKEN,
+        url,
+        b"{}",
+        "a9nBmqA0ju/hNViExpshrM61xv4="
+    ));
+}
+
+#[test]
+fn parses_call_webhook() {
+    let mut p = params();
+    p.insert("CallSid".to_string(), CALL_SID.to_string());
+    p.insert("CallStatus".to_string(), "in-progress".to_string());
+    p.insert("Direction".to_string(), "inbound".to_string());
+    p.insert("FromCity".to_string(), "SAN FRANCISCO".to_string());
+    p.insert(
+        "StirVerstat".to_string(),
+        "TN-Validation-Passed-A".to_string(),
+    );
+
+    let call = Call::from_map(p).unwrap();
+    assert_eq!(call.sid.as_str(), CALL_SID);
+    assert!(matches!(call.status, CallStatus::InProgress));
+    assert_eq!(call.digits.as_deref(), Some("1234"));
+    assert_eq!(call.direction, Some(CallDirection::Inbound));
+    assert_eq!(call.from_city.as_deref(), Some("SAN FRANCISCO"));
+    assert_eq!(call.extra["Caller"], "+12349013030");
+    assert_eq!(call.extra["StirVerstat"], "TN-Validation-Passed-A");
+}
+
+#[test]
+fn parses_answering_machine_detection_result() {
+    let mut p = params();
+    p.insert("CallSid".to_string(), CALL_SID.to_string());
+    p.insert("CallStatus".to_string(), "in-progress".to_string());
+    p.insert("AnsweredBy".to_string(), "machine_end_beep".to_string());
+    p.insert("MachineDetectionDuration".to_string(), "4120".to_string());
+
+    let call = Call::from_map(p).unwrap();
+    let answered_by = call.answered_by.unwrap();
+    assert_eq!(answered_by, AnsweredBy::MachineEndBeep);
+    assert!(answered_by.is_machine());
+    assert_eq!(call.machine_detection_duration, Some(4120));
+}
+
+#[test]
+fn parses_call_status_callback() {
+    let p = [
+        ("CallSid", CALL_SID),
+        ("CallStatus", "completed"),
+        ("CallDuration", "42"),
+        ("SequenceNumber", "3"),
+        ("CallbackSource", "call-progress-events"),
+        ("Timestamp", "Mon, 16 Aug 2010 03:45:01 +0000"),
+        ("AnsweredBy", "human"),
+        ("Duration", "1"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = CallStatusEvent::from_map(p).unwrap();
+    assert!(matches!(event.call_status, CallStatus::Completed));
+    assert_eq!(event.call_duration, Some(42));
+    assert_eq!(event.sequence_number, Some(3));
+    assert_eq!(event.answered_by, Some(AnsweredBy::Human));
+    assert_eq!(event.machine_detection_duration, None);
+    assert_eq!(event.extra["Duration"], "1");
+}
+
+#[test]
+fn parses_gather_result() {
+    let mut p = params();
+    p.insert("CallSid".to_string(), CALL_SID.to_string());
+    p.insert("SpeechResult".to_string(), "Talk to sales".to_string());
+    p.insert("Confidence".to_string(), "0.92".to_string());
+    p.insert("FinishedOnKey".to_string(), "".to_string());
+
+    let result = GatherResult::from_map(p).unwrap();
+    assert_eq!(result.digits.as_deref(), Some("1234"));
+    assert_eq!(result.speech_result.as_deref(), Some("Talk to sales"));
+    assert_eq!(result.confidence, Some(0.92));
+    assert_eq!(result.finished_on_key.as_deref(), Some(""));
+    assert_eq!(result.extra["Caller"], "+12349013030");
+}
+
+#[test]
+fn parses_pay_result() {
+    let mut p = params();
+    p.insert("CallSid".to_string(), CALL_SID.to_string());
+    p.insert("Result".to_string(), "success".to_string());
+    p.insert(
+        "PaymentConfirmationCode".to_string(),
+        "ch_a9dc6297".to_string(),
+    );
+    p.insert(
+        "PaymentCardNumber".to_string(),
+        "xxxx-xxxx-xxxx-1111".to_string(),
+    );
+    p.insert("PaymentCardType".to_string(), "visa".to_string());
+    p.insert("ExpirationDate".to_string(), "1225".to_string());
+    p.insert("PaymentError".to_string(), "".to_string());
+
+    let result = PayResult::from_map(p).unwrap();
+    assert_eq!(result.result, PayOutcome::Success);
+    assert_eq!(
+        result.payment_confirmation_code.as_deref(),
+        Some("ch_a9dc6297")
+    );
+    assert_eq!(
+        result.payment_card_number.as_deref(),
+        Some("xxxx-xxxx-xxxx-1111")
+    );
+    assert_eq!(result.expiration_date.as_deref(), Some("1225"));
+    assert!(result.payment_token.is_none());
+    assert_eq!(result.extra["Caller"], "+12349013030");
+}
+
+#[test]
+fn parses_recording_status_callback() {
+    let p = [
+        ("RecordingSid", "RE0123456789abcdef0123456789abcdef"),
+        ("RecordingUrl", "https://api.twilio.com/recording"),
+        ("RecordingStatus", "completed"),
+        ("RecordingDuration", "17"),
+        ("RecordingChannels", "2"),
+        ("RecordingSource", "DialVerb"),
+        ("CallSid", CALL_SID),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = RecordingStatusEvent::from_map(p).unwrap();
+    assert!(matches!(
+        event.recording_status,
+        Some(RecordingStatus::Completed)
+    ));
+    assert_eq!(event.recording_duration, Some(17));
+    assert_eq!(event.recording_channels, Some(2));
+    assert_eq!(event.call_sid.unwrap().as_str(), CALL_SID);
+}
+
+#[test]
+fn parses_message_webhook_with_media() {
+    let p = [
+        ("MessageSid", MESSAGE_SID),
+        ("From", "+12349013030"),
+        ("To", "+18005551212"),
+        ("Body", "Look"),
+        ("NumMedia", "2"),
+        ("MediaUrl0", "https://example.com/0.jpg"),
+        ("MediaUrl1", "https://example.com/1.jpg"),
+        ("MediaContentType0", "image/jpeg"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let msg = Message::from_map(p).unwrap();
+    assert_eq!(msg.sid.as_str(), MESSAGE_SID);
+    assert_eq!(
+        msg.media_urls,
+        vec!["https://example.com/0.jpg", "https://example.com/1.jpg"]
+    );
+    assert!(!msg.extra.contains_key("MediaUrl0"));
+    assert_eq!(msg.extra["MediaContentType0"], "image/jpeg");
+}
+
+#[test]
+fn parses_whatsapp_button_reply() {
+    let p = [
+        ("MessageSid", "SM0123456789abcdef0123456789abcdef"),
+        ("From", "whatsapp:+12349013030"),
+        ("To", "whatsapp:+18005551212"),
+        ("Body", "Yes"),
+        ("ProfileName", "Alice"),
+        ("WaId", "12349013030"),
+        ("MessageType", "button"),
+        ("ButtonText", "Yes"),
+        ("ButtonPayload", "confirm"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let msg = Message::from_map(p).unwrap();
+    let from = MessageAddress::parse(msg.from.as_deref().unwrap());
+    assert_eq!(from, MessageAddress::Whatsapp("+12349013030"));
+    assert_eq!(from.to_string(), "whatsapp:+12349013030");
+    assert_eq!(msg.profile_name.as_deref(), Some("Alice"));
+    assert_eq!(msg.wa_id.as_deref(), Some("12349013030"));
+    assert_eq!(msg.message_type.as_deref(), Some("button"));
+    assert_eq!(msg.button_payload.as_deref(), Some("confirm"));
+}
+
+#[test]
+fn parses_message_status_callback() {
+    let p = [
+        ("MessageSid", MESSAGE_SID),
+        ("SmsSid", MESSAGE_SID),
+        ("MessageStatus", "undelivered"),
+        ("SmsStatus", "undelivered"),
+        ("ErrorCode", "30003"),
+        ("To", "+18005551212"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = MessageStatusEvent::from_map(p).unwrap();
+    assert_eq!(event.message_sid.as_str(), MESSAGE_SID);
+    assert!(matches!(event.message_status, MessageStatus::undelivered));
+    assert_eq!(event.error_code, Some(30003));
+    assert_eq!(event.extra["SmsStatus"], "undelivered");
+}
+
+#[test]
+fn rejects_webhook_missing_required_fields() {
+    let mut p = params();
+    p.insert("CallStatus".to_string(), "ringing".to_string());
+    p.remove("CallSid");
+    assert!(Call::from_map(p).is_err());
+}
+
+#[test]
+fn parses_taskrouter_event() {
+    let p = [
+        ("EventType", "reservation.created"),
+        ("ResourceType", "reservation"),
+        ("WorkspaceSid", "WS0123456789abcdef0123456789abcdef"),
+        ("TaskSid", "WT0123456789abcdef0123456789abcdef"),
+        ("TaskAssignmentStatus", "reserved"),
+        ("TaskAttributes", "{\"type\":\"support\"}"),
+        ("WorkerName", "Alice"),
+        ("Sid", "EV0123456789abcdef0123456789abcdef"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = Event::from_map(p).unwrap();
+    assert_eq!(event.event_type, "reservation.created");
+    assert_eq!(
+        event.task_assignment_status,
+        Some(AssignmentStatus::Reserved)
+    );
+    assert_eq!(event.worker_name.as_deref(), Some("Alice"));
+    assert!(event.extra.contains_key("Sid"));
+}
+
+#[test]
+fn parses_fax_status_callback() {
+    let p = [
+        ("FaxSid", "FX0123456789abcdef0123456789abcdef"),
+        ("FaxStatus", "no-answer"),
+        ("NumPages", ""),
+        ("ErrorCode", "15003"),
+        ("RemoteStationId", "ACME"),
+        ("ApiVersion", "v1"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = FaxStatusEvent::from_map(p).unwrap();
+    assert_eq!(event.fax_status, FaxStatus::NoAnswer);
+    assert_eq!(event.num_pages, None);
+    assert_eq!(event.error_code, Some(15003));
+    assert_eq!(event.remote_station_id.as_deref(), Some("ACME"));
+    assert_eq!(event.extra["ApiVersion"], "v1");
+}
+
+#[test]
+fn parses_delivered_and_received_fax_callbacks() {
+    let p = [
+        ("FaxSid", "FX0123456789abcdef0123456789abcdef"),
+        ("AccountSid", "AC0123456789abcdef0123456789abcdef"),
+        ("From", "+15005550006"),
+        ("To", "+15558675310"),
+        ("FaxStatus", "delivered"),
+        ("NumPages", "3"),
+        ("OriginalMediaUrl", "https://example.com/fax.pdf"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    let event = FaxStatusEvent::from_map(p).unwrap();
+    assert_eq!(event.fax_sid.as_str(), "FX0123456789abcdef0123456789abcdef");
+    assert_eq!(event.fax_status, FaxStatus::Delivered);
+    assert_eq!(event.num_pages, Some(3));
+    assert_eq!(event.from.as_deref(), Some("+15005550006"));
+    assert_eq!(
+        event.original_media_url.as_deref(),
+        Some("https://example.com/fax.pdf")
+    );
+    assert_eq!(event.error_code, None);
+
+    let p = [
+        ("FaxSid", "FXfedcba9876543210fedcba9876543210"),
+        ("FaxStatus", "received"),
+        ("NumPages", "1"),
+        ("MediaUrl", "https://api.twilio.com/fax/media.pdf"),
+        ("RemoteStationId", "+15005550006"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    let event = FaxStatusEvent::from_map(p).unwrap();
+    assert_eq!(event.fax_status, FaxStatus::Received);
+    assert_eq!(
+        event.media_url.as_deref(),
+        Some("https://api.twilio.com/fax/media.pdf")
+    );
+    assert!(event.extra.is_empty());
+
+    let p = [
+        ("FaxSid", "FX0123456789abcdef0123456789abcdef"),
+        ("FaxStatus", "lost"),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    assert!(FaxStatusEvent::from_map(p).is_err());
+}
+
+#[test]
+fn parses_debugger_webhook_payload() {
+    let payload = r#"{"resource_sid": "CA0123456789abcdef0123456789abcdef",
+        "service_sid": null, "error_code": "11200",
+        "more_info": {"msg": "An attempt to retrieve content returned an HTTP 502",
+            "sourceComponent": "14100"},
+        "webhook": {"type": "application/json",
+            "request": {"url": "https://example.com/voice", "method": "POST",
+                "headers": {}, "parameters": {"CallSid": "CA0123456789abcdef0123456789abcdef"}},
+            "response": {"status_code": 502, "headers": {}, "body": "Bad Gateway"}}}"#;
+    let p = [
+        ("Sid", "NO0123456789abcdef0123456789abcdef"),
+        ("AccountSid", "AC0123456789abcdef0123456789abcdef"),
+        ("Level", "ERROR"),
+        ("Timestamp", "2023-01-31T00:00:00Z"),
+        ("PayloadType", "application/json"),
+        ("Payload", payload),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    let event = DebuggerEvent::from_map(p).unwrap();
+    assert_eq!(event.error_code(), Some(11200));
+    assert_eq!(event.request_url(), Some("https://example.com/voice"));
+    assert_eq!(event.response_body(), Some("Bad Gateway"));
+    assert_eq!(event.payload.more_info["sourceComponent"], "14100");
+    let webhook = event.payload.webhook.unwrap();
+    assert_eq!(webhook.response.unwrap().status_code, Some(502));
+    assert_eq!(
+        webhook.request.unwrap().parameters["CallSid"],
+        "CA0123456789abcdef0123456789abcdef"
+    );
+}
+
+#[test]
+fn parses_cloud_events_from_webhook_sink() {
+    #[derive(serde::Deserialize)]
+    struct MessageStatus {
+        #[serde(rename = "messageSid")]
+        message_sid: String,
+        #[serde(rename = "messageStatus")]
+        message_status: String,
+    }
+
+    let body = format!(
+        r#"[{{"specversion": "1.0", "type": "com.twilio.messaging.message.delivered",
+            "source": "/2010-04-01/Accounts/AC0123456789abcdef0123456789abcdef/Messages/{MESSAGE_SID}.json",
+            "id": "EZ0123456789abcdef0123456789abcdef",
+            "dataschema": "https://events-schemas.twilio.com/Messaging.MessageStatus/2",
+            "datacontenttype": "application/json", "time": "2023-01-31T00:00:00.000Z",
+            "data": {{"messageSid": "{MESSAGE_SID}", "messageStatus": "DELIVERED"}}}}]"#
+    );
+    let events = parse_cloud_events(body.as_bytes()).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].event_type,
+        "com.twilio.messaging.message.delivered"
+    );
+    let status: MessageStatus = events[0].data_as().unwrap();
+    assert_eq!(status.message_sid, MESSAGE_SID);
+    assert_eq!(status.message_status, "DELIVERED");
+
+    let single = r#"{"specversion": "1.0", "type": "com.twilio.voice.status-callback.call.completed",
+        "source": "/v1/Calls", "id": "EZ1", "data": {}}"#;
+    let events = parse_cloud_events(single.as_bytes()).unwrap();
+    assert_eq!(events[0].source, "/v1/Calls");
+}